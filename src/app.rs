@@ -1,7 +1,10 @@
+use std::collections::{HashMap, VecDeque};
 use std::io::{self, Stdout, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use alloy::primitives::U256;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
@@ -10,9 +13,14 @@ use crossterm::{
 use eyre::Result;
 use ratatui::{Terminal, prelude::*};
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
 
 use crate::config::AppConfig;
-use crate::contracts::{CallResult, ContractCaller, chain_id_to_network};
+use crate::contracts::{
+    CallResult, ContractCaller, ContractEvent, ContractFunction, LogEntry, LogQuery, TxPreview,
+    TxReceipt, chain_id_to_network, encode_call_data, function_matches, function_signature,
+    parse_eth_value,
+};
 
 /// Helper to temporarily restore terminal for dialoguer prompts
 fn with_restored_terminal<F, T>(f: F) -> Result<T>
@@ -37,13 +45,28 @@ where
     result
 }
 use crate::contracts::DeploymentManager;
-use crate::project::Project;
-use crate::scripts::ScriptManager;
+use crate::project::{HardhatConfig, Project};
+use crate::scripts::{
+    CANCEL_GRACE, RunHistory, RunOptionField, RunOptions, RunRecord, Script, ScriptExit, ScriptIo,
+    ScriptManager, ScriptOutput, ScriptSummary, ScriptType,
+};
 use crate::ui;
 
+/// A project found in workspace mode, with the results of its last scan
+pub struct WorkspaceMember {
+    pub project: Project,
+    pub deployments: DeploymentManager,
+    pub scripts: Arc<ScriptManager>,
+}
+
 /// Application state
 pub struct App {
+    /// Project the lists are scoped to (the first one when merged)
     pub project: Project,
+    /// Every project found; a single one outside workspace mode
+    pub workspace: Vec<WorkspaceMember>,
+    /// Index into `workspace` the lists are scoped to, `None` to merge all projects
+    pub workspace_scope: Option<usize>,
     pub config: AppConfig,
     pub view: View,
     pub should_quit: bool,
@@ -51,15 +74,49 @@ pub struct App {
     pub scripts: Arc<ScriptManager>,
     pub status_message: Option<String>,
     pub script_tx: UnboundedSender<Action>,
+    /// Background task following the selected deployment's logs
+    pub log_tail: Option<tokio::task::AbortHandle>,
+    /// Background task waiting for the receipt of a transaction sent from Interact
+    pub receipt_watch: Option<tokio::task::AbortHandle>,
+    /// Bumped whenever the workspace's projects are rescanned or reconfigured, so
+    /// background refreshes started before that are dropped
+    pub workspace_generation: u64,
+    /// Cancels the running script
+    pub script_cancel: Option<oneshot::Sender<()>>,
+    /// Saved script runs, newest first
+    pub history: RunHistory,
 }
 
 pub enum Action {
     ScriptLine(String),
-    ScriptFinished(Result<String>),
+    ScriptFinished(Result<ScriptOutput>),
+    /// Result of a background rescan of the workspace, with chains lacking a network.
+    /// `generation` is the `App::workspace_generation` the rescan started from.
+    WorkspaceRefreshed {
+        generation: u64,
+        result: Result<(Vec<WorkspaceMember>, Vec<u64>)>,
+    },
+    /// hardhat.config of the project at `root`, as evaluated by Hardhat itself
+    HardhatConfigEvaluated {
+        root: PathBuf,
+        config: HardhatConfig,
+    },
+    /// A finished run was saved to the history
+    RunRecorded(RunRecord),
+    /// Receipt (or watch error) for a transaction sent from the Interact view
+    TransactionMined {
+        tx_hash: String,
+        result: Result<TxReceipt>,
+    },
+    /// New log from the live event tail
+    TailLog(LogEntry),
+    /// The live event tail ended, with the error that stopped it
+    TailStopped(Option<String>),
 }
 
 /// Current view/screen
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::large_enum_variant)]
 pub enum View {
     #[default]
     Home,
@@ -74,10 +131,16 @@ pub enum InteractFocus {
     #[default]
     Deployments,
     Functions,
+    FunctionSearch, // Typing a name or selector to filter the function list
     Inputs,
     WalletSelection,
     AbiSelection,
+    History,              // Pick an earlier deployment of the selected contract
     ImplementationPrompt, // Prompt for proxy implementation ABI
+    ConfirmTransaction,   // Review a simulated write before signing
+    Events,               // Events tab: pick an event to query
+    EventQuery,           // Events tab: edit block range and topic filters
+    EventLogs,            // Events tab: scroll through fetched logs
 }
 
 /// Status of a contract call
@@ -88,10 +151,18 @@ pub enum CallStatus {
     Idle,
     /// Connecting to RPC
     Connecting,
+    /// Simulating a write call and estimating gas
+    Simulating,
     /// Executing the call
     Executing,
     /// Call completed successfully
     Completed,
+    /// Transaction sent, waiting for it to be mined and confirmed
+    Pending { tx_hash: String, confirmations: u64 },
+    /// Transaction mined successfully
+    Mined,
+    /// Transaction mined but reverted
+    Reverted,
     /// Call failed
     Failed(String),
 }
@@ -104,11 +175,32 @@ pub struct NetworkInfo {
     pub rpc_url: String,
 }
 
+/// Events tab of the interact view
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EventsState {
+    /// 0 = all events, otherwise index + 1 into the deployment's events
+    pub selected_event: usize,
+    /// From block, to block, then one filter per indexed parameter
+    pub query_values: Vec<String>,
+    pub current_field: usize,
+    pub logs: Vec<LogEntry>,
+    pub selected_log: usize,
+    /// Summary of the last query
+    pub status: Option<String>,
+    pub error: Option<String>,
+    /// Whether new logs are being followed live
+    pub tailing: bool,
+    /// Most recent live logs, oldest first, capped at `TAIL_CAPACITY`
+    pub tail: VecDeque<LogEntry>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct InteractState {
     pub focus: InteractFocus,
     pub selected_deployment: usize,
     pub selected_function: usize,
+    /// Function list filter: matches names, signatures or selectors
+    pub function_filter: String,
     pub input_values: Vec<String>,
     pub current_input: usize,
     pub result: Option<String>,
@@ -116,8 +208,17 @@ pub struct InteractState {
     pub call_status: CallStatus,
     pub network_info: Option<NetworkInfo>,
     pub selected_wallet: Option<String>,
+    /// ETH value parsed from the value field of a payable function
+    pub call_value: Option<U256>,
+    /// Simulated write awaiting confirmation
+    pub tx_preview: Option<TxPreview>,
+    /// Receipt of the last mined transaction
+    pub receipt: Option<TxReceipt>,
     pub abi_selection_index: usize,
     pub selecting_abi_for: Option<usize>,
+    /// Selected row of the deployment history panel
+    pub history_index: usize,
+    pub events: EventsState,
 }
 
 /// Phase of script execution flow
@@ -125,6 +226,17 @@ pub struct InteractState {
 pub enum ScriptPhase {
     #[default]
     SelectScript,
+    /// Pick which of the script's public functions to run
+    SelectFunction {
+        selected: usize,
+    },
+    /// Typed arguments for the chosen function
+    EnterArguments {
+        function: usize,
+        args: Vec<String>,
+        current: usize,
+        error: Option<String>,
+    },
     SelectNetwork {
         selected: usize,
     },
@@ -132,7 +244,43 @@ pub enum ScriptPhase {
         network_idx: usize,
         selected: usize,
     },
-    Running,
+    /// Simulate or broadcast, verification and extra flags
+    RunOptions {
+        network_idx: usize,
+        wallet_idx: usize,
+        selected: usize,
+        options: RunOptions,
+    },
+    Running {
+        started: Instant,
+        /// How the script ended, once it has
+        exit: Option<ScriptExit>,
+    },
+    /// Saved runs, browsed with `ScriptsState::history`
+    History,
+    /// One saved run's details and output
+    HistoryRun {
+        run: usize,
+        scroll: u16,
+    },
+    /// Line diff between two saved runs' output
+    HistoryDiff {
+        old: usize,
+        new: usize,
+        scroll: u16,
+    },
+}
+
+/// Browsing state of the run history panel
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HistoryState {
+    /// Position in the filtered list
+    pub selected: usize,
+    /// Search over run details and output
+    pub query: String,
+    pub searching: bool,
+    /// Run picked as the first side of a diff
+    pub marked: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -140,16 +288,44 @@ pub struct ScriptsState {
     pub selected_script: usize,
     pub phase: ScriptPhase,
     pub output: Option<String>,
+    /// Entry point chosen for this run; `None` runs `run()`
+    pub call: Option<ScriptCall>,
+    /// Transactions of the last forge run, from its `--json` output
+    pub summary: Option<ScriptSummary>,
+    pub history: HistoryState,
+}
+
+/// A script function and its ABI-encoded arguments, passed to forge as `--sig`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptCall {
+    /// Index into the script's `functions`
+    pub function: usize,
+    pub signature: String,
+    pub calldata: Vec<u8>,
 }
 
 impl App {
-    pub fn new(project: Project, script_tx: UnboundedSender<Action>) -> Result<Self> {
+    pub fn new(projects: Vec<Project>, script_tx: UnboundedSender<Action>) -> Result<Self> {
         let config = AppConfig::load()?;
+        let project = projects
+            .first()
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("No project to open"))?;
         let deployments = DeploymentManager::new(&project);
         let scripts = Arc::new(ScriptManager::new(&project));
+        let workspace = projects
+            .into_iter()
+            .map(|project| WorkspaceMember {
+                deployments: DeploymentManager::new(&project),
+                scripts: Arc::new(ScriptManager::new(&project)),
+                project,
+            })
+            .collect();
 
         Ok(Self {
             project,
+            workspace,
+            workspace_scope: Some(0),
             config,
             view: View::Home,
             should_quit: false,
@@ -157,10 +333,14 @@ impl App {
             scripts,
             status_message: None,
             script_tx,
+            log_tail: None,
+            receipt_watch: None,
+            workspace_generation: 0,
+            script_cancel: None,
+            history: RunHistory::load(),
         })
     }
 
-    #[allow(dead_code)]
     pub fn set_status(&mut self, msg: impl Into<String>) {
         self.status_message = Some(msg.into());
     }
@@ -168,10 +348,48 @@ impl App {
     pub fn clear_status(&mut self) {
         self.status_message = None;
     }
+
+    /// Whether several projects were found under the root
+    pub fn is_workspace(&self) -> bool {
+        self.workspace.len() > 1
+    }
+
+    /// Manager of the project a script belongs to, which knows how to run it
+    pub fn script_manager(&self, script: &Script) -> Arc<ScriptManager> {
+        self.workspace
+            .iter()
+            .find(|member| member.project.root == script.project_root)
+            .map_or_else(|| self.scripts.clone(), |member| member.scripts.clone())
+    }
+
+    /// Show the deployments and scripts of one project, or of all of them
+    pub fn set_workspace_scope(&mut self, scope: Option<usize>) {
+        let members: Vec<&WorkspaceMember> = match scope {
+            Some(i) => self.workspace.get(i).into_iter().collect(),
+            None => self.workspace.iter().collect(),
+        };
+        let Some(first) = members.first() else {
+            return;
+        };
+
+        let mut deployments = DeploymentManager::new(&first.project);
+        let mut scripts = ScriptManager::new(&first.project);
+        for member in &members {
+            // Label entries only when they come from several projects
+            let label = scope.is_none().then_some(member.project.name.as_str());
+            deployments.merge(&member.deployments, label);
+            scripts.merge(&member.scripts, label);
+        }
+
+        self.project = first.project.clone();
+        self.deployments = deployments;
+        self.scripts = Arc::new(scripts);
+        self.workspace_scope = scope;
+    }
 }
 
 /// Main entry point for running the TUI application
-pub async fn run(project: Project) -> Result<()> {
+pub async fn run(projects: Vec<Project>) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -183,17 +401,18 @@ pub async fn run(project: Project) -> Result<()> {
     let (tx, rx) = mpsc::unbounded_channel::<Action>();
 
     // Create app state
-    let mut app = App::new(project, tx)?;
-
-    // Scan for deployments and scripts
-    let missing_chain_ids = app.deployments.scan()?;
-    Arc::get_mut(&mut app.scripts).unwrap().scan()?;
+    let mut app = App::new(projects, tx)?;
 
-    // Check for missing network configurations
-    if !missing_chain_ids.is_empty() {
-        handle_missing_networks(&mut app, &missing_chain_ids).await?;
+    // Offer networks from foundry.toml before asking for missing ones
+    let projects: Vec<Project> = app.workspace.iter().map(|m| m.project.clone()).collect();
+    for project in &projects {
+        offer_foundry_import(&mut app, project, true).await?;
     }
 
+    // Scan for deployments and scripts
+    rescan(&mut app).await?;
+    spawn_hardhat_evaluation(&app);
+
     // Run main loop
     let result = run_app(&mut terminal, &mut app, rx).await;
 
@@ -209,6 +428,138 @@ pub async fn run(project: Project) -> Result<()> {
     result
 }
 
+/// Rebuild deployments and scripts of every project from its current directories
+async fn rescan(app: &mut App) -> Result<()> {
+    app.workspace_generation += 1;
+    let mut missing_chain_ids = Vec::new();
+    for member in &mut app.workspace {
+        member.deployments = DeploymentManager::new(&member.project);
+        for chain_id in member.deployments.scan()? {
+            if !missing_chain_ids.contains(&chain_id) {
+                missing_chain_ids.push(chain_id);
+            }
+        }
+
+        let mut scripts = ScriptManager::new(&member.project);
+        scripts.scan()?;
+        member.scripts = Arc::new(scripts);
+    }
+
+    // Check for missing network configurations
+    if !missing_chain_ids.is_empty() {
+        handle_missing_networks(app, &missing_chain_ids).await?;
+    }
+
+    // Resolve proxies and diamonds from on-chain state
+    for member in &mut app.workspace {
+        let rpc_urls = configured_rpc_urls(&app.config, &member.deployments);
+        member.deployments.detect_proxies(&rpc_urls).await;
+    }
+
+    app.set_workspace_scope(app.workspace_scope);
+    Ok(())
+}
+
+/// Rescan every project in a background task, sending `Action::WorkspaceRefreshed`.
+/// Unlike `rescan` this never prompts (the terminal is in raw mode); chains without a
+/// configured network are only reported.
+fn spawn_refresh(app: &App) {
+    let projects: Vec<Project> = app.workspace.iter().map(|m| m.project.clone()).collect();
+    let config = app.config.clone();
+    let generation = app.workspace_generation;
+    let tx = app.script_tx.clone();
+    tokio::spawn(async move {
+        let result = scan_workspace(projects, &config).await;
+        let _ = tx.send(Action::WorkspaceRefreshed { generation, result });
+    });
+}
+
+/// Evaluate each Hardhat project's config in the background. Until its
+/// `Action::HardhatConfigEvaluated` arrives, the statically parsed config is used.
+fn spawn_hardhat_evaluation(app: &App) {
+    for member in &app.workspace {
+        if !member.project.is_hardhat() {
+            continue;
+        }
+        let root = member.project.root.clone();
+        let tx = app.script_tx.clone();
+        tokio::task::spawn_blocking(move || match HardhatConfig::evaluate(&root) {
+            Ok(config) => {
+                let _ = tx.send(Action::HardhatConfigEvaluated { root, config });
+            }
+            Err(e) => tracing::info!(
+                "Could not evaluate Hardhat config in {:?} ({}), keeping the parsed one",
+                root,
+                e
+            ),
+        });
+    }
+}
+
+/// Fresh deployments and scripts for each project, plus chains with no configured network
+async fn scan_workspace(
+    projects: Vec<Project>,
+    config: &AppConfig,
+) -> Result<(Vec<WorkspaceMember>, Vec<u64>)> {
+    let mut members = Vec::new();
+    let mut unconfigured = Vec::new();
+    for project in projects {
+        let mut deployments = DeploymentManager::new(&project);
+        for chain_id in deployments.scan()? {
+            if config.get_network_by_chain_id(chain_id).is_none()
+                && !unconfigured.contains(&chain_id)
+            {
+                unconfigured.push(chain_id);
+            }
+        }
+        let rpc_urls = configured_rpc_urls(config, &deployments);
+        deployments.detect_proxies(&rpc_urls).await;
+
+        let mut scripts = ScriptManager::new(&project);
+        scripts.scan()?;
+        members.push(WorkspaceMember {
+            project,
+            deployments,
+            scripts: Arc::new(scripts),
+        });
+    }
+    Ok((members, unconfigured))
+}
+
+/// Switch the scoped project to its next foundry.toml profile and rescan
+async fn switch_profile(app: &mut App) -> Result<()> {
+    let Some(scope) = app.workspace_scope else {
+        app.set_status("Select a project to switch its profile");
+        return Ok(());
+    };
+    let Some(foundry) = app.project.foundry_config() else {
+        app.set_status("Profiles are only available for Foundry projects");
+        return Ok(());
+    };
+
+    let profiles = foundry.profile_names();
+    if profiles.len() < 2 {
+        app.set_status("foundry.toml defines no profiles besides default");
+        return Ok(());
+    }
+    let current = profiles
+        .iter()
+        .position(|name| name == foundry.profile_name())
+        .unwrap_or(0);
+    let next = &profiles[(current + 1) % profiles.len()];
+
+    app.workspace[scope].project = app.project.with_profile(next)?;
+    rescan(app).await?;
+    app.set_status(format!(
+        "Profile {}: {} deployments, {} scripts",
+        next,
+        app.deployments.deployments.len(),
+        app.scripts.scripts.len()
+    ));
+
+    Ok(())
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     app: &mut App,
@@ -228,16 +579,72 @@ async fn run_app(
                         }
                     }
                 }
+                Action::TransactionMined { tx_hash, result } => {
+                    app.receipt_watch = None;
+                    if let View::Interact(state) = &mut app.view
+                        && matches!(&state.call_status, CallStatus::Pending { tx_hash: pending, .. } if *pending == tx_hash)
+                    {
+                        match result {
+                            Ok(receipt) => {
+                                state.call_status = if receipt.success {
+                                    CallStatus::Mined
+                                } else {
+                                    CallStatus::Reverted
+                                };
+                                state.receipt = Some(receipt);
+                            }
+                            Err(e) => {
+                                state.call_status = CallStatus::Failed(e.to_string());
+                                state.error = Some(format!("Failed to get receipt: {}", e));
+                            }
+                        }
+                    }
+                }
+                Action::TailLog(entry) => {
+                    if let View::Interact(state) = &mut app.view
+                        && state.events.tailing
+                        && app
+                            .deployments
+                            .deployments
+                            .get(state.selected_deployment)
+                            .and_then(|d| d.callable_address.parse().ok())
+                            == Some(entry.address)
+                    {
+                        if state.events.tail.len() == TAIL_CAPACITY {
+                            state.events.tail.pop_front();
+                        }
+                        state.events.tail.push_back(entry);
+                    }
+                }
+                Action::TailStopped(error) => {
+                    if let View::Interact(state) = &mut app.view
+                        && state.events.tailing
+                    {
+                        state.events.tailing = false;
+                        if let Some(e) = error {
+                            state.events.error = Some(format!("Live tail stopped: {}", e));
+                        }
+                    }
+                    app.log_tail = None;
+                }
+                Action::RunRecorded(record) => app.history.runs.insert(0, record),
                 Action::ScriptFinished(result) => {
-                    if let View::Scripts(state) = &mut app.view {
+                    app.script_cancel = None;
+                    let refresh = matches!(&result, Ok(output) if output.exit.success());
+                    if let View::Scripts(state) = &mut app.view
+                        && let ScriptPhase::Running { started, exit } = &mut state.phase
+                    {
                         // Keep in Running phase so output remains visible
                         // User presses Esc to return to SelectScript
                         match result {
-                            Ok(_stdout) => {
+                            Ok(output) => {
                                 state.output = Some(format!(
-                                    "{}\n─── Finished ───\nPress Esc to continue",
-                                    state.output.as_deref().unwrap_or("")
+                                    "{}\n─── Finished: {} ───\nPress Esc to continue",
+                                    state.output.as_deref().unwrap_or(""),
+                                    output.exit
                                 ));
+                                *exit = Some(output.exit);
+                                state.summary = output.summary;
                             }
                             Err(e) => {
                                 state.output = Some(format!(
@@ -245,10 +652,50 @@ async fn run_app(
                                     state.output.as_deref().unwrap_or(""),
                                     e
                                 ));
+                                *exit = Some(ScriptExit::failed(started.elapsed()));
                             }
                         }
                     }
+
+                    // Pick up contracts the script deployed
+                    if refresh {
+                        spawn_refresh(app);
+                    }
+                }
+                Action::HardhatConfigEvaluated { root, config } => {
+                    if let Some(member) = app.workspace.iter_mut().find(|m| m.project.root == root)
+                        && member.project.hardhat_config() != Some(&config)
+                    {
+                        member.project = member.project.with_hardhat_config(config);
+                        app.workspace_generation += 1;
+                        spawn_refresh(app);
+                    }
                 }
+                // The projects were rescanned or reconfigured since this refresh started
+                Action::WorkspaceRefreshed { generation, .. }
+                    if generation != app.workspace_generation => {}
+                Action::WorkspaceRefreshed { result, .. } => match result {
+                    Ok((members, unconfigured)) => {
+                        let previous = std::mem::take(&mut app.deployments.deployments);
+                        app.workspace = members;
+                        app.set_workspace_scope(app.workspace_scope);
+                        app.deployments.carry_over(&previous);
+                        let mut status = format!(
+                            "Deployments refreshed: {} contract(s)",
+                            app.deployments.deployments.len()
+                        );
+                        if !unconfigured.is_empty() {
+                            let chains: Vec<String> =
+                                unconfigured.iter().map(|id| id.to_string()).collect();
+                            status.push_str(&format!(
+                                "; no network configured for chain {}",
+                                chains.join(", ")
+                            ));
+                        }
+                        app.set_status(status);
+                    }
+                    Err(e) => app.set_status(format!("Failed to refresh deployments: {}", e)),
+                },
             }
         }
 
@@ -256,23 +703,35 @@ async fn run_app(
         terminal.draw(|frame| ui::draw(frame, app))?;
 
         // Handle events with timeout
-        if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                // Global quit: Ctrl+C or q from home
-                if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-                    app.should_quit = true;
-                }
+        if event::poll(Duration::from_millis(50))?
+            && let Event::Key(key) = event::read()?
+        {
+            // Global quit: Ctrl+C or q from home
+            if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                app.should_quit = true;
+            }
 
-                // Handle input based on current view
-                match &app.view {
-                    View::Home => handle_home_input(app, key.code),
-                    View::Interact(_) => handle_interact_input(app, key.code).await,
-                    View::Scripts(_) => handle_scripts_input(app, key.code).await,
-                    View::Config => handle_config_input(app, key.code),
-                }
+            // Handle input based on current view
+            match &app.view {
+                View::Home => handle_home_input(app, key.code).await,
+                View::Interact(_) => handle_interact_input(app, key.code).await,
+                View::Scripts(_) => handle_scripts_input(app, key.code).await,
+                View::Config => handle_config_input(app, key.code).await,
             }
         }
 
+        // Stop the live tail once its deployment or view is gone
+        let tailing = matches!(&app.view, View::Interact(s) if s.events.tailing);
+        if !tailing && let Some(handle) = app.log_tail.take() {
+            handle.abort();
+        }
+
+        // Stop waiting for a receipt nobody is looking at any more
+        let pending = matches!(&app.view, View::Interact(s) if matches!(s.call_status, CallStatus::Pending { .. }));
+        if !pending && let Some(handle) = app.receipt_watch.take() {
+            handle.abort();
+        }
+
         if app.should_quit {
             break;
         }
@@ -281,7 +740,7 @@ async fn run_app(
     Ok(())
 }
 
-fn handle_home_input(app: &mut App, key: KeyCode) {
+async fn handle_home_input(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Char('q') => app.should_quit = true,
         KeyCode::Char('i') => {
@@ -296,6 +755,27 @@ fn handle_home_input(app: &mut App, key: KeyCode) {
             app.view = View::Config;
             app.clear_status();
         }
+        KeyCode::Char('p') => {
+            if let Err(e) = switch_profile(app).await {
+                app.set_status(format!("Failed to switch profile: {}", e));
+            }
+        }
+        // Project picker: "All projects" first, then each project
+        KeyCode::Up | KeyCode::Char('k') if app.is_workspace() => {
+            let scope = match app.workspace_scope {
+                Some(0) | None => None,
+                Some(i) => Some(i - 1),
+            };
+            app.set_workspace_scope(scope);
+        }
+        KeyCode::Down | KeyCode::Char('j') if app.is_workspace() => {
+            let last = app.workspace.len() - 1;
+            let scope = match app.workspace_scope {
+                None => 0,
+                Some(i) => (i + 1).min(last),
+            };
+            app.set_workspace_scope(Some(scope));
+        }
         _ => {}
     }
 }
@@ -308,12 +788,13 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
         _ => return,
     };
 
-    let (focus, input_values, current_input, selected_wallet) = match &app.view {
+    let (focus, input_values, current_input, selected_wallet, call_value) = match &app.view {
         View::Interact(s) => (
             s.focus.clone(),
             s.input_values.clone(),
             s.current_input,
             s.selected_wallet.clone(),
+            s.call_value,
         ),
         _ => return,
     };
@@ -331,6 +812,11 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
         .get(selected_deployment_idx)
         .cloned();
 
+    let visible = match (&app.view, deployment_clone.as_ref()) {
+        (View::Interact(s), Some(d)) => visible_functions(&d.functions, &s.function_filter),
+        _ => vec![],
+    };
+
     match focus {
         InteractFocus::Deployments => match key {
             KeyCode::Esc => app.view = View::Home,
@@ -338,10 +824,13 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                 if let View::Interact(state) = &mut app.view {
                     state.selected_deployment = state.selected_deployment.saturating_sub(1);
                     state.selected_function = 0;
+                    state.function_filter.clear();
                     state.result = None;
                     state.error = None;
+                    state.receipt = None;
                     state.network_info = None;
                     state.call_status = CallStatus::Idle;
+                    state.events = EventsState::default();
                 }
             }
             KeyCode::Down | KeyCode::Char('j') => {
@@ -349,52 +838,67 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                     let max = deployments_count.saturating_sub(1);
                     state.selected_deployment = (state.selected_deployment + 1).min(max);
                     state.selected_function = 0;
+                    state.function_filter.clear();
                     state.result = None;
                     state.error = None;
+                    state.receipt = None;
                     state.network_info = None;
                     state.call_status = CallStatus::Idle;
+                    state.events = EventsState::default();
                 }
             }
-            KeyCode::Enter | KeyCode::Tab | KeyCode::Right => {
-                if deployments_count > 0 {
-                    // Check if this is a proxy that needs implementation confirmation
-                    // A contract is considered a proxy if callable_address != address
-                    if let Some(deployment) = deployment_clone.as_ref() {
-                        let is_behind_proxy = deployment.callable_address != deployment.address;
-                        if is_behind_proxy && !deployment.implementation_set {
-                            if let View::Interact(state) = &mut app.view {
-                                state.focus = InteractFocus::ImplementationPrompt;
-                                state.abi_selection_index = state.selected_deployment;
-                            }
-                            return;
-                        }
-                    }
-                    // Normal flow: go to functions if available
-                    if functions_count > 0 {
-                        if let View::Interact(state) = &mut app.view {
-                            state.focus = InteractFocus::Functions;
-                        }
+            KeyCode::Enter | KeyCode::Tab | KeyCode::Right if deployments_count > 0 => {
+                // Check if this is a proxy that needs implementation confirmation
+                // (guessed from names/arguments, or read on-chain without a matching ABI)
+                if let Some(deployment) = deployment_clone.as_ref()
+                    && deployment.is_proxy
+                    && !deployment.implementation_set
+                {
+                    if let View::Interact(state) = &mut app.view {
+                        state.focus = InteractFocus::ImplementationPrompt;
+                        state.abi_selection_index = state.selected_deployment;
                     }
+                    return;
+                }
+                // Normal flow: go to functions if available
+                if functions_count > 0
+                    && let View::Interact(state) = &mut app.view
+                {
+                    state.focus = InteractFocus::Functions;
+                }
+            }
+            KeyCode::Char('e') if deployments_count > 0 => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Events;
+                }
+            }
+            KeyCode::Char('h') if deployments_count > 0 => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::History;
+                    state.history_index = deployment_clone
+                        .as_ref()
+                        .and_then(|d| d.current_instance())
+                        .unwrap_or(0);
                 }
             }
             KeyCode::Char('a') => {
-                 if let View::Interact(state) = &mut app.view {
-                     state.focus = InteractFocus::AbiSelection;
-                     state.abi_selection_index = state.selected_deployment;
-                     state.selecting_abi_for = Some(state.selected_deployment);
-                 }
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::AbiSelection;
+                    state.abi_selection_index = state.selected_deployment;
+                    state.selecting_abi_for = Some(state.selected_deployment);
+                }
             }
             _ => {}
         },
 
         InteractFocus::AbiSelection => match key {
             KeyCode::Esc => {
-                 if let View::Interact(state) = &mut app.view {
-                     state.focus = InteractFocus::Deployments;
-                     state.selecting_abi_for = None;
-                 }
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Deployments;
+                    state.selecting_abi_for = None;
+                }
             }
-             KeyCode::Up | KeyCode::Char('k') => {
+            KeyCode::Up | KeyCode::Char('k') => {
                 if let View::Interact(state) = &mut app.view {
                     state.abi_selection_index = state.abi_selection_index.saturating_sub(1);
                 }
@@ -411,24 +915,63 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                     _ => return,
                 };
                 let target_idx = match &app.view {
-                     View::Interact(s) => s.selecting_abi_for,
-                     _ => return,
+                    View::Interact(s) => s.selecting_abi_for,
+                    _ => return,
                 };
 
                 if let Some(target_idx) = target_idx {
                     // Clone ABI info from source
-                     if let Some(source) = app.deployments.deployments.get(abi_idx).cloned() {
-                          if let Some(target) = app.deployments.deployments.get_mut(target_idx) {
-                               target.functions = source.functions;
-                               target.abi_path = source.abi_path;
-                          }
-                     }
+                    if let Some(source) = app.deployments.deployments.get(abi_idx).cloned()
+                        && let Some(target) = app.deployments.deployments.get_mut(target_idx)
+                    {
+                        target.functions = source.functions;
+                        target.events = source.events;
+                        target.errors = source.errors;
+                        target.abi_path = source.abi_path;
+                        target.abi_chosen = true;
+                    }
+                }
+
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Deployments;
+                    state.selecting_abi_for = None;
+                    state.selected_function = 0; // Reset function selection
+                    state.function_filter.clear();
                 }
+            }
+            _ => {}
+        },
 
+        InteractFocus::History => match key {
+            KeyCode::Esc | KeyCode::Char('h') => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Deployments;
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                if let View::Interact(state) = &mut app.view {
+                    state.history_index = state.history_index.saturating_sub(1);
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if let View::Interact(state) = &mut app.view {
+                    let max = deployment_clone
+                        .as_ref()
+                        .map(|d| d.history.len().saturating_sub(1))
+                        .unwrap_or(0);
+                    state.history_index = (state.history_index + 1).min(max);
+                }
+            }
+            KeyCode::Enter => {
                 if let View::Interact(state) = &mut app.view {
-                     state.focus = InteractFocus::Deployments;
-                     state.selecting_abi_for = None;
-                     state.selected_function = 0; // Reset function selection
+                    app.deployments
+                        .select_instance(selected_deployment_idx, state.history_index);
+                    state.focus = InteractFocus::Deployments;
+                    state.result = None;
+                    state.error = None;
+                    state.receipt = None;
+                    state.call_status = CallStatus::Idle;
+                    state.events = EventsState::default();
                 }
             }
             _ => {}
@@ -460,16 +1003,16 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                 };
 
                 // Clone ABI info from source to current deployment
-                if let Some(source) = app.deployments.deployments.get(abi_idx).cloned() {
-                    if let Some(target) = app
-                        .deployments
-                        .deployments
-                        .get_mut(selected_deployment_idx)
-                    {
-                        target.functions = source.functions;
-                        target.abi_path = source.abi_path;
-                        target.implementation_set = true;
-                    }
+                if let Some(source) = app.deployments.deployments.get(abi_idx).cloned()
+                    && let Some(target) =
+                        app.deployments.deployments.get_mut(selected_deployment_idx)
+                {
+                    target.functions = source.functions;
+                    target.events = source.events;
+                    target.errors = source.errors;
+                    target.abi_path = source.abi_path;
+                    target.abi_chosen = true;
+                    target.implementation_set = true;
                 }
 
                 // Go to functions
@@ -482,6 +1025,7 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
 
                 if let View::Interact(state) = &mut app.view {
                     state.selected_function = 0;
+                    state.function_filter.clear();
                     if new_functions_count > 0 {
                         state.focus = InteractFocus::Functions;
                     } else {
@@ -491,11 +1035,7 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
             }
             KeyCode::Char('s') => {
                 // Skip - use current ABI as-is
-                if let Some(target) = app
-                    .deployments
-                    .deployments
-                    .get_mut(selected_deployment_idx)
-                {
+                if let Some(target) = app.deployments.deployments.get_mut(selected_deployment_idx) {
                     target.implementation_set = true;
                 }
 
@@ -516,53 +1056,100 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                     state.focus = InteractFocus::Deployments;
                 }
             }
+            KeyCode::Char('e') => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Events;
+                }
+            }
+            KeyCode::Char('/') => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::FunctionSearch;
+                }
+            }
             KeyCode::Up | KeyCode::Char('k') => {
                 if let View::Interact(state) = &mut app.view {
-                    state.selected_function = state.selected_function.saturating_sub(1);
+                    state.selected_function =
+                        step_function(&visible, state.selected_function, false);
                     state.result = None;
                     state.error = None;
                 }
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 if let View::Interact(state) = &mut app.view {
-                    let max = functions_count.saturating_sub(1);
-                    state.selected_function = (state.selected_function + 1).min(max);
+                    state.selected_function =
+                        step_function(&visible, state.selected_function, true);
                     state.result = None;
                     state.error = None;
                 }
             }
-            KeyCode::Enter => {
-                if let Some(deployment) = deployment_clone.as_ref() {
-                    if let Some(func) = deployment.functions.get(selected_function_idx) {
-                        if func.inputs.is_empty() {
-                            let is_write = !ContractCaller::is_read_only(func);
-                            if is_write {
-                                if let View::Interact(state) = &mut app.view {
-                                    state.selected_wallet =
-                                        app.config.defaults.as_ref().and_then(|d| d.wallet.clone());
-                                    state.focus = InteractFocus::WalletSelection;
-                                }
-                            } else {
-                                execute_function_call(
-                                    app,
-                                    selected_deployment_idx,
-                                    selected_function_idx,
-                                    vec![],
-                                    None,
-                                )
-                                .await;
+            KeyCode::Enter if visible.contains(&selected_function_idx) => {
+                if let Some(deployment) = deployment_clone.as_ref()
+                    && let Some(func) = deployment.functions.get(selected_function_idx)
+                {
+                    let is_payable = ContractCaller::is_payable(func);
+                    if func.inputs.is_empty() && !is_payable {
+                        let is_write = !ContractCaller::is_read_only(func);
+                        if is_write {
+                            if let View::Interact(state) = &mut app.view {
+                                state.selected_wallet =
+                                    app.config.defaults.as_ref().and_then(|d| d.wallet.clone());
+                                state.focus = InteractFocus::WalletSelection;
                             }
-                        } else if let View::Interact(state) = &mut app.view {
-                            state.input_values = vec![String::new(); func.inputs.len()];
-                            state.current_input = 0;
-                            state.focus = InteractFocus::Inputs;
+                        } else {
+                            execute_function_call(
+                                app,
+                                selected_deployment_idx,
+                                selected_function_idx,
+                                vec![],
+                                None,
+                                None,
+                            )
+                            .await;
                         }
+                    } else if let View::Interact(state) = &mut app.view {
+                        // Payable functions get an extra trailing field for the ETH value
+                        state.input_values =
+                            vec![String::new(); func.inputs.len() + usize::from(is_payable)];
+                        state.call_value = None;
+                        state.current_input = 0;
+                        state.focus = InteractFocus::Inputs;
                     }
                 }
             }
             _ => {}
         },
 
+        InteractFocus::FunctionSearch => {
+            if let View::Interact(state) = &mut app.view {
+                match key {
+                    KeyCode::Esc => {
+                        state.function_filter.clear();
+                        state.focus = InteractFocus::Functions;
+                    }
+                    KeyCode::Enter | KeyCode::Down | KeyCode::Tab => {
+                        state.focus = InteractFocus::Functions;
+                    }
+                    KeyCode::Backspace => {
+                        state.function_filter.pop();
+                    }
+                    KeyCode::Char(c) => state.function_filter.push(c),
+                    _ => {}
+                }
+
+                // Keep the selection on a function that is still listed
+                if let Some(deployment) = deployment_clone.as_ref() {
+                    let visible = visible_functions(&deployment.functions, &state.function_filter);
+                    if !visible.contains(&state.selected_function)
+                        && let Some(&first) = visible.first()
+                    {
+                        state.selected_function = first;
+                        state.result = None;
+                        state.error = None;
+                    }
+                }
+            }
+        }
+
         InteractFocus::Inputs => match key {
             KeyCode::Esc => {
                 if let View::Interact(state) = &mut app.view {
@@ -579,7 +1166,28 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                     if let Some(func) = deployment.functions.get(selected_function_idx) {
                         let is_write = !ContractCaller::is_read_only(func);
                         if is_write {
+                            let call_value = if ContractCaller::is_payable(func) {
+                                let value_input = input_values
+                                    .get(func.inputs.len())
+                                    .map(String::as_str)
+                                    .unwrap_or("");
+                                match parse_eth_value(value_input) {
+                                    Ok(value) => Some(value),
+                                    Err(e) => {
+                                        if let View::Interact(state) = &mut app.view {
+                                            state.current_input = func.inputs.len();
+                                            state.error = Some(e.to_string());
+                                        }
+                                        return;
+                                    }
+                                }
+                            } else {
+                                None
+                            };
+
                             if let View::Interact(state) = &mut app.view {
+                                state.error = None;
+                                state.call_value = call_value;
                                 state.selected_wallet =
                                     app.config.defaults.as_ref().and_then(|d| d.wallet.clone());
                                 state.focus = InteractFocus::WalletSelection;
@@ -591,62 +1199,63 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                                 selected_function_idx,
                                 input_values,
                                 None,
+                                None,
                             )
                             .await;
                         }
                     }
-                    if let View::Interact(state) = &mut app.view {
-                        if state.focus != InteractFocus::WalletSelection {
-                            state.focus = InteractFocus::Functions;
-                            state.input_values.clear();
-                        }
+                    if let View::Interact(state) = &mut app.view
+                        && state.focus != InteractFocus::WalletSelection
+                    {
+                        state.focus = InteractFocus::Functions;
+                        state.input_values.clear();
                     }
                 }
             }
             KeyCode::Tab => {
-                if input_values.len() > 1 {
-                    if let View::Interact(state) = &mut app.view {
-                        state.current_input = (state.current_input + 1) % state.input_values.len();
-                    }
+                if input_values.len() > 1
+                    && let View::Interact(state) = &mut app.view
+                {
+                    state.current_input = (state.current_input + 1) % state.input_values.len();
                 }
             }
             KeyCode::BackTab => {
-                if input_values.len() > 1 {
-                    if let View::Interact(state) = &mut app.view {
-                        if state.current_input > 0 {
-                            state.current_input -= 1;
-                        } else {
-                            state.current_input = state.input_values.len() - 1;
-                        }
+                if input_values.len() > 1
+                    && let View::Interact(state) = &mut app.view
+                {
+                    if state.current_input > 0 {
+                        state.current_input -= 1;
+                    } else {
+                        state.current_input = state.input_values.len() - 1;
                     }
                 }
             }
             KeyCode::Up => {
-                if current_input > 0 {
-                    if let View::Interact(state) = &mut app.view {
-                        state.current_input -= 1;
-                    }
+                if current_input > 0
+                    && let View::Interact(state) = &mut app.view
+                {
+                    state.current_input -= 1;
                 }
             }
             KeyCode::Down => {
-                if current_input + 1 < input_values.len() {
-                    if let View::Interact(state) = &mut app.view {
-                        state.current_input += 1;
-                    }
+                if current_input + 1 < input_values.len()
+                    && let View::Interact(state) = &mut app.view
+                {
+                    state.current_input += 1;
                 }
             }
             KeyCode::Backspace => {
-                if let View::Interact(state) = &mut app.view {
-                    if let Some(input) = state.input_values.get_mut(state.current_input) {
-                        input.pop();
-                    }
+                if let View::Interact(state) = &mut app.view
+                    && let Some(input) = state.input_values.get_mut(state.current_input)
+                {
+                    input.pop();
                 }
             }
             KeyCode::Char(c) => {
-                if let View::Interact(state) = &mut app.view {
-                    if let Some(input) = state.input_values.get_mut(state.current_input) {
-                        input.push(c);
-                    }
+                if let View::Interact(state) = &mut app.view
+                    && let Some(input) = state.input_values.get_mut(state.current_input)
+                {
+                    input.push(c);
                 }
             }
             _ => {}
@@ -664,60 +1273,191 @@ async fn handle_interact_input(app: &mut App, key: KeyCode) {
                     }
                 }
                 KeyCode::Up | KeyCode::Char('k') => {
-                    if wallet_count > 0 {
-                        if let View::Interact(state) = &mut app.view {
-                            let current_idx = state
-                                .selected_wallet
-                                .as_ref()
-                                .and_then(|w| wallet_names.iter().position(|n| n == w))
-                                .unwrap_or(0);
-                            let new_idx = if current_idx > 0 {
-                                current_idx - 1
-                            } else {
-                                wallet_count - 1
-                            };
-                            state.selected_wallet = wallet_names.get(new_idx).cloned();
-                        }
+                    if wallet_count > 0
+                        && let View::Interact(state) = &mut app.view
+                    {
+                        let current_idx = state
+                            .selected_wallet
+                            .as_ref()
+                            .and_then(|w| wallet_names.iter().position(|n| n == w))
+                            .unwrap_or(0);
+                        let new_idx = if current_idx > 0 {
+                            current_idx - 1
+                        } else {
+                            wallet_count - 1
+                        };
+                        state.selected_wallet = wallet_names.get(new_idx).cloned();
                     }
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
-                    if wallet_count > 0 {
+                    if wallet_count > 0
+                        && let View::Interact(state) = &mut app.view
+                    {
+                        let current_idx = state
+                            .selected_wallet
+                            .as_ref()
+                            .and_then(|w| wallet_names.iter().position(|n| n == w))
+                            .unwrap_or(0);
+                        let new_idx = (current_idx + 1) % wallet_count;
+                        state.selected_wallet = wallet_names.get(new_idx).cloned();
+                    }
+                }
+                KeyCode::Enter => {
+                    if let Some(deployment) = deployment_clone.as_ref()
+                        && let Some(func) = deployment.functions.get(selected_function_idx)
+                    {
                         if let View::Interact(state) = &mut app.view {
-                            let current_idx = state
-                                .selected_wallet
-                                .as_ref()
-                                .and_then(|w| wallet_names.iter().position(|n| n == w))
-                                .unwrap_or(0);
-                            let new_idx = (current_idx + 1) % wallet_count;
-                            state.selected_wallet = wallet_names.get(new_idx).cloned();
+                            state.focus = InteractFocus::Functions;
                         }
+                        // Drop the trailing value field of payable functions
+                        let params: Vec<String> = input_values
+                            .iter()
+                            .take(func.inputs.len())
+                            .cloned()
+                            .collect();
+                        execute_function_call(
+                            app,
+                            selected_deployment_idx,
+                            selected_function_idx,
+                            params,
+                            selected_wallet.clone(),
+                            call_value,
+                        )
+                        .await;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        InteractFocus::Events => {
+            let events = deployment_clone
+                .as_ref()
+                .map(|d| d.events.clone())
+                .unwrap_or_default();
+
+            match key {
+                KeyCode::Esc | KeyCode::Left => {
+                    if let View::Interact(state) = &mut app.view {
+                        state.focus = InteractFocus::Deployments;
+                    }
+                }
+                KeyCode::Char('f') if functions_count > 0 => {
+                    if let View::Interact(state) = &mut app.view {
+                        state.focus = InteractFocus::Functions;
+                    }
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if let View::Interact(state) = &mut app.view {
+                        state.events.selected_event = state.events.selected_event.saturating_sub(1);
+                        state.events.query_values.clear();
+                    }
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if let View::Interact(state) = &mut app.view {
+                        // Entry 0 is "all events"
+                        state.events.selected_event =
+                            (state.events.selected_event + 1).min(events.len());
+                        state.events.query_values.clear();
+                    }
+                }
+                KeyCode::Tab => {
+                    if let View::Interact(state) = &mut app.view
+                        && !state.events.logs.is_empty()
+                    {
+                        state.focus = InteractFocus::EventLogs;
                     }
                 }
+                KeyCode::Char('t') => toggle_log_tail(app, selected_deployment_idx),
                 KeyCode::Enter => {
-                    if let Some(deployment) = deployment_clone.as_ref() {
-                        if let Some(func) = deployment.functions.get(selected_function_idx) {
-                            if let View::Interact(state) = &mut app.view {
-                                state.focus = InteractFocus::Functions;
-                            }
-                            let params = if func.inputs.is_empty() {
-                                vec![]
-                            } else {
-                                input_values.clone()
-                            };
-                            execute_function_call(
-                                app,
-                                selected_deployment_idx,
-                                selected_function_idx,
-                                params,
-                                selected_wallet.clone(),
-                            )
-                            .await;
+                    if let View::Interact(state) = &mut app.view {
+                        let event = selected_event(&events, state.events.selected_event);
+                        if state.events.query_values.is_empty() {
+                            state.events.query_values = default_log_query(event);
+                            state.events.current_field = 0;
                         }
+                        state.focus = InteractFocus::EventQuery;
                     }
                 }
                 _ => {}
             }
         }
+
+        InteractFocus::EventQuery => match key {
+            KeyCode::Esc => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Events;
+                }
+            }
+            KeyCode::Enter => {
+                run_log_query(app, selected_deployment_idx).await;
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                if let View::Interact(state) = &mut app.view {
+                    let count = state.events.query_values.len().max(1);
+                    state.events.current_field = (state.events.current_field + 1) % count;
+                }
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                if let View::Interact(state) = &mut app.view {
+                    let count = state.events.query_values.len().max(1);
+                    state.events.current_field = (state.events.current_field + count - 1) % count;
+                }
+            }
+            KeyCode::Backspace => {
+                if let View::Interact(state) = &mut app.view {
+                    let field = state.events.current_field;
+                    if let Some(value) = state.events.query_values.get_mut(field) {
+                        value.pop();
+                    }
+                }
+            }
+            KeyCode::Char(c) => {
+                if let View::Interact(state) = &mut app.view {
+                    let field = state.events.current_field;
+                    if let Some(value) = state.events.query_values.get_mut(field) {
+                        value.push(c);
+                    }
+                }
+            }
+            _ => {}
+        },
+
+        InteractFocus::EventLogs if key == KeyCode::Char('t') => {
+            toggle_log_tail(app, selected_deployment_idx);
+        }
+
+        InteractFocus::EventLogs => {
+            if let View::Interact(state) = &mut app.view {
+                let last = state.events.logs.len().saturating_sub(1);
+                let selected = &mut state.events.selected_log;
+                match key {
+                    KeyCode::Esc | KeyCode::Tab => state.focus = InteractFocus::Events,
+                    KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(last),
+                    KeyCode::PageUp => *selected = selected.saturating_sub(LOG_PAGE_SIZE),
+                    KeyCode::PageDown => *selected = (*selected + LOG_PAGE_SIZE).min(last),
+                    KeyCode::Home | KeyCode::Char('g') => *selected = 0,
+                    KeyCode::End | KeyCode::Char('G') => *selected = last,
+                    _ => {}
+                }
+            }
+        }
+
+        InteractFocus::ConfirmTransaction => match key {
+            KeyCode::Esc => {
+                if let View::Interact(state) = &mut app.view {
+                    state.focus = InteractFocus::Functions;
+                    state.tx_preview = None;
+                    state.result = Some("Transaction cancelled".to_string());
+                    state.call_status = CallStatus::Completed;
+                }
+            }
+            KeyCode::Enter => {
+                send_confirmed_transaction(app, selected_deployment_idx, selected_wallet).await;
+            }
+            _ => {}
+        },
     }
 }
 
@@ -727,12 +1467,19 @@ async fn execute_function_call(
     function_idx: usize,
     params: Vec<String>,
     wallet_name: Option<String>,
+    value: Option<U256>,
 ) {
     // First, extract deployment info without holding borrow on state
-    let deployment_info = app.deployments.deployments.get(deployment_idx)
-        .map(|d| (d.chain_id, d.callable_address.clone(), d.functions.clone()));
-
-    let (chain_id, callable_address, functions) = match deployment_info {
+    let deployment_info = app.deployments.deployments.get(deployment_idx).map(|d| {
+        (
+            d.chain_id,
+            d.callable_address.clone(),
+            d.functions.clone(),
+            d.errors.clone(),
+        )
+    });
+
+    let (chain_id, callable_address, functions, errors) = match deployment_info {
         Some(info) => info,
         None => {
             if let View::Interact(state) = &mut app.view {
@@ -756,55 +1503,25 @@ async fn execute_function_call(
     if let View::Interact(state) = &mut app.view {
         state.result = None;
         state.error = None;
+        state.receipt = None;
     }
 
-    // Try to find the network that matches the deployment's chain ID
-    // Do this outside the state borrow so we can prompt for network if needed
-    let network_result = app.config.get_network_by_chain_id(chain_id);
-
-    let (network_name, rpc_url): (String, String) = match network_result {
-        Some((name, _network)) => {
-            // Found network with matching chain ID - use it
-            match app.config.resolve_rpc_url(name) {
-                Ok(Some(url)) => (name.clone(), url),
-                Ok(None) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.error =
-                            Some(format!("No RPC URL configured for network: {}", name));
-                        state.call_status = CallStatus::Failed("No RPC URL".to_string());
-                    }
-                    return;
-                }
-                Err(e) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.error = Some(format!("Failed to resolve RPC URL: {}", e));
-                        state.call_status = CallStatus::Failed(format!("RPC error: {}", e));
-                    }
-                    return;
-                }
+    // Resolve the network outside the state borrow so we can prompt for it if needed
+    let (network_name, rpc_url) = match resolve_chain_rpc(app, chain_id) {
+        Ok(Some(network)) => network,
+        Ok(None) => {
+            // User cancelled
+            if let View::Interact(state) = &mut app.view {
+                state.call_status = CallStatus::Idle;
             }
+            return;
         }
-        None => {
-            // No network with matching chain ID found - prompt user to add one
-            let suggested_name = crate::contracts::chain_id_to_network(chain_id);
-
-            match prompt_add_network_for_chain(app, chain_id, &suggested_name) {
-                Ok(Some(url)) => (suggested_name, url),
-                Ok(None) => {
-                    // User cancelled
-                    if let View::Interact(state) = &mut app.view {
-                        state.call_status = CallStatus::Idle;
-                    }
-                    return;
-                }
-                Err(e) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.error = Some(format!("Failed to add network: {}", e));
-                        state.call_status = CallStatus::Failed("Config error".to_string());
-                    }
-                    return;
-                }
+        Err(e) => {
+            if let View::Interact(state) = &mut app.view {
+                state.error = Some(e.to_string());
+                state.call_status = CallStatus::Failed("No RPC URL".to_string());
             }
+            return;
         }
     };
 
@@ -818,7 +1535,7 @@ async fn execute_function_call(
         state.call_status = CallStatus::Connecting;
     }
 
-    let caller = ContractCaller::new(&rpc_url, chain_id);
+    let caller = ContractCaller::new(&rpc_url, chain_id).with_errors(errors);
 
     let result = if ContractCaller::is_read_only(&func) {
         if let View::Interact(state) = &mut app.view {
@@ -826,48 +1543,228 @@ async fn execute_function_call(
         }
         caller.call_read(&callable_address, &func, &params).await
     } else {
-        let resolved_wallet = wallet_name
-            .or_else(|| app.config.defaults.as_ref().and_then(|d| d.wallet.clone()));
+        if let View::Interact(state) = &mut app.view {
+            state.call_status = CallStatus::Simulating;
+        }
+        match with_wallet_signer(app, caller, wallet_name) {
+            Ok(caller) => {
+                caller
+                    .simulate_write(&callable_address, &func, &params, value)
+                    .await
+            }
+            Err(e) => Err(e),
+        }
+    };
 
-        match resolved_wallet {
-            Some(w_name) => match app.config.resolve_wallet_key(&w_name) {
-                Ok(Some(private_key)) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.call_status = CallStatus::Executing;
-                    }
-                    match caller.with_signer(private_key) {
-                        Ok(caller_with_signer) => {
-                            caller_with_signer
-                                .call_write(&callable_address, &func, &params, None)
-                                .await
-                        }
-                        Err(e) => Err(eyre::eyre!("Failed to set signer: {}", e)),
-                    }
-                }
-                Ok(None) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.call_status = CallStatus::Failed("Wallet not found".to_string());
-                    }
-                    Err(eyre::eyre!("Private key not found for wallet: {}", w_name))
-                }
-                Err(e) => {
-                    if let View::Interact(state) = &mut app.view {
-                        state.call_status = CallStatus::Failed(format!("Wallet error: {}", e));
-                    }
-                    Err(e)
-                }
-            },
-            None => {
-                if let View::Interact(state) = &mut app.view {
-                    state.call_status = CallStatus::Failed("No wallet configured".to_string());
-                }
-                Err(eyre::eyre!(
-                    "Write transaction requires a wallet. Configure one in settings."
-                ))
+    apply_call_result(app, result, deployment_idx);
+}
+
+/// Indices of the functions that match the function list filter
+pub fn visible_functions(functions: &[ContractFunction], filter: &str) -> Vec<usize> {
+    functions
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| function_matches(f, filter))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Move the function selection to the next or previous listed function
+fn step_function(visible: &[usize], selected: usize, forward: bool) -> usize {
+    let Some(pos) = visible.iter().position(|&i| i == selected) else {
+        return visible.first().copied().unwrap_or(selected);
+    };
+    let pos = if forward {
+        (pos + 1).min(visible.len() - 1)
+    } else {
+        pos.saturating_sub(1)
+    };
+    visible[pos]
+}
+
+/// Rows skipped by PageUp/PageDown in the logs table
+const LOG_PAGE_SIZE: usize = 10;
+
+/// Live logs kept in the tail panel before the oldest are dropped
+const TAIL_CAPACITY: usize = 200;
+
+/// Event picked in the Events tab (`None` for "all events")
+fn selected_event(events: &[ContractEvent], selected: usize) -> Option<&ContractEvent> {
+    selected.checked_sub(1).and_then(|i| events.get(i))
+}
+
+/// Initial query fields: from block, to block and an empty filter per indexed parameter
+fn default_log_query(event: Option<&ContractEvent>) -> Vec<String> {
+    let indexed = event
+        .map(|e| e.inputs.iter().filter(|p| p.indexed).count())
+        .unwrap_or(0);
+    let mut values = vec!["latest-1000".to_string(), "latest".to_string()];
+    values.extend(std::iter::repeat_n(String::new(), indexed));
+    values
+}
+
+/// Run the Events tab query with eth_getLogs and show the decoded logs
+async fn run_log_query(app: &mut App, deployment_idx: usize) {
+    let Some(deployment) = app.deployments.deployments.get(deployment_idx).cloned() else {
+        return;
+    };
+    let (selected, values) = match &app.view {
+        View::Interact(s) => (s.events.selected_event, s.events.query_values.clone()),
+        _ => return,
+    };
+
+    let query = LogQuery {
+        from_block: values.first().cloned().unwrap_or_default(),
+        to_block: values.get(1).cloned().unwrap_or_default(),
+        event: selected_event(&deployment.events, selected).cloned(),
+        topic_filters: values.iter().skip(2).cloned().collect(),
+    };
+
+    let result = match resolve_chain_rpc(app, deployment.chain_id) {
+        Ok(Some((_name, rpc_url))) => ContractCaller::new(&rpc_url, deployment.chain_id)
+            .get_logs(&deployment.callable_address, &deployment.events, &query)
+            .await
+            .map(Some),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    if let View::Interact(state) = &mut app.view {
+        match result {
+            Ok(Some(((from, to), logs))) => {
+                state.events.status =
+                    Some(format!("{} log(s) in blocks {}..={}", logs.len(), from, to));
+                state.events.error = None;
+                state.events.selected_log = 0;
+                if !logs.is_empty() {
+                    state.focus = InteractFocus::EventLogs;
+                }
+                state.events.logs = logs;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                state.events.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
+
+/// Start following the deployment's logs live, or stop if already following
+fn toggle_log_tail(app: &mut App, deployment_idx: usize) {
+    if let View::Interact(state) = &mut app.view
+        && state.events.tailing
+    {
+        // The main loop aborts the task once nothing is tailing
+        state.events.tailing = false;
+        return;
+    }
+    let Some(deployment) = app.deployments.deployments.get(deployment_idx).cloned() else {
+        return;
+    };
+
+    let rpc_url = match resolve_chain_rpc(app, deployment.chain_id) {
+        Ok(Some((_name, rpc_url))) => rpc_url,
+        Ok(None) => return,
+        Err(e) => {
+            if let View::Interact(state) = &mut app.view {
+                state.events.error = Some(format!("{:#}", e));
             }
+            return;
+        }
+    };
+
+    let tx = app.script_tx.clone();
+    let handle = tokio::spawn(async move {
+        let caller = ContractCaller::new(&rpc_url, deployment.chain_id);
+        let result = caller
+            .tail_logs(&deployment.callable_address, &deployment.events, |entry| {
+                tx.send(Action::TailLog(entry)).is_ok()
+            })
+            .await;
+        let _ = tx.send(Action::TailStopped(
+            result.err().map(|e| format!("{:#}", e)),
+        ));
+    });
+
+    if let Some(previous) = app.log_tail.replace(handle.abort_handle()) {
+        previous.abort();
+    }
+    if let View::Interact(state) = &mut app.view {
+        state.events.tailing = true;
+        state.events.tail.clear();
+        state.events.error = None;
+    }
+}
+
+/// Sign and send the simulated transaction the user just confirmed
+async fn send_confirmed_transaction(
+    app: &mut App,
+    deployment_idx: usize,
+    wallet_name: Option<String>,
+) {
+    let (preview, network_info) = match &mut app.view {
+        View::Interact(state) => {
+            state.focus = InteractFocus::Functions;
+            (state.tx_preview.take(), state.network_info.clone())
         }
+        _ => return,
+    };
+    let (Some(preview), Some(network_info)) = (preview, network_info) else {
+        return;
+    };
+
+    if let View::Interact(state) = &mut app.view {
+        state.call_status = CallStatus::Executing;
+    }
+
+    let errors = app
+        .deployments
+        .deployments
+        .get(deployment_idx)
+        .map(|d| d.errors.clone())
+        .unwrap_or_default();
+    let caller =
+        ContractCaller::new(&network_info.rpc_url, network_info.chain_id).with_errors(errors);
+
+    let result = match with_wallet_signer(app, caller, wallet_name) {
+        Ok(caller) => caller.call_write(&preview).await,
+        Err(e) => Err(e),
     };
 
+    apply_call_result(app, result, deployment_idx);
+}
+
+/// Attach the key of the given wallet (or the default wallet) to a caller
+fn with_wallet_signer(
+    app: &App,
+    caller: ContractCaller,
+    wallet_name: Option<String>,
+) -> Result<ContractCaller> {
+    let wallet_name = wallet_name
+        .or_else(|| app.config.defaults.as_ref().and_then(|d| d.wallet.clone()))
+        .ok_or_else(|| {
+            eyre::eyre!("Write transaction requires a wallet. Configure one in settings.")
+        })?;
+
+    let private_key = app
+        .config
+        .resolve_wallet_key(&wallet_name)?
+        .ok_or_else(|| eyre::eyre!("Private key not found for wallet: {}", wallet_name))?;
+
+    caller
+        .with_signer(private_key)
+        .map_err(|e| eyre::eyre!("Failed to set signer: {}", e))
+}
+
+/// Record the outcome of a call in the Interact view
+fn apply_call_result(app: &mut App, result: Result<CallResult>, deployment_idx: usize) {
+    let events = app
+        .deployments
+        .deployments
+        .get(deployment_idx)
+        .map(|d| d.events.clone())
+        .unwrap_or_default();
+
     // Update state with result
     if let View::Interact(state) = &mut app.view {
         match result {
@@ -876,12 +1773,51 @@ async fn execute_function_call(
                 if outputs.is_empty() {
                     state.result = Some("Call successful (no return values)".to_string());
                 } else {
-                    state.result = Some(format!("Result: {}", outputs.join(", ")));
+                    state.result = Some(outputs.join("\n"));
                 }
             }
+            Ok(CallResult::Simulated(preview)) => {
+                state.call_status = CallStatus::Idle;
+                state.tx_preview = Some(*preview);
+                state.focus = InteractFocus::ConfirmTransaction;
+            }
+            Ok(CallResult::Reverted(reason)) => {
+                state.call_status = CallStatus::Failed("Simulation reverted".to_string());
+                state.error = Some(format!(
+                    "The call would revert, so it was not sent:\n{}",
+                    reason
+                ));
+            }
             Ok(CallResult::Write(tx_hash)) => {
-                state.call_status = CallStatus::Completed;
+                let Some(network_info) = state.network_info.clone() else {
+                    return;
+                };
+                let confirmations = app
+                    .config
+                    .networks
+                    .get(&network_info.network_name)
+                    .and_then(|n| n.confirmations)
+                    .unwrap_or(1)
+                    .max(1);
+
+                state.call_status = CallStatus::Pending {
+                    tx_hash: tx_hash.clone(),
+                    confirmations,
+                };
                 state.result = Some(format!("Transaction sent: {}", tx_hash));
+
+                // Follow the transaction in the background so the UI stays responsive
+                let tx = app.script_tx.clone();
+                let handle = tokio::spawn(async move {
+                    let watcher = ContractCaller::new(&network_info.rpc_url, network_info.chain_id);
+                    let result = watcher
+                        .wait_for_receipt(&tx_hash, confirmations, &events)
+                        .await;
+                    let _ = tx.send(Action::TransactionMined { tx_hash, result });
+                });
+                if let Some(previous) = app.receipt_watch.replace(handle.abort_handle()) {
+                    previous.abort();
+                }
             }
             Ok(CallResult::Error(msg)) => {
                 state.call_status = CallStatus::Failed(msg.clone());
@@ -895,6 +1831,45 @@ async fn execute_function_call(
     }
 }
 
+/// RPC URLs of configured networks for every chain with deployments, without prompting
+fn configured_rpc_urls(
+    config: &AppConfig,
+    deployments: &DeploymentManager,
+) -> HashMap<u64, String> {
+    let mut urls = HashMap::new();
+    for deployment in &deployments.deployments {
+        if urls.contains_key(&deployment.chain_id) {
+            continue;
+        }
+        if let Some((name, _network)) = config.get_network_by_chain_id(deployment.chain_id)
+            && let Ok(Some(url)) = config.resolve_rpc_url(name)
+        {
+            urls.insert(deployment.chain_id, url);
+        }
+    }
+    urls
+}
+
+/// Find the configured network for a chain, prompting to add one if there is none.
+/// Returns Ok(Some((network_name, rpc_url))), or Ok(None) if the user cancelled
+fn resolve_chain_rpc(app: &mut App, chain_id: u64) -> Result<Option<(String, String)>> {
+    if let Some((name, _network)) = app.config.get_network_by_chain_id(chain_id) {
+        let name = name.clone();
+        let url = app
+            .config
+            .resolve_rpc_url(&name)
+            .map_err(|e| eyre::eyre!("Failed to resolve RPC URL: {}", e))?
+            .ok_or_else(|| eyre::eyre!("No RPC URL configured for network: {}", name))?;
+        return Ok(Some((name, url)));
+    }
+
+    // No network with matching chain ID found - prompt user to add one
+    let suggested_name = crate::contracts::chain_id_to_network(chain_id);
+    let url = prompt_add_network_for_chain(app, chain_id, &suggested_name)
+        .map_err(|e| eyre::eyre!("Failed to add network: {}", e))?;
+    Ok(url.map(|url| (suggested_name, url)))
+}
+
 /// Prompt user to add an RPC URL for a specific chain ID
 /// Returns Ok(Some(rpc_url)) if added, Ok(None) if cancelled, Err on failure
 fn prompt_add_network_for_chain(
@@ -925,12 +1900,15 @@ fn prompt_add_network_for_chain(
 
     let rpc_url: String = with_restored_terminal(|| {
         Input::<String>::new()
-            .with_prompt(format!("Enter RPC URL for {} (chain {})", network_name, chain_id))
+            .with_prompt(format!(
+                "Enter RPC URL for {} (chain {})",
+                network_name, chain_id
+            ))
             .validate_with(|input: &String| {
-                if input.starts_with("http://") || input.starts_with("https://") {
+                if crate::config::is_valid_rpc_url(input) {
                     Ok(())
                 } else {
-                    Err("URL must start with http:// or https://".to_string())
+                    Err("URL must start with http(s):// or ws(s)://".to_string())
                 }
             })
             .interact()
@@ -948,6 +1926,7 @@ fn prompt_add_network_for_chain(
             chain_id: Some(chain_id),
             explorer_url: None,
             explorer_api_key: None,
+            confirmations: None,
         },
     );
 
@@ -956,14 +1935,72 @@ fn prompt_add_network_for_chain(
     Ok(Some(rpc_url))
 }
 
-async fn handle_missing_networks(app: &mut App, missing_chain_ids: &[u64]) -> Result<()> {
-    use dialoguer::{Confirm, Input};
+/// Offer to import foundry.toml `rpc_endpoints` that aren't configured networks yet.
+///
+/// On startup, endpoints the user already declined are not offered again.
+async fn offer_foundry_import(app: &mut App, project: &Project, on_startup: bool) -> Result<()> {
+    use crate::config::{import_endpoints, pending_endpoints, probe_endpoints};
+    use dialoguer::Confirm;
 
-    for &chain_id in missing_chain_ids {
-        let network_name = chain_id_to_network(chain_id);
+    let Some(foundry) = project.foundry_config() else {
+        if !on_startup {
+            app.set_status("Not a Foundry project: no foundry.toml to import from");
+        }
+        return Ok(());
+    };
 
-        // Check if we already have a network with this chain_id
-        let has_matching_network = app
+    let mut pending = pending_endpoints(foundry, &app.config);
+    if on_startup {
+        pending.retain(|(name, _)| !app.config.skipped_endpoints.contains(name));
+    }
+    if pending.is_empty() {
+        if !on_startup {
+            app.set_status("No new rpc_endpoints in foundry.toml");
+        }
+        return Ok(());
+    }
+
+    let names: Vec<&str> = pending.iter().map(|(name, _)| name.as_str()).collect();
+    let message = format!(
+        "{}/foundry.toml defines RPC endpoints not configured in runic: {}.\n\
+         Import them as networks?",
+        project.name,
+        names.join(", ")
+    );
+    let should_import = with_restored_terminal(|| {
+        Confirm::new()
+            .with_prompt(&message)
+            .default(true)
+            .interact()
+            .map_err(eyre::Error::from)
+    })?;
+
+    if !should_import {
+        if on_startup {
+            app.config
+                .skipped_endpoints
+                .extend(names.iter().map(|name| name.to_string()));
+            app.config.save()?;
+        }
+        return Ok(());
+    }
+
+    let probed = probe_endpoints(pending).await;
+    let summary = import_endpoints(&mut app.config, foundry, &probed)?;
+    app.config.save()?;
+    app.set_status(summary.to_string());
+
+    Ok(())
+}
+
+async fn handle_missing_networks(app: &mut App, missing_chain_ids: &[u64]) -> Result<()> {
+    use dialoguer::{Confirm, Input};
+
+    for &chain_id in missing_chain_ids {
+        let network_name = chain_id_to_network(chain_id);
+
+        // Check if we already have a network with this chain_id
+        let has_matching_network = app
             .config
             .networks
             .values()
@@ -992,10 +2029,10 @@ async fn handle_missing_networks(app: &mut App, missing_chain_ids: &[u64]) -> Re
                             network_name, chain_id
                         ))
                         .validate_with(|input: &String| {
-                            if input.starts_with("http://") || input.starts_with("https://") {
+                            if crate::config::is_valid_rpc_url(input) {
                                 Ok(())
                             } else {
-                                Err("URL must start with http:// or https://".to_string())
+                                Err("URL must start with http(s):// or ws(s)://".to_string())
                             }
                         })
                         .interact()
@@ -1009,6 +2046,7 @@ async fn handle_missing_networks(app: &mut App, missing_chain_ids: &[u64]) -> Re
                         chain_id: Some(chain_id),
                         explorer_url: None,
                         explorer_api_key: None,
+                        confirmations: None,
                     },
                 );
 
@@ -1024,10 +2062,100 @@ async fn handle_missing_networks(app: &mut App, missing_chain_ids: &[u64]) -> Re
     Ok(())
 }
 
+/// Manager of the script selected in the Scripts view
+pub fn selected_script_manager(app: &App) -> Arc<ScriptManager> {
+    let selected = match &app.view {
+        View::Scripts(state) => app.scripts.scripts.get(state.selected_script),
+        _ => None,
+    };
+    selected.map_or_else(|| app.scripts.clone(), |script| app.script_manager(script))
+}
+
+/// Networks offered when running a script: runic's, then any only defined in hardhat.config
+pub fn script_networks(app: &App) -> Vec<String> {
+    let mut networks: Vec<String> = app.config.networks.keys().cloned().collect();
+    for name in selected_script_manager(app).hardhat_networks.keys() {
+        if !app.config.networks.contains_key(name) {
+            networks.push(name.clone());
+        }
+    }
+    networks
+}
+
+/// Rows of the run-options step for a script
+pub fn run_option_fields(script: &Script) -> &'static [RunOptionField] {
+    match script.script_type {
+        ScriptType::Foundry => &RunOptionField::FORGE,
+        ScriptType::Hardhat => &RunOptionField::HARDHAT,
+    }
+}
+
+/// Whether a script offers more than a plain `run()` to choose from
+pub fn has_entry_points(script: &Script) -> bool {
+    match script.functions.as_slice() {
+        [] => false,
+        [only] => only.name != "run" || !only.inputs.is_empty(),
+        _ => true,
+    }
+}
+
+/// Index of the script's argument-less `run()`, which forge calls by default
+fn default_entry_point(script: &Script) -> usize {
+    script
+        .functions
+        .iter()
+        .position(|f| f.name == "run" && f.inputs.is_empty())
+        .unwrap_or(0)
+}
+
+/// Move on to network selection, starting at the default network
+fn select_script_network(app: &mut App, network_names: &[String]) {
+    let default_idx = app
+        .config
+        .defaults
+        .as_ref()
+        .and_then(|d| d.network.as_ref())
+        .and_then(|default| network_names.iter().position(|n| n == default))
+        .unwrap_or(0);
+
+    if let View::Scripts(state) = &mut app.view {
+        state.phase = ScriptPhase::SelectNetwork {
+            selected: default_idx,
+        };
+        state.output =
+            Some("Select network (↑↓ to navigate, Enter to confirm, Esc to cancel)".to_string());
+    }
+}
+
+/// Encode the chosen function call and continue to network selection
+fn choose_script_call(
+    app: &mut App,
+    script: &Script,
+    function: usize,
+    args: &[String],
+    network_names: &[String],
+) -> Result<()> {
+    let func = &script.functions[function];
+    let calldata = encode_call_data(func, args)?;
+    let call =
+        (function != default_entry_point(script) || !func.inputs.is_empty()).then(|| ScriptCall {
+            function,
+            signature: function_signature(func),
+            calldata,
+        });
+    if let View::Scripts(state) = &mut app.view {
+        state.call = call;
+    }
+    select_script_network(app, network_names);
+    Ok(())
+}
+
 async fn handle_scripts_input(app: &mut App, key: KeyCode) {
-    let scripts_manager = app.scripts.clone();
-    let scripts = &scripts_manager.scripts;
-    let network_count = app.config.networks.len();
+    let scripts_list = app.scripts.clone();
+    let scripts = &scripts_list.scripts;
+    let scripts_manager = selected_script_manager(app);
+    let network_names = script_networks(app);
+    let network_count = network_names.len();
     let wallet_count = app.config.wallets.len() + 1; // +1 for "Use env var" option
 
     // Get current phase
@@ -1056,37 +2184,48 @@ async fn handle_scripts_input(app: &mut App, key: KeyCode) {
                         state.selected_script = (selected_script + 1).min(max);
                     }
                 }
+                KeyCode::Char('h') => {
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::History;
+                        state.history.marked = None;
+                    }
+                    app.set_status(format!("{} saved run(s)", app.history.runs.len()));
+                }
                 KeyCode::Enter => {
-                    if !scripts.is_empty() && network_count > 0 {
-                        // Find default network index
-                        let default_idx = app
-                            .config
-                            .defaults
-                            .as_ref()
-                            .and_then(|d| d.network.as_ref())
-                            .and_then(|default| {
-                                app.config.networks.keys().position(|n| n == default)
-                            })
-                            .unwrap_or(0);
-
+                    if network_count == 0 {
+                        app.set_status("No networks configured. Add networks in config first.");
+                    } else if let Some(script) = scripts.get(selected_script) {
                         if let View::Scripts(state) = &mut app.view {
-                            state.phase = ScriptPhase::SelectNetwork {
-                                selected: default_idx,
-                            };
-                            state.output = Some(
-                                "Select network (↑↓ to navigate, Enter to confirm, Esc to cancel)"
-                                    .to_string(),
-                            );
+                            state.call = None;
+                        }
+                        if has_entry_points(script) {
+                            if let View::Scripts(state) = &mut app.view {
+                                state.phase = ScriptPhase::SelectFunction {
+                                    selected: default_entry_point(script),
+                                };
+                                state.output = Some(
+                                    "Select function (↑↓ to navigate, Enter to confirm, Esc to cancel)"
+                                        .to_string(),
+                                );
+                            }
+                        } else {
+                            select_script_network(app, &network_names);
                         }
-                    } else if network_count == 0 {
-                        app.set_status("No networks configured. Add networks in config first.");
                     }
                 }
                 _ => {}
             }
         }
 
-        ScriptPhase::SelectNetwork { selected } => {
+        ScriptPhase::SelectFunction { selected } => {
+            let selected_script = match &app.view {
+                View::Scripts(state) => state.selected_script,
+                _ => return,
+            };
+            let Some(script) = scripts.get(selected_script) else {
+                return;
+            };
+
             match key {
                 KeyCode::Esc => {
                     if let View::Scripts(state) = &mut app.view {
@@ -1094,6 +2233,134 @@ async fn handle_scripts_input(app: &mut App, key: KeyCode) {
                         state.output = None;
                     }
                 }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::SelectFunction {
+                            selected: selected.saturating_sub(1),
+                        };
+                    }
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let max = script.functions.len().saturating_sub(1);
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::SelectFunction {
+                            selected: (selected + 1).min(max),
+                        };
+                    }
+                }
+                KeyCode::Enter => {
+                    let Some(func) = script.functions.get(selected) else {
+                        return;
+                    };
+                    if func.inputs.is_empty() {
+                        if let Err(e) =
+                            choose_script_call(app, script, selected, &[], &network_names)
+                        {
+                            app.set_status(e.to_string());
+                        }
+                    } else if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::EnterArguments {
+                            function: selected,
+                            args: vec![String::new(); func.inputs.len()],
+                            current: 0,
+                            error: None,
+                        };
+                        state.output = Some(
+                            "Enter arguments (Tab/↑↓ to navigate, Enter to submit, Esc to go back)"
+                                .to_string(),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        ScriptPhase::EnterArguments {
+            function,
+            mut args,
+            mut current,
+            mut error,
+        } => {
+            let selected_script = match &app.view {
+                View::Scripts(state) => state.selected_script,
+                _ => return,
+            };
+            let Some(script) = scripts.get(selected_script) else {
+                return;
+            };
+
+            match key {
+                KeyCode::Esc => {
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::SelectFunction { selected: function };
+                        state.output = Some(
+                            "Select function (↑↓ to navigate, Enter to confirm, Esc to cancel)"
+                                .to_string(),
+                        );
+                    }
+                    return;
+                }
+                KeyCode::Enter if current + 1 < args.len() => current += 1,
+                KeyCode::Enter => {
+                    match choose_script_call(app, script, function, &args, &network_names) {
+                        Ok(()) => return,
+                        Err(e) => error = Some(e.to_string()),
+                    }
+                }
+                KeyCode::Tab => current = (current + 1) % args.len(),
+                KeyCode::BackTab => current = current.checked_sub(1).unwrap_or(args.len() - 1),
+                KeyCode::Up => current = current.saturating_sub(1),
+                KeyCode::Down => current = (current + 1).min(args.len() - 1),
+                KeyCode::Backspace => {
+                    if let Some(arg) = args.get_mut(current) {
+                        arg.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(arg) = args.get_mut(current) {
+                        arg.push(c);
+                    }
+                }
+                _ => return,
+            }
+
+            if let View::Scripts(state) = &mut app.view {
+                state.phase = ScriptPhase::EnterArguments {
+                    function,
+                    args,
+                    current,
+                    error,
+                };
+            }
+        }
+
+        ScriptPhase::SelectNetwork { selected } => {
+            match key {
+                KeyCode::Esc => {
+                    let function = match &app.view {
+                        View::Scripts(state) => state.call.as_ref().map(|c| c.function),
+                        _ => return,
+                    };
+                    let script = match &app.view {
+                        View::Scripts(state) => scripts.get(state.selected_script),
+                        _ => return,
+                    };
+                    let back_to_functions = script.filter(|s| has_entry_points(s));
+                    let selected_function = back_to_functions
+                        .map(|s| function.unwrap_or_else(|| default_entry_point(s)));
+                    if let View::Scripts(state) = &mut app.view {
+                        if let Some(selected) = selected_function {
+                            state.phase = ScriptPhase::SelectFunction { selected };
+                            state.output = Some(
+                                "Select function (↑↓ to navigate, Enter to confirm, Esc to cancel)"
+                                    .to_string(),
+                            );
+                        } else {
+                            state.phase = ScriptPhase::SelectScript;
+                            state.output = None;
+                        }
+                    }
+                }
                 KeyCode::Up | KeyCode::Char('k') => {
                     if let View::Scripts(state) = &mut app.view {
                         state.phase = ScriptPhase::SelectNetwork {
@@ -1127,7 +2394,7 @@ async fn handle_scripts_input(app: &mut App, key: KeyCode) {
                             selected: default_wallet_idx,
                         };
                         state.output = Some(
-                            "Select wallet (↑↓ to navigate, Enter to run, Esc to go back)"
+                            "Select wallet (↑↓ to navigate, Enter to continue, Esc to go back)"
                                 .to_string(),
                         );
                     }
@@ -1170,93 +2437,373 @@ async fn handle_scripts_input(app: &mut App, key: KeyCode) {
                     }
                 }
                 KeyCode::Enter => {
-                    // Execute the script
                     let selected_script = match &app.view {
                         View::Scripts(state) => state.selected_script,
                         _ => return,
                     };
+                    let Some(script) = scripts.get(selected_script) else {
+                        return;
+                    };
 
-                    if let Some(script) = scripts.get(selected_script) {
-                        let script_clone = script.clone();
-                        let config_clone = app.config.clone();
-                        let tx = app.script_tx.clone();
-
-                        // Get network name
-                        let network_names: Vec<String> =
-                            app.config.networks.keys().cloned().collect();
-                        let network_name =
-                            network_names.get(network_idx).cloned().unwrap_or_default();
-
-                        // Get wallet name (None = use env var)
-                        let wallet_names: Vec<String> =
-                            app.config.wallets.keys().cloned().collect();
-                        let wallet_name = if selected == 0 {
-                            None
-                        } else {
-                            wallet_names.get(selected - 1).cloned()
+                    // Start from the options last used for this script on this network
+                    let network_name = network_names.get(network_idx).cloned().unwrap_or_default();
+                    let options = app
+                        .config
+                        .script_options
+                        .get(&RunOptions::key(&script.path, &network_name))
+                        .cloned()
+                        .unwrap_or_default();
+
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::RunOptions {
+                            network_idx,
+                            wallet_idx: selected,
+                            selected: 0,
+                            options,
                         };
+                        state.output = Some(
+                            "Choose run options (↑↓ to navigate, Space to toggle, Enter to run, Esc to go back)"
+                                .to_string(),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
 
-                        if let View::Scripts(state) = &mut app.view {
-                            state.phase = ScriptPhase::Running;
-                            state.output = Some(format!(
-                                "Running {} on {} with wallet {}...\n\n",
-                                script_clone.name,
-                                network_name,
-                                wallet_name.as_deref().unwrap_or("(env)")
-                            ));
-                        }
+        ScriptPhase::RunOptions {
+            network_idx,
+            wallet_idx,
+            selected,
+            mut options,
+        } => {
+            let selected_script = match &app.view {
+                View::Scripts(state) => state.selected_script,
+                _ => return,
+            };
+            let Some(script) = scripts.get(selected_script) else {
+                return;
+            };
+            let fields = run_option_fields(script);
+            let field = fields[selected.min(fields.len() - 1)];
 
-                        // Spawn script execution
-                        tokio::spawn(async move {
-                            let (line_tx, mut line_rx) = mpsc::unbounded_channel::<String>();
-                            let tx_for_run = Some(line_tx);
+            match key {
+                KeyCode::Esc => {
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::SelectWallet {
+                            network_idx,
+                            selected: wallet_idx,
+                        };
+                        state.output = Some(
+                            "Select wallet (↑↓ to navigate, Enter to continue, Esc to go back)"
+                                .to_string(),
+                        );
+                    }
+                    return;
+                }
+                KeyCode::Up => {
+                    let selected = selected.saturating_sub(1);
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::RunOptions {
+                            network_idx,
+                            wallet_idx,
+                            selected,
+                            options,
+                        };
+                    }
+                    return;
+                }
+                KeyCode::Down | KeyCode::Tab => {
+                    let selected = (selected + 1).min(fields.len() - 1);
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::RunOptions {
+                            network_idx,
+                            wallet_idx,
+                            selected,
+                            options,
+                        };
+                    }
+                    return;
+                }
+                KeyCode::Enter => {}
+                // Text rows take every character, toggles flip on Space
+                KeyCode::Char(c) => {
+                    if let Some(text) = options.text_mut(field) {
+                        text.push(c);
+                    } else if c == ' ' {
+                        options.toggle(field);
+                    }
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::RunOptions {
+                            network_idx,
+                            wallet_idx,
+                            selected,
+                            options,
+                        };
+                    }
+                    return;
+                }
+                KeyCode::Backspace => {
+                    if let Some(text) = options.text_mut(field) {
+                        text.pop();
+                    }
+                    if let View::Scripts(state) = &mut app.view {
+                        state.phase = ScriptPhase::RunOptions {
+                            network_idx,
+                            wallet_idx,
+                            selected,
+                            options,
+                        };
+                    }
+                    return;
+                }
+                _ => return,
+            }
 
-                            let tx_clone = tx.clone();
-                            tokio::spawn(async move {
-                                while let Some(line) = line_rx.recv().await {
-                                    let _ = tx_clone.send(Action::ScriptLine(line));
-                                }
-                            });
-
-                            let result = scripts_manager
-                                .run_with_wallet(
-                                    &script_clone,
-                                    &network_name,
-                                    wallet_name.as_deref(),
-                                    &config_clone,
-                                    true,
-                                    false,
-                                    tx_for_run,
-                                )
-                                .await;
-
-                            match result {
-                                Ok(output) => {
-                                    let _ = tx.send(Action::ScriptFinished(Ok(output.stdout)));
-                                }
-                                Err(e) => {
-                                    let _ = tx.send(Action::ScriptFinished(Err(e)));
-                                }
-                            }
-                        });
+            // Enter: remember the options, then run
+            if let Err(e) = options.extra_args() {
+                app.set_status(e.to_string());
+                return;
+            }
+            let network_name = network_names.get(network_idx).cloned().unwrap_or_default();
+            app.config.script_options.insert(
+                RunOptions::key(&script.path, &network_name),
+                options.clone(),
+            );
+            if let Err(e) = app.config.save() {
+                app.set_status(format!("Failed to save run options: {}", e));
+            }
+
+            let call = match &app.view {
+                View::Scripts(state) => state.call.clone(),
+                _ => return,
+            };
+            options.calldata = call.as_ref().map(|c| c.calldata.clone());
+            let target = match &call {
+                Some(call) => format!("{}.{}", script.name, call.signature),
+                None => script.name.clone(),
+            };
+
+            let script_clone = script.clone();
+            let config_clone = app.config.clone();
+            let tx = app.script_tx.clone();
+            let history_dir = app.history.dir.clone();
+            let flags = match script.script_type {
+                ScriptType::Foundry => options.forge_args(),
+                ScriptType::Hardhat => options.extra_args(),
+            }
+            .unwrap_or_default();
+
+            // Get wallet name (None = use env var)
+            let wallet_names: Vec<String> = app.config.wallets.keys().cloned().collect();
+            let wallet_name = if wallet_idx == 0 {
+                None
+            } else {
+                wallet_names.get(wallet_idx - 1).cloned()
+            };
+
+            let mode = if script.script_type == ScriptType::Hardhat || options.broadcast {
+                "Running"
+            } else {
+                "Simulating"
+            };
+            let (cancel_tx, cancel_rx) = oneshot::channel();
+            app.script_cancel = Some(cancel_tx);
+            if let View::Scripts(state) = &mut app.view {
+                state.phase = ScriptPhase::Running {
+                    started: Instant::now(),
+                    exit: None,
+                };
+                state.summary = None;
+                state.output = Some(format!(
+                    "{} {} on {} with wallet {}...\n\n",
+                    mode,
+                    target,
+                    network_name,
+                    wallet_name.as_deref().unwrap_or("(env)")
+                ));
+            }
+
+            // Spawn script execution
+            let started_at = chrono::Local::now();
+            tokio::spawn(async move {
+                let (line_tx, mut line_rx) = mpsc::unbounded_channel::<String>();
+                let io = ScriptIo {
+                    lines: Some(line_tx),
+                    cancel: Some(cancel_rx),
+                };
+
+                let tx_clone = tx.clone();
+                tokio::spawn(async move {
+                    while let Some(line) = line_rx.recv().await {
+                        let _ = tx_clone.send(Action::ScriptLine(line));
+                    }
+                });
+
+                let result = scripts_manager
+                    .run_with_wallet(
+                        &script_clone,
+                        &network_name,
+                        wallet_name.as_deref(),
+                        &config_clone,
+                        &options,
+                        io,
+                    )
+                    .await;
+
+                // Keep an audit trail of every run
+                if let Some(dir) = history_dir {
+                    let record = RunRecord::new(
+                        &script_clone,
+                        &network_name,
+                        wallet_name.as_deref().unwrap_or("PRIVATE_KEY env var"),
+                        &flags,
+                        started_at,
+                        &result,
+                    );
+                    match RunHistory::save(&dir, &record) {
+                        Ok(_) => {
+                            let _ = tx.send(Action::RunRecorded(record));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ScriptLine(format!(
+                                "Failed to save run history: {}",
+                                e
+                            )));
+                        }
                     }
                 }
-                _ => {}
+
+                let _ = tx.send(Action::ScriptFinished(result));
+            });
+        }
+
+        ScriptPhase::History => handle_history_input(app, key),
+
+        ScriptPhase::HistoryRun { run, scroll } => {
+            if let View::Scripts(state) = &mut app.view {
+                state.phase = match scroll_key(key, scroll) {
+                    Some(scroll) => ScriptPhase::HistoryRun { run, scroll },
+                    None => ScriptPhase::History,
+                };
+            }
+        }
+
+        ScriptPhase::HistoryDiff { old, new, scroll } => {
+            if let View::Scripts(state) = &mut app.view {
+                state.phase = match scroll_key(key, scroll) {
+                    Some(scroll) => ScriptPhase::HistoryDiff { old, new, scroll },
+                    None => ScriptPhase::History,
+                };
             }
         }
 
-        ScriptPhase::Running => {
-            if key == KeyCode::Esc {
+        ScriptPhase::Running { exit, .. } => match key {
+            KeyCode::Char('c') if exit.is_none() => {
+                if let Some(cancel) = app.script_cancel.take() {
+                    let _ = cancel.send(());
+                    app.set_status(format!(
+                        "Cancelling: SIGINT sent, SIGKILL in {}s if it doesn't stop",
+                        CANCEL_GRACE.as_secs()
+                    ));
+                }
+            }
+            KeyCode::Esc if exit.is_none() => {
+                app.set_status("Script is still running; press c to cancel it");
+            }
+            KeyCode::Esc => {
                 if let View::Scripts(state) = &mut app.view {
                     state.phase = ScriptPhase::SelectScript;
                     // Keep output visible
                 }
+                app.clear_status();
+            }
+            _ => {}
+        },
+    }
+}
+
+/// Browse, search and pick runs to open or diff
+fn handle_history_input(app: &mut App, key: KeyCode) {
+    let View::Scripts(state) = &mut app.view else {
+        return;
+    };
+    let history = &mut state.history;
+    let mut status = None;
+
+    if history.searching {
+        match key {
+            KeyCode::Esc => {
+                history.query.clear();
+                history.searching = false;
+            }
+            KeyCode::Enter | KeyCode::Down | KeyCode::Tab => history.searching = false,
+            KeyCode::Backspace => {
+                history.query.pop();
+            }
+            KeyCode::Char(c) => history.query.push(c),
+            _ => {}
+        }
+    } else {
+        let visible = app.history.filtered(&history.query);
+        let current = visible.get(history.selected).copied();
+        match key {
+            KeyCode::Esc if history.marked.is_some() => history.marked = None,
+            KeyCode::Esc => state.phase = ScriptPhase::SelectScript,
+            KeyCode::Up | KeyCode::Char('k') => {
+                history.selected = history.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => history.selected += 1,
+            KeyCode::Char('/') => history.searching = true,
+            KeyCode::Enter => {
+                if let Some(run) = current {
+                    state.phase = ScriptPhase::HistoryRun { run, scroll: 0 };
+                }
             }
+            KeyCode::Char('d') => match (history.marked, current) {
+                (_, None) => {}
+                (None, Some(run)) => {
+                    history.marked = Some(run);
+                    status = Some("Marked; select another run and press d to diff".to_string());
+                }
+                (Some(marked), Some(run)) if marked == run => history.marked = None,
+                // Newest first, so the higher index is the older run
+                (Some(marked), Some(run)) => {
+                    state.phase = ScriptPhase::HistoryDiff {
+                        old: marked.max(run),
+                        new: marked.min(run),
+                        scroll: 0,
+                    };
+                    history.marked = None;
+                }
+            },
+            _ => {}
         }
     }
+
+    // Keep the selection inside the (possibly re-filtered) list
+    let count = app.history.filtered(&history.query).len();
+    history.selected = history.selected.min(count.saturating_sub(1));
+
+    if let Some(status) = status {
+        app.set_status(status);
+    }
 }
 
-fn handle_config_input(app: &mut App, key: KeyCode) {
+/// New scroll offset for a scrolling key, `None` for Esc
+fn scroll_key(key: KeyCode, scroll: u16) -> Option<u16> {
+    Some(match key {
+        KeyCode::Esc => return None,
+        KeyCode::Up | KeyCode::Char('k') => scroll.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => scroll.saturating_add(1),
+        KeyCode::PageUp => scroll.saturating_sub(10),
+        KeyCode::PageDown => scroll.saturating_add(10),
+        KeyCode::Home => 0,
+        _ => scroll,
+    })
+}
+
+async fn handle_config_input(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Esc | KeyCode::Char('q') => app.view = View::Home,
         KeyCode::Char('k') => {
@@ -1292,6 +2839,12 @@ fn handle_config_input(app: &mut App, key: KeyCode) {
                 app.set_status(format!("Export failed: {}", e));
             }
         }
+        KeyCode::Char('f') => {
+            let project = app.project.clone();
+            if let Err(e) = offer_foundry_import(app, &project, false).await {
+                app.set_status(format!("Import failed: {}", e));
+            }
+        }
         _ => {}
     }
 }
@@ -1613,10 +3166,10 @@ fn handle_add_rpc_url(app: &mut App) -> Result<()> {
         let rpc_url = Input::<String>::new()
             .with_prompt("Enter RPC URL (e.g., https://eth.llamarpc.com)")
             .validate_with(|input: &String| {
-                if input.starts_with("http://") || input.starts_with("https://") {
+                if crate::config::is_valid_rpc_url(input) {
                     Ok(())
                 } else {
-                    Err("URL must start with http:// or https://".to_string())
+                    Err("URL must start with http(s):// or ws(s)://".to_string())
                 }
             })
             .interact()?;
@@ -1640,6 +3193,7 @@ fn handle_add_rpc_url(app: &mut App) -> Result<()> {
             chain_id,
             explorer_url: None,
             explorer_api_key: None,
+            confirmations: None,
         },
     );
     app.config.save()?;
@@ -0,0 +1,270 @@
+//! Import foundry.toml `rpc_endpoints` and `[etherscan]` keys as runic networks
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use alloy::providers::{Provider, ProviderBuilder};
+use eyre::Result;
+use tokio::task::JoinSet;
+
+use super::{AppConfig, NetworkConfig, resolve_env_vars, store_api_key, store_rpc_url};
+use crate::project::{ChainRef, EtherscanConfig, FoundryConfig};
+
+/// Upper bound on each `eth_chainId` lookup, so a dead endpoint doesn't stall the import
+const CHAIN_ID_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A foundry.toml RPC endpoint, checked against the node it points to
+#[derive(Debug, Clone)]
+pub struct EndpointImport {
+    pub name: String,
+    /// URL as written in foundry.toml, possibly with `${VAR}` references
+    pub url: String,
+    /// Chain id the endpoint reported, or why it couldn't be asked
+    pub chain_id: Result<u64, String>,
+}
+
+/// What an import added and what it had to leave out
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    pub networks: Vec<String>,
+    pub api_keys: Vec<String>,
+    /// Endpoint name and reason
+    pub skipped: Vec<(String, String)>,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Imported {} network(s)", self.networks.len())?;
+        if !self.networks.is_empty() {
+            write!(f, ": {}", self.networks.join(", "))?;
+        }
+        if !self.api_keys.is_empty() {
+            write!(f, "; {} API key(s)", self.api_keys.len())?;
+        }
+        for (name, reason) in &self.skipped {
+            write!(f, "; skipped {} ({})", name, reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Endpoints in foundry.toml that aren't runic networks yet, as `(name, url)` sorted by name
+pub fn pending_endpoints(foundry: &FoundryConfig, config: &AppConfig) -> Vec<(String, String)> {
+    let mut pending: Vec<(String, String)> = foundry
+        .rpc_endpoints()
+        .into_iter()
+        .filter(|(name, _)| !config.networks.contains_key(name))
+        .map(|(name, endpoint)| (name, endpoint.url().to_string()))
+        .collect();
+    pending.sort();
+    pending
+}
+
+/// Resolve each endpoint's `${VAR}` references and ask it for its chain id
+pub async fn probe_endpoints(endpoints: Vec<(String, String)>) -> Vec<EndpointImport> {
+    let mut lookups = JoinSet::new();
+    for (name, url) in endpoints {
+        lookups.spawn(async move {
+            let chain_id = match resolve_env_vars(&url) {
+                Ok(resolved) => fetch_chain_id(&resolved).await,
+                Err(e) => Err(e.to_string()),
+            };
+            EndpointImport {
+                name,
+                url,
+                chain_id,
+            }
+        });
+    }
+
+    let mut probed = lookups.join_all().await;
+    probed.sort_by(|a, b| a.name.cmp(&b.name));
+    probed
+}
+
+/// `eth_chainId`, with errors that never echo the URL (it often embeds an API key)
+async fn fetch_chain_id(url: &str) -> Result<u64, String> {
+    let lookup = async {
+        let provider = ProviderBuilder::new().connect(url).await.ok()?;
+        provider.get_chain_id().await.ok()
+    };
+    match tokio::time::timeout(CHAIN_ID_TIMEOUT, lookup).await {
+        Ok(Some(chain_id)) => Ok(chain_id),
+        Ok(None) => Err("eth_chainId failed".to_string()),
+        Err(_) => Err("timed out".to_string()),
+    }
+}
+
+/// Add reachable endpoints as networks and map etherscan keys into `api_keys`.
+///
+/// URLs and keys that reference environment variables are kept as `${VAR}` and resolved
+/// when used; literal values are moved into the keychain like manually added ones.
+pub fn import_endpoints(
+    config: &mut AppConfig,
+    foundry: &FoundryConfig,
+    probed: &[EndpointImport],
+) -> Result<ImportSummary> {
+    let etherscan = foundry.etherscan();
+    let mut summary = ImportSummary::default();
+
+    // Every etherscan entry becomes an API key, whether or not its network was imported
+    let mut api_key_refs: HashMap<String, String> = HashMap::new();
+    let mut names: Vec<&String> = etherscan.keys().collect();
+    names.sort();
+    for name in names {
+        let Some(key) = &etherscan[name].key else {
+            continue;
+        };
+        let service = format!("etherscan_{}", name);
+        let reference = match config.api_keys.get(&service) {
+            Some(existing) => existing.clone(),
+            None => {
+                let reference = if key.contains("${") {
+                    key.clone()
+                } else {
+                    store_api_key(&service, key)?;
+                    format!("keychain:api:{}", service)
+                };
+                config.api_keys.insert(service.clone(), reference.clone());
+                summary.api_keys.push(service);
+                reference
+            }
+        };
+        api_key_refs.insert(name.clone(), reference);
+    }
+
+    for endpoint in probed {
+        let chain_id = match &endpoint.chain_id {
+            Ok(chain_id) => *chain_id,
+            Err(reason) => {
+                summary
+                    .skipped
+                    .push((endpoint.name.clone(), reason.clone()));
+                continue;
+            }
+        };
+
+        let rpc_url = if endpoint.url.contains("${") {
+            endpoint.url.clone()
+        } else {
+            store_rpc_url(&endpoint.name, &endpoint.url)?;
+            format!("keychain:{}", endpoint.name)
+        };
+        let explorer_api_key = etherscan_entry(&etherscan, &endpoint.name, chain_id)
+            .and_then(|name| api_key_refs.get(name).cloned());
+
+        config.networks.insert(
+            endpoint.name.clone(),
+            NetworkConfig {
+                rpc_url,
+                chain_id: Some(chain_id),
+                explorer_url: None,
+                explorer_api_key,
+                confirmations: None,
+            },
+        );
+        config.skipped_endpoints.retain(|n| n != &endpoint.name);
+        summary.networks.push(endpoint.name.clone());
+    }
+
+    Ok(summary)
+}
+
+/// Name of the etherscan entry for an endpoint: the same alias, or one naming its chain
+fn etherscan_entry<'a>(
+    etherscan: &'a HashMap<String, EtherscanConfig>,
+    endpoint: &str,
+    chain_id: u64,
+) -> Option<&'a String> {
+    if let Some((name, _)) = etherscan.get_key_value(endpoint) {
+        return Some(name);
+    }
+    etherscan
+        .iter()
+        .find(|(_, entry)| match &entry.chain {
+            Some(ChainRef::Id(id)) => *id == chain_id,
+            Some(ChainRef::Name(name)) => name == endpoint,
+            None => false,
+        })
+        .map(|(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn foundry_config() -> FoundryConfig {
+        toml::from_str(
+            r#"
+[rpc_endpoints]
+mainnet = "${MAINNET_RPC_URL}"
+base = "https://base.example/${BASE_KEY}"
+sepolia = "${SEPOLIA_RPC_URL}"
+
+[etherscan]
+mainnet = { key = "${ETHERSCAN_API_KEY}" }
+basescan = { key = "${BASESCAN_API_KEY}", chain = 8453 }
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pending_endpoints_skip_configured_networks() {
+        let mut config = AppConfig::default();
+        config
+            .networks
+            .insert("mainnet".to_string(), NetworkConfig::default());
+
+        let pending = pending_endpoints(&foundry_config(), &config);
+        let names: Vec<_> = pending.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["base", "sepolia"]);
+    }
+
+    #[test]
+    fn test_import_endpoints_keeps_env_references() {
+        let foundry = foundry_config();
+        let mut config = AppConfig::default();
+        config.skipped_endpoints.push("base".to_string());
+        let probed = vec![
+            EndpointImport {
+                name: "base".to_string(),
+                url: "https://base.example/${BASE_KEY}".to_string(),
+                chain_id: Ok(8453),
+            },
+            EndpointImport {
+                name: "mainnet".to_string(),
+                url: "${MAINNET_RPC_URL}".to_string(),
+                chain_id: Ok(1),
+            },
+            EndpointImport {
+                name: "sepolia".to_string(),
+                url: "${SEPOLIA_RPC_URL}".to_string(),
+                chain_id: Err("Environment variable SEPOLIA_RPC_URL is not set".to_string()),
+            },
+        ];
+
+        let summary = import_endpoints(&mut config, &foundry, &probed).unwrap();
+        assert_eq!(summary.networks, ["base", "mainnet"]);
+        assert_eq!(
+            summary.api_keys,
+            ["etherscan_basescan", "etherscan_mainnet"]
+        );
+        assert_eq!(summary.skipped.len(), 1);
+
+        let base = &config.networks["base"];
+        assert_eq!(base.rpc_url, "https://base.example/${BASE_KEY}");
+        assert_eq!(base.chain_id, Some(8453));
+        // Matched through `chain = 8453`
+        assert_eq!(
+            base.explorer_api_key.as_deref(),
+            Some("${BASESCAN_API_KEY}")
+        );
+        assert_eq!(
+            config.networks["mainnet"].explorer_api_key.as_deref(),
+            Some("${ETHERSCAN_API_KEY}")
+        );
+        assert!(!config.networks.contains_key("sepolia"));
+        assert!(config.skipped_endpoints.is_empty());
+    }
+}
@@ -102,12 +102,19 @@ pub fn get_private_key(name: &str) -> Result<Option<Zeroizing<String>>> {
     km.get_zeroizing(name)
 }
 
+/// Check that an RPC URL uses a supported transport (HTTP or WebSocket)
+pub fn is_valid_rpc_url(url: &str) -> bool {
+    ["http://", "https://", "ws://", "wss://"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
 /// Store an RPC URL securely
 pub fn store_rpc_url(name: &str, url: &str) -> Result<()> {
     // Validate URL format
-    if !url.starts_with("http://") && !url.starts_with("https://") {
+    if !is_valid_rpc_url(url) {
         return Err(eyre::eyre!(
-            "Invalid RPC URL format: must start with http:// or https://"
+            "Invalid RPC URL format: must start with http(s):// or ws(s)://"
         ));
     }
 
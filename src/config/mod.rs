@@ -1,8 +1,12 @@
+mod import;
 mod keychain;
 mod settings;
 
+pub use import::{import_endpoints, pending_endpoints, probe_endpoints};
 pub use keychain::{
-    KeychainManager, get_private_key, get_rpc_url, store_api_key, store_private_key,
-    store_rpc_url,
+    KeychainManager, get_private_key, get_rpc_url, is_valid_rpc_url, store_api_key,
+    store_private_key, store_rpc_url,
+};
+pub use settings::{
+    AppConfig, Defaults, NetworkConfig, WalletConfig, data_dir, load_chain_names, resolve_env_vars,
 };
-pub use settings::{AppConfig, Defaults, NetworkConfig, WalletConfig, load_chain_names};
@@ -6,6 +6,8 @@ use eyre::{Result, WrapErr};
 use serde::{Deserialize, Serialize};
 use toml::Table;
 
+use crate::scripts::RunOptions;
+
 const CONFIG_DIR: &str = "runic";
 const CONFIG_FILE: &str = "config.toml";
 
@@ -24,6 +26,14 @@ pub struct AppConfig {
     #[serde(default)]
     pub defaults: Option<Defaults>,
 
+    /// foundry.toml endpoints the user chose not to import, so startup doesn't ask again
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_endpoints: Vec<String>,
+
+    /// Last run options per script and network, keyed by `RunOptions::key`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub script_options: HashMap<String, RunOptions>,
+
     #[serde(skip)]
     config_path: Option<PathBuf>,
 }
@@ -34,6 +44,9 @@ pub struct NetworkConfig {
     pub chain_id: Option<u64>,
     pub explorer_url: Option<String>,
     pub explorer_api_key: Option<String>,
+    /// Blocks to wait for before a transaction is reported as mined (default 1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmations: Option<u64>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -169,11 +182,37 @@ impl AppConfig {
             use super::get_rpc_url;
             get_rpc_url(keychain_ref)
         } else {
-            Ok(Some(network.rpc_url.clone()))
+            resolve_env_vars(&network.rpc_url).map(Some)
         }
     }
 }
 
+/// Replace `${VAR}` references with the values of environment variables
+pub fn resolve_env_vars(value: &str) -> Result<String> {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let len = rest[start..]
+            .find('}')
+            .ok_or_else(|| eyre::eyre!("Unclosed ${{ reference"))?;
+        result.push_str(&rest[..start]);
+        let name = &rest[start + 2..start + len];
+        let var = std::env::var(name)
+            .map_err(|_| eyre::eyre!("Environment variable {} is not set", name))?;
+        result.push_str(&var);
+        rest = &rest[start + len + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// runic's data directory, e.g. `~/.local/share/runic`, for state that isn't configuration
+pub fn data_dir() -> Result<PathBuf> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| eyre::eyre!("Could not determine data directory"))?;
+    Ok(data_dir.join(CONFIG_DIR))
+}
+
 /// Load chain ID to network name mappings from chains.toml
 pub fn load_chain_names() -> Result<HashMap<u64, String>> {
     let config_dir =
@@ -236,4 +275,20 @@ etherscan = "keychain:etherscan_api"
             Some("sepolia".to_string())
         );
     }
+
+    #[test]
+    fn test_resolve_env_vars() {
+        // Cargo sets this for test binaries
+        assert_eq!(
+            resolve_env_vars("https://rpc/${CARGO_PKG_NAME}/v1").unwrap(),
+            "https://rpc/runic/v1"
+        );
+        assert_eq!(resolve_env_vars("no refs").unwrap(), "no refs");
+        let err = resolve_env_vars("${RUNIC_TEST_UNSET_VAR}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Environment variable RUNIC_TEST_UNSET_VAR is not set"
+        );
+        assert!(resolve_env_vars("https://rpc/${KEY").is_err());
+    }
 }
@@ -1,3 +1,4 @@
+use alloy::primitives::{Selector, keccak256};
 use eyre::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -9,6 +10,15 @@ pub struct ContractFunction {
     pub inputs: Vec<FunctionParam>,
     pub outputs: Vec<FunctionParam>,
     pub state_mutability: String,
+    /// NatSpec `@notice` from the artifact's userdoc
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// NatSpec `@dev` from the artifact's devdoc
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// Diamond facet the function's selector routes to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facet: Option<String>,
 }
 
 /// Represents a function parameter
@@ -17,6 +27,32 @@ pub struct FunctionParam {
     pub name: String,
     pub param_type: String,
     pub components: Option<Vec<FunctionParam>>,
+    /// NatSpec `@param` (inputs) or `@return` (outputs) description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+/// Represents an event from the ABI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractEvent {
+    pub name: String,
+    pub inputs: Vec<EventParam>,
+    pub anonymous: bool,
+}
+
+/// Represents an event parameter, which may be stored in a log topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventParam {
+    #[serde(flatten)]
+    pub param: FunctionParam,
+    pub indexed: bool,
+}
+
+/// Represents a custom error from the ABI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractError {
+    pub name: String,
+    pub inputs: Vec<FunctionParam>,
 }
 
 /// Parse an ABI JSON and extract callable functions
@@ -48,6 +84,9 @@ pub fn parse_abi(abi_json: &Value) -> Result<Vec<ContractFunction>> {
                 inputs,
                 outputs,
                 state_mutability,
+                notice: None,
+                details: None,
+                facet: None,
             })
         })
         .collect();
@@ -55,6 +94,77 @@ pub fn parse_abi(abi_json: &Value) -> Result<Vec<ContractFunction>> {
     Ok(functions)
 }
 
+/// Parse an ABI JSON and extract events
+pub fn parse_events(abi_json: &Value) -> Result<Vec<ContractEvent>> {
+    let abi_array = abi_json
+        .as_array()
+        .ok_or_else(|| eyre::eyre!("ABI must be a JSON array"))?;
+
+    let events: Vec<ContractEvent> = abi_array
+        .iter()
+        .filter_map(|item| {
+            if item.get("type")?.as_str()? != "event" {
+                return None;
+            }
+
+            let name = item.get("name")?.as_str()?.to_string();
+            let anonymous = item
+                .get("anonymous")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+
+            // parse_params drops entries without a type, so pair the flags up front
+            let indexed: Vec<bool> = item
+                .get("inputs")
+                .and_then(|i| i.as_array())
+                .map(|arr| {
+                    arr.iter()
+                        .filter(|p| p.get("type").and_then(|t| t.as_str()).is_some())
+                        .map(|p| p.get("indexed").and_then(|v| v.as_bool()).unwrap_or(false))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let inputs = parse_params(item.get("inputs"))
+                .into_iter()
+                .zip(indexed)
+                .map(|(param, indexed)| EventParam { param, indexed })
+                .collect();
+
+            Some(ContractEvent {
+                name,
+                inputs,
+                anonymous,
+            })
+        })
+        .collect();
+
+    Ok(events)
+}
+
+/// Parse an ABI JSON and extract custom errors
+pub fn parse_errors(abi_json: &Value) -> Result<Vec<ContractError>> {
+    let abi_array = abi_json
+        .as_array()
+        .ok_or_else(|| eyre::eyre!("ABI must be a JSON array"))?;
+
+    let errors: Vec<ContractError> = abi_array
+        .iter()
+        .filter_map(|item| {
+            if item.get("type")?.as_str()? != "error" {
+                return None;
+            }
+
+            Some(ContractError {
+                name: item.get("name")?.as_str()?.to_string(),
+                inputs: parse_params(item.get("inputs")),
+            })
+        })
+        .collect();
+
+    Ok(errors)
+}
+
 fn parse_params(params: Option<&Value>) -> Vec<FunctionParam> {
     params
         .and_then(|p| p.as_array())
@@ -81,6 +191,7 @@ fn parse_params(params: Option<&Value>) -> Vec<FunctionParam> {
                         name,
                         param_type,
                         components,
+                        doc: None,
                     })
                 })
                 .collect()
@@ -88,20 +199,109 @@ fn parse_params(params: Option<&Value>) -> Vec<FunctionParam> {
         .unwrap_or_default()
 }
 
+/// Attach NatSpec docs from a compiled artifact to its functions.
+///
+/// Reads `userdoc`/`devdoc` from the artifact's `metadata.output` (Foundry),
+/// its `rawMetadata` string, or top-level `userdoc`/`devdoc` fields.
+pub fn apply_natspec(functions: &mut [ContractFunction], artifact: &Value) {
+    let raw_metadata = artifact
+        .get("rawMetadata")
+        .and_then(|m| m.as_str())
+        .and_then(|m| serde_json::from_str::<Value>(m).ok());
+    let output = artifact
+        .get("metadata")
+        .filter(|m| m.is_object())
+        .or(raw_metadata.as_ref())
+        .and_then(|m| m.get("output"))
+        .unwrap_or(artifact);
+
+    let methods = |doc: &str| {
+        output
+            .get(doc)
+            .or_else(|| artifact.get(doc))
+            .and_then(|d| d.get("methods"))
+            .unwrap_or(&Value::Null)
+    };
+    let (userdoc, devdoc) = (methods("userdoc"), methods("devdoc"));
+    let text = |v: Option<&Value>| v.and_then(|v| v.as_str()).map(|s| s.trim().to_string());
+
+    for func in functions {
+        let signature = function_signature(func);
+        let user = userdoc.get(&signature);
+        let dev = devdoc.get(&signature);
+
+        func.notice = text(user.and_then(|u| u.get("notice")));
+        func.details = text(dev.and_then(|d| d.get("details")));
+
+        let params = dev.and_then(|d| d.get("params"));
+        for input in &mut func.inputs {
+            input.doc = text(params.and_then(|p| p.get(&input.name)));
+        }
+
+        // Unnamed return values are documented as `_0`, `_1`, ...
+        let returns = dev.and_then(|d| d.get("returns"));
+        for (i, output) in func.outputs.iter_mut().enumerate() {
+            let key = if output.name.is_empty() {
+                format!("_{}", i)
+            } else {
+                output.name.clone()
+            };
+            output.doc = text(returns.and_then(|r| r.get(&key)));
+        }
+    }
+}
+
 /// Get function signature string (for selector calculation)
-#[allow(dead_code)]
 pub fn function_signature(func: &ContractFunction) -> String {
     let params: Vec<String> = func.inputs.iter().map(encode_param_type).collect();
     format!("{}({})", func.name, params.join(","))
 }
 
-fn encode_param_type(param: &FunctionParam) -> String {
-    if let Some(components) = &param.components {
-        // Tuple type
-        let inner: Vec<String> = components.iter().map(encode_param_type).collect();
-        format!("({})", inner.join(","))
-    } else {
-        param.param_type.clone()
+/// Get the 4-byte function selector (first bytes of the signature's keccak256 hash)
+pub fn function_selector(func: &ContractFunction) -> Selector {
+    Selector::from_slice(&keccak256(function_signature(func).as_bytes())[..4])
+}
+
+/// Whether a function matches a search query by name, full signature or selector
+pub fn function_matches(func: &ContractFunction, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return true;
+    }
+    if function_signature(func).to_lowercase().contains(&query) {
+        return true;
+    }
+    let selector = hex::encode(function_selector(func));
+    query
+        .strip_prefix("0x")
+        .is_some_and(|hex| selector.starts_with(hex))
+        || selector.starts_with(&query)
+}
+
+/// Get event signature string (hashed into topic0 for non-anonymous events)
+pub fn event_signature(event: &ContractEvent) -> String {
+    let params: Vec<String> = event
+        .inputs
+        .iter()
+        .map(|p| encode_param_type(&p.param))
+        .collect();
+    format!("{}({})", event.name, params.join(","))
+}
+
+/// Get custom error signature string (for selector calculation)
+pub fn error_signature(error: &ContractError) -> String {
+    let params: Vec<String> = error.inputs.iter().map(encode_param_type).collect();
+    format!("{}({})", error.name, params.join(","))
+}
+
+/// Canonical type string for a parameter, expanding tuples (`tuple[]` -> `(address,uint256)[]`)
+pub fn encode_param_type(param: &FunctionParam) -> String {
+    match (&param.components, param.param_type.strip_prefix("tuple")) {
+        (Some(components), Some(array_suffix)) => {
+            let inner: Vec<String> = components.iter().map(encode_param_type).collect();
+            format!("({}){}", inner.join(","), array_suffix)
+        }
+        _ => param.param_type.clone(),
     }
 }
 
@@ -131,7 +331,7 @@ mod tests {
             }
         ]"#;
 
-        let functions = parse_abi_string(abi_str).unwrap();
+        let functions = parse_abi(&serde_json::from_str(abi_str).unwrap()).unwrap();
         assert_eq!(functions.len(), 2);
         assert_eq!(functions[0].name, "balanceOf");
         assert_eq!(functions[0].state_mutability, "view");
@@ -148,17 +348,174 @@ mod tests {
                     name: "to".to_string(),
                     param_type: "address".to_string(),
                     components: None,
+                    doc: None,
                 },
                 FunctionParam {
                     name: "amount".to_string(),
                     param_type: "uint256".to_string(),
                     components: None,
+                    doc: None,
                 },
             ],
             outputs: vec![],
             state_mutability: "nonpayable".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
         };
 
         assert_eq!(function_signature(&func), "transfer(address,uint256)");
+        assert_eq!(function_selector(&func).to_string(), "0xa9059cbb");
+    }
+
+    #[test]
+    fn test_apply_natspec() {
+        let artifact: Value = serde_json::from_str(
+            r#"{
+                "abi": [
+                    {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"},
+                    {"type":"function","name":"owner","inputs":[],"outputs":[{"name":"","type":"address"}],"stateMutability":"view"}
+                ],
+                "metadata": {
+                    "output": {
+                        "userdoc": {"kind":"user","methods":{"transfer(address,uint256)":{"notice":"Send tokens to a recipient"}}},
+                        "devdoc": {"kind":"dev","methods":{"transfer(address,uint256)":{
+                            "details":"Emits a Transfer event.",
+                            "params":{"to":"Recipient address","amount":"Amount in wei"},
+                            "returns":{"_0":"True on success"}
+                        }}}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut functions = parse_abi(&artifact["abi"]).unwrap();
+        apply_natspec(&mut functions, &artifact);
+
+        let transfer = &functions[0];
+        assert_eq!(
+            transfer.notice.as_deref(),
+            Some("Send tokens to a recipient")
+        );
+        assert_eq!(transfer.details.as_deref(), Some("Emits a Transfer event."));
+        assert_eq!(transfer.inputs[0].doc.as_deref(), Some("Recipient address"));
+        assert_eq!(transfer.inputs[1].doc.as_deref(), Some("Amount in wei"));
+        assert_eq!(transfer.outputs[0].doc.as_deref(), Some("True on success"));
+
+        let owner = &functions[1];
+        assert!(owner.notice.is_none() && owner.details.is_none());
+    }
+
+    #[test]
+    fn test_function_matches() {
+        let abi = r#"[
+            {"type":"function","name":"safeTransferFrom","inputs":[{"name":"from","type":"address"},{"name":"to","type":"address"},{"name":"tokenId","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"},
+            {"type":"function","name":"safeTransferFrom","inputs":[{"name":"from","type":"address"},{"name":"to","type":"address"},{"name":"tokenId","type":"uint256"},{"name":"data","type":"bytes"}],"outputs":[],"stateMutability":"nonpayable"}
+        ]"#;
+        let functions = parse_abi(&serde_json::from_str(abi).unwrap()).unwrap();
+        let (short, long) = (&functions[0], &functions[1]);
+
+        assert_eq!(function_selector(short).to_string(), "0x42842e0e");
+        assert_eq!(function_selector(long).to_string(), "0xb88d4fde");
+
+        assert!(function_matches(short, ""));
+        assert!(function_matches(short, "SafeTransfer"));
+        assert!(function_matches(long, "uint256,bytes"));
+        assert!(!function_matches(short, "uint256,bytes"));
+        assert!(function_matches(long, "0xb88d"));
+        assert!(function_matches(long, "B88D4FDE"));
+        assert!(!function_matches(short, "0xb88d"));
+        assert!(!function_matches(short, "approve"));
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let abi = serde_json::json!([
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [
+                    {"name": "from", "type": "address", "indexed": true},
+                    {"name": "to", "type": "address", "indexed": true},
+                    {"name": "value", "type": "uint256", "indexed": false}
+                ],
+                "anonymous": false
+            },
+            {
+                "type": "event",
+                "name": "Logged",
+                "inputs": [{"name": "data", "type": "bytes", "indexed": false}],
+                "anonymous": true
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            }
+        ]);
+
+        let events = parse_events(&abi).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            event_signature(&events[0]),
+            "Transfer(address,address,uint256)"
+        );
+        let indexed: Vec<bool> = events[0].inputs.iter().map(|p| p.indexed).collect();
+        assert_eq!(indexed, vec![true, true, false]);
+        assert!(!events[0].anonymous);
+        assert!(events[1].anonymous);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let abi = serde_json::json!([
+            {
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [
+                    {"name": "available", "type": "uint256"},
+                    {"name": "required", "type": "uint256"}
+                ]
+            },
+            {"type": "error", "name": "Unauthorized", "inputs": []},
+            {"type": "event", "name": "Paused", "inputs": [], "anonymous": false}
+        ]);
+
+        let errors = parse_errors(&abi).unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            error_signature(&errors[0]),
+            "InsufficientBalance(uint256,uint256)"
+        );
+        assert_eq!(error_signature(&errors[1]), "Unauthorized()");
+    }
+
+    #[test]
+    fn test_function_signature_with_tuple_array() {
+        let abi_str = r#"[
+            {
+                "type": "function",
+                "name": "multicall",
+                "inputs": [{
+                    "name": "calls",
+                    "type": "tuple[]",
+                    "components": [
+                        {"name": "target", "type": "address"},
+                        {"name": "callData", "type": "bytes"}
+                    ]
+                }],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            }
+        ]"#;
+
+        let functions = parse_abi(&serde_json::from_str(abi_str).unwrap()).unwrap();
+        assert_eq!(
+            function_signature(&functions[0]),
+            "multicall((address,bytes)[])"
+        );
     }
 }
@@ -1,15 +1,30 @@
+use std::time::Duration;
+
 use alloy::{
     network::EthereumWallet,
-    primitives::{Address, Bytes, U256},
+    primitives::{Address, B256, U256},
     providers::{Provider, ProviderBuilder},
-    rpc::types::TransactionRequest,
+    rpc::types::{Filter, Log, TransactionRequest},
     signers::local::PrivateKeySigner,
-    sol_types::SolValue,
+    transports::TransportError,
 };
-use eyre::{Result, WrapErr};
+use eyre::{Result, WrapErr, eyre};
 use zeroize::Zeroizing;
 
-use super::abi::ContractFunction;
+use super::abi::{ContractError, ContractEvent, ContractFunction, function_signature};
+use super::codec::{
+    DecodedLog, decode_log, decode_outputs, decode_revert, encode_call_data, event_topics,
+};
+
+/// How often to poll for receipts and new blocks while a transaction is pending
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait for a sent transaction to be mined and confirmed (it may have been
+/// dropped or replaced)
+const RECEIPT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How often to poll for new logs when tailing over HTTP
+const TAIL_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 /// Handles contract calls via Alloy
 pub struct ContractCaller {
@@ -17,6 +32,8 @@ pub struct ContractCaller {
     #[allow(dead_code)]
     chain_id: u64,
     signer: Option<PrivateKeySigner>,
+    /// Custom errors used to decode revert data
+    errors: Vec<ContractError>,
 }
 
 /// Result of a contract call
@@ -26,20 +43,115 @@ pub enum CallResult {
     Read(Vec<String>),
     /// Write call result (transaction hash)
     Write(String),
+    /// Write call that simulated successfully and awaits confirmation
+    Simulated(Box<TxPreview>),
+    /// Write call whose simulation reverted (decoded reason)
+    Reverted(String),
     /// Error during call
     #[allow(dead_code)]
     Error(String),
 }
 
+/// A simulated write call, ready to be confirmed and signed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPreview {
+    pub signature: String,
+    /// Arguments decoded back from the calldata, one `name: value` line each
+    pub decoded_args: Vec<String>,
+    pub calldata: Vec<u8>,
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+    /// Estimated gas, used as the transaction's gas limit
+    pub gas_limit: u64,
+    pub max_fee_per_gas: u128,
+    /// `None` when the chain only supports legacy gas pricing
+    pub max_priority_fee_per_gas: Option<u128>,
+}
+
+impl TxPreview {
+    /// Upper bound on the fee paid for this transaction, in wei
+    pub fn max_fee(&self) -> U256 {
+        U256::from(self.gas_limit) * U256::from(self.max_fee_per_gas)
+    }
+}
+
+/// An `eth_getLogs` query against one contract
+#[derive(Debug, Clone)]
+pub struct LogQuery {
+    /// Block range bounds: a number, `latest`, `earliest` or `latest-N`
+    pub from_block: String,
+    pub to_block: String,
+    /// Only return logs of this event; all of the contract's logs if `None`
+    pub event: Option<ContractEvent>,
+    /// One value per indexed parameter of `event`; empty values match anything
+    pub topic_filters: Vec<String>,
+}
+
+/// A log emitted by a contract, decoded with its events where possible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub block_number: Option<u64>,
+    pub tx_hash: Option<B256>,
+    pub address: Address,
+    pub topic0: Option<B256>,
+    /// `None` if no event in the ABI matched
+    pub decoded: Option<DecodedLog>,
+}
+
+impl LogEntry {
+    pub fn from_log(events: &[ContractEvent], log: &Log) -> Self {
+        Self {
+            block_number: log.block_number,
+            tx_hash: log.transaction_hash,
+            address: log.address(),
+            topic0: log.topics().first().copied(),
+            decoded: decode_log(events, log.topics(), &log.data().data),
+        }
+    }
+}
+
+impl std::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.decoded, self.topic0) {
+            (Some(decoded), _) => write!(f, "{}", decoded),
+            (None, Some(topic0)) => {
+                write!(f, "Unknown event from {} (topic0 {})", self.address, topic0)
+            }
+            (None, None) => write!(f, "Unknown event from {} (no topics)", self.address),
+        }
+    }
+}
+
+/// Summary of a mined transaction's receipt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxReceipt {
+    pub tx_hash: String,
+    /// False if the transaction reverted
+    pub success: bool,
+    pub block_number: Option<u64>,
+    pub gas_used: u64,
+    pub effective_gas_price: u128,
+    /// Emitted logs, decoded with the contract's events where possible
+    pub logs: Vec<String>,
+}
+
 impl ContractCaller {
     pub fn new(rpc_url: &str, chain_id: u64) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
             chain_id,
             signer: None,
+            errors: Vec::new(),
         }
     }
 
+    /// Set the contract's custom errors so reverts can be decoded
+    pub fn with_errors(mut self, errors: Vec<ContractError>) -> Self {
+        self.errors = errors;
+        self
+    }
+
     /// Set the signer for write transactions
     pub fn with_signer(mut self, private_key: Zeroizing<String>) -> Result<Self> {
         let key_str = private_key.as_str();
@@ -74,221 +186,411 @@ impl ContractCaller {
             .to(address)
             .input(calldata.into());
 
-        let result = provider.call(tx).await.wrap_err("Call failed")?;
+        let result = match provider.call(tx).await {
+            Ok(result) => result,
+            Err(e) => {
+                return match revert_reason(&e, &self.errors) {
+                    Some(reason) => Err(eyre::eyre!("Call reverted: {}", reason)),
+                    None => Err(e).wrap_err("Call failed"),
+                };
+            }
+        };
 
         // Decode the result
-        let decoded = decode_result(function, &result)?;
+        let decoded = decode_outputs(&function.outputs, &result)?;
 
         Ok(CallResult::Read(decoded))
     }
 
-    /// Execute a write transaction
-    pub async fn call_write(
+    /// Dry-run a write call from the signer's address and price it.
+    ///
+    /// Returns `CallResult::Simulated` with everything needed to send the
+    /// transaction, or `CallResult::Reverted` with the reason if it would fail.
+    pub async fn simulate_write(
         &self,
         contract_address: &str,
         function: &ContractFunction,
         params: &[String],
         value: Option<U256>,
     ) -> Result<CallResult> {
-        let signer = self
+        let from = self
             .signer
-            .clone()
+            .as_ref()
+            .map(|s| s.address())
             .ok_or_else(|| eyre::eyre!("No signer configured for write transaction"))?;
 
-        let wallet = EthereumWallet::from(signer);
-
         let provider = ProviderBuilder::new()
-            .wallet(wallet)
             .connect(&self.rpc_url)
             .await
             .wrap_err("Failed to connect to RPC")?;
 
-        let address: Address = contract_address
+        let to: Address = contract_address
             .parse()
             .wrap_err("Invalid contract address")?;
 
-        // Encode the call data
         let calldata = encode_call_data(function, params)?;
+        let value = value.unwrap_or_default();
 
-        let mut tx = TransactionRequest::default()
-            .to(address)
-            .input(calldata.into());
-
-        if let Some(v) = value {
-            tx = tx.value(v);
+        let tx = TransactionRequest::default()
+            .from(from)
+            .to(to)
+            .value(value)
+            .input(calldata.clone().into());
+
+        if let Err(e) = provider.call(tx.clone()).await {
+            return match revert_reason(&e, &self.errors) {
+                Some(reason) => Ok(CallResult::Reverted(reason)),
+                None => Err(e).wrap_err("Simulation failed"),
+            };
         }
 
-        let pending_tx = provider
-            .send_transaction(tx)
+        let gas_limit = match provider.estimate_gas(tx).await {
+            Ok(gas) => gas,
+            Err(e) => {
+                return match revert_reason(&e, &self.errors) {
+                    Some(reason) => Ok(CallResult::Reverted(reason)),
+                    None => Err(e).wrap_err("Gas estimation failed"),
+                };
+            }
+        };
+
+        // Fall back to legacy pricing on chains without EIP-1559
+        let (max_fee_per_gas, max_priority_fee_per_gas) =
+            match provider.estimate_eip1559_fees().await {
+                Ok(fees) => (fees.max_fee_per_gas, Some(fees.max_priority_fee_per_gas)),
+                Err(_) => (
+                    provider
+                        .get_gas_price()
+                        .await
+                        .wrap_err("Failed to fetch gas price")?,
+                    None,
+                ),
+            };
+
+        // Round-trip the arguments so the confirmation shows exactly what was encoded
+        let decoded_args = decode_outputs(&function.inputs, &calldata[4..])?;
+
+        Ok(CallResult::Simulated(Box::new(TxPreview {
+            signature: function_signature(function),
+            decoded_args,
+            calldata,
+            from,
+            to,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        })))
+    }
+
+    /// Sign and send a transaction that was prepared by `simulate_write`
+    pub async fn call_write(&self, preview: &TxPreview) -> Result<CallResult> {
+        let signer = self
+            .signer
+            .clone()
+            .ok_or_else(|| eyre::eyre!("No signer configured for write transaction"))?;
+
+        let wallet = EthereumWallet::from(signer);
+
+        let provider = ProviderBuilder::new()
+            .wallet(wallet)
+            .connect(&self.rpc_url)
             .await
-            .wrap_err("Failed to send transaction")?;
+            .wrap_err("Failed to connect to RPC")?;
+
+        let mut tx = TransactionRequest::default()
+            .from(preview.from)
+            .to(preview.to)
+            .value(preview.value)
+            .input(preview.calldata.clone().into())
+            .gas_limit(preview.gas_limit);
+
+        tx = match preview.max_priority_fee_per_gas {
+            Some(priority_fee) => tx
+                .max_fee_per_gas(preview.max_fee_per_gas)
+                .max_priority_fee_per_gas(priority_fee),
+            None => tx.gas_price(preview.max_fee_per_gas),
+        };
+
+        let pending_tx = match provider.send_transaction(tx).await {
+            Ok(pending_tx) => pending_tx,
+            Err(e) => {
+                return match revert_reason(&e, &self.errors) {
+                    Some(reason) => Err(eyre::eyre!("Transaction reverted: {}", reason)),
+                    None => Err(e).wrap_err("Failed to send transaction"),
+                };
+            }
+        };
 
         let tx_hash = format!("{:?}", pending_tx.tx_hash());
 
         Ok(CallResult::Write(tx_hash))
     }
 
-    /// Determine if a function is a read or write operation
-    pub fn is_read_only(function: &ContractFunction) -> bool {
-        matches!(function.state_mutability.as_str(), "view" | "pure")
+    /// Wait until a transaction is mined and has the given number of confirmations
+    pub async fn wait_for_receipt(
+        &self,
+        tx_hash: &str,
+        confirmations: u64,
+        events: &[ContractEvent],
+    ) -> Result<TxReceipt> {
+        let provider = ProviderBuilder::new()
+            .connect(&self.rpc_url)
+            .await
+            .wrap_err("Failed to connect to RPC")?;
+
+        let hash: B256 = tx_hash.parse().wrap_err("Invalid transaction hash")?;
+
+        // The receipt is fetched again on every poll, after the head: a reorg can drop the
+        // transaction or move it to another block, so only a receipt read once the head is
+        // deep enough counts
+        let poll = async {
+            loop {
+                let head = provider
+                    .get_block_number()
+                    .await
+                    .wrap_err("Failed to fetch block number")?;
+                if let Some(receipt) = provider
+                    .get_transaction_receipt(hash)
+                    .await
+                    .wrap_err("Failed to fetch transaction receipt")?
+                {
+                    // The block containing the transaction counts as the first confirmation
+                    let mined_in = receipt.block_number.unwrap_or(head);
+                    if head >= mined_in + confirmations.saturating_sub(1) {
+                        return Ok::<_, eyre::Report>(receipt);
+                    }
+                }
+                tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+            }
+        };
+        let receipt = tokio::time::timeout(RECEIPT_TIMEOUT, poll)
+            .await
+            .map_err(|_| {
+                eyre!(
+                    "transaction not confirmed after {}s; it may have been dropped or replaced",
+                    RECEIPT_TIMEOUT.as_secs()
+                )
+            })??;
+
+        let logs = receipt
+            .inner
+            .logs()
+            .iter()
+            .map(|log| LogEntry::from_log(events, log).to_string())
+            .collect();
+
+        Ok(TxReceipt {
+            tx_hash: tx_hash.to_string(),
+            success: receipt.status(),
+            block_number: receipt.block_number,
+            gas_used: receipt.gas_used,
+            effective_gas_price: receipt.effective_gas_price,
+            logs,
+        })
     }
-}
 
-/// Encode call data for a function call
-fn encode_call_data(function: &ContractFunction, params: &[String]) -> Result<Vec<u8>> {
-    use alloy::primitives::keccak256;
+    /// Fetch and decode a contract's logs over a block range.
+    ///
+    /// Returns the resolved `(from, to)` block numbers alongside the logs.
+    pub async fn get_logs(
+        &self,
+        contract_address: &str,
+        events: &[ContractEvent],
+        query: &LogQuery,
+    ) -> Result<((u64, u64), Vec<LogEntry>)> {
+        let provider = ProviderBuilder::new()
+            .connect(&self.rpc_url)
+            .await
+            .wrap_err("Failed to connect to RPC")?;
+
+        let address: Address = contract_address
+            .parse()
+            .wrap_err("Invalid contract address")?;
 
-    // Calculate function selector
-    let signature = super::abi::function_signature(function);
-    let selector = &keccak256(signature.as_bytes())[..4];
+        let latest = provider
+            .get_block_number()
+            .await
+            .wrap_err("Failed to fetch block number")?;
+        let from = resolve_block(&query.from_block, latest).wrap_err("Invalid from block")?;
+        let to = resolve_block(&query.to_block, latest).wrap_err("Invalid to block")?;
+        if from > to {
+            return Err(eyre::eyre!("From block {} is after to block {}", from, to));
+        }
 
-    // Encode parameters
-    let mut calldata = selector.to_vec();
+        let mut filter = Filter::new().address(address).from_block(from).to_block(to);
+        if let Some(event) = &query.event {
+            let topics = event_topics(event, &query.topic_filters)?;
+            // Logs carry at most four topics; a malformed ABI can declare more
+            if topics.len() > filter.topics.len() {
+                return Err(eyre!(
+                    "Event {} has {} topics, but logs can only be filtered on {}",
+                    event.name,
+                    topics.len(),
+                    filter.topics.len()
+                ));
+            }
+            for (i, topic) in topics.into_iter().enumerate() {
+                if let Some(topic) = topic {
+                    filter.topics[i] = topic.into();
+                }
+            }
+        }
 
-    for (i, param) in function.inputs.iter().enumerate() {
-        let value = params.get(i).map(|s| s.as_str()).unwrap_or("");
-        let encoded = encode_param(&param.param_type, value)?;
-        calldata.extend(encoded);
+        let logs = provider
+            .get_logs(&filter)
+            .await
+            .wrap_err("Failed to fetch logs")?;
+
+        Ok((
+            (from, to),
+            logs.iter()
+                .map(|log| LogEntry::from_log(events, log))
+                .collect(),
+        ))
     }
 
-    Ok(calldata)
-}
+    /// Follow a contract's new logs, handing each one to `on_log` until it returns false.
+    ///
+    /// WebSocket RPCs use an `eth_subscribe` log subscription; HTTP RPCs poll
+    /// `eth_getLogs` over each range of new blocks.
+    pub async fn tail_logs<F>(
+        &self,
+        contract_address: &str,
+        events: &[ContractEvent],
+        mut on_log: F,
+    ) -> Result<()>
+    where
+        F: FnMut(LogEntry) -> bool,
+    {
+        let provider = ProviderBuilder::new()
+            .connect(&self.rpc_url)
+            .await
+            .wrap_err("Failed to connect to RPC")?;
 
-/// Encode a single parameter value
-fn encode_param(param_type: &str, value: &str) -> Result<Vec<u8>> {
-    match param_type {
-        "address" => {
-            let addr: Address = value.parse().wrap_err("Invalid address")?;
-            Ok(addr.abi_encode())
-        }
-        "uint256" | "uint" => {
-            let num: U256 = if value.starts_with("0x") {
-                value.parse().wrap_err("Invalid uint256")?
-            } else {
-                U256::from_str_radix(value, 10).wrap_err("Invalid uint256")?
-            };
-            Ok(num.abi_encode())
-        }
-        "bool" => {
-            let b = value.to_lowercase() == "true" || value == "1";
-            Ok(b.abi_encode())
-        }
-        "bytes32" => {
-            let bytes: alloy::primitives::B256 = value.parse().wrap_err("Invalid bytes32")?;
-            Ok(bytes.abi_encode())
-        }
-        "bytes" => {
-            let hex_str = value.strip_prefix("0x").unwrap_or(value);
-            let bytes = hex::decode(hex_str).wrap_err("Invalid bytes")?;
-            Ok(Bytes::from(bytes).abi_encode())
-        }
-        "string" => Ok(value.to_string().abi_encode()),
-        t if t.starts_with("uint") => {
-            // Handle uint8, uint16, etc.
-            let num: U256 = if value.starts_with("0x") {
-                value.parse().wrap_err("Invalid uint")?
-            } else {
-                U256::from_str_radix(value, 10).wrap_err("Invalid uint")?
-            };
-            Ok(num.abi_encode())
+        let address: Address = contract_address
+            .parse()
+            .wrap_err("Invalid contract address")?;
+        let filter = Filter::new().address(address);
+
+        if is_websocket_url(&self.rpc_url) {
+            let mut subscription = provider
+                .subscribe_logs(&filter)
+                .await
+                .wrap_err("Failed to subscribe to logs")?;
+            loop {
+                let log = subscription
+                    .recv()
+                    .await
+                    .wrap_err("Log subscription closed")?;
+                if !on_log(LogEntry::from_log(events, &log)) {
+                    return Ok(());
+                }
+            }
         }
-        t if t.starts_with("int") => {
-            // Handle int types (simplified - treating as uint for now)
-            let num: U256 = if value.starts_with("0x") {
-                value.parse().wrap_err("Invalid int")?
-            } else {
-                U256::from_str_radix(value, 10).wrap_err("Invalid int")?
-            };
-            Ok(num.abi_encode())
+
+        let mut next_block = provider
+            .get_block_number()
+            .await
+            .wrap_err("Failed to fetch block number")?
+            + 1;
+        loop {
+            tokio::time::sleep(TAIL_POLL_INTERVAL).await;
+
+            let latest = provider
+                .get_block_number()
+                .await
+                .wrap_err("Failed to fetch block number")?;
+            if latest < next_block {
+                continue;
+            }
+
+            let logs = provider
+                .get_logs(&filter.clone().from_block(next_block).to_block(latest))
+                .await
+                .wrap_err("Failed to fetch logs")?;
+            for log in &logs {
+                if !on_log(LogEntry::from_log(events, log)) {
+                    return Ok(());
+                }
+            }
+            next_block = latest + 1;
         }
-        _ => Err(eyre::eyre!("Unsupported parameter type: {}", param_type)),
     }
-}
 
-/// Decode result bytes based on function outputs
-fn decode_result(function: &ContractFunction, data: &Bytes) -> Result<Vec<String>> {
-    if function.outputs.is_empty() || data.is_empty() {
-        return Ok(vec![]);
+    /// Determine if a function is a read or write operation
+    pub fn is_read_only(function: &ContractFunction) -> bool {
+        matches!(function.state_mutability.as_str(), "view" | "pure")
+    }
+
+    /// Determine if a function accepts ETH with the call
+    pub fn is_payable(function: &ContractFunction) -> bool {
+        function.state_mutability == "payable"
     }
+}
 
-    let mut results = Vec::new();
-    let mut offset = 0;
+/// Resolve a block number entered as a number, `latest`, `earliest` or `latest-N`
+pub fn resolve_block(input: &str, latest: u64) -> Result<u64> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "" | "latest" => return Ok(latest),
+        "earliest" => return Ok(0),
+        _ => {}
+    }
 
-    for output in &function.outputs {
-        let (decoded, consumed) = decode_single_output(&output.param_type, data, offset)?;
-        results.push(decoded);
-        offset += consumed;
+    if let Some(offset) = input.strip_prefix("latest") {
+        let offset = offset
+            .trim()
+            .strip_prefix('-')
+            .ok_or_else(|| eyre::eyre!("Expected latest-N, got '{}'", input))?;
+        let offset: u64 = offset
+            .trim()
+            .parse()
+            .wrap_err_with(|| format!("Invalid block offset '{}'", offset.trim()))?;
+        return Ok(latest.saturating_sub(offset));
     }
 
-    Ok(results)
+    match input.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => input.parse(),
+    }
+    .wrap_err_with(|| format!("Invalid block number '{}'", input))
 }
 
-fn decode_single_output(param_type: &str, data: &Bytes, offset: usize) -> Result<(String, usize)> {
-    let slice = &data[offset..];
+/// Whether an RPC URL uses a WebSocket transport
+fn is_websocket_url(url: &str) -> bool {
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
 
-    match param_type {
-        "address" => {
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for address"));
-            }
-            let addr = Address::from_slice(&slice[12..32]);
-            Ok((format!("{:?}", addr), 32))
-        }
-        "uint256" | "uint" => {
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for uint256"));
-            }
-            let num = U256::from_be_slice(&slice[..32]);
-            Ok((num.to_string(), 32))
-        }
-        "bool" => {
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for bool"));
-            }
-            let b = slice[31] != 0;
-            Ok((b.to_string(), 32))
-        }
-        "bytes32" => {
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for bytes32"));
-            }
-            let hex = format!("0x{}", hex::encode(&slice[..32]));
-            Ok((hex, 32))
-        }
-        "string" => {
-            // String is dynamic - has offset, then length, then data
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for string offset"));
-            }
-            let str_offset = U256::from_be_slice(&slice[..32]).to::<usize>();
-            let len_slice = &data[str_offset..];
-            let len = U256::from_be_slice(&len_slice[..32]).to::<usize>();
-            let str_data = &len_slice[32..32 + len];
-            let s = String::from_utf8_lossy(str_data).to_string();
-            Ok((s, 32))
-        }
-        t if t.starts_with("uint") => {
-            if slice.len() < 32 {
-                return Err(eyre::eyre!("Insufficient data for {}", t));
-            }
-            let num = U256::from_be_slice(&slice[..32]);
-            Ok((num.to_string(), 32))
-        }
-        _ => {
-            // Unknown type - return hex
-            if slice.len() < 32 {
-                return Ok(("0x".to_string(), slice.len()));
-            }
-            let hex = format!("0x{}", hex::encode(&slice[..32]));
-            Ok((hex, 32))
-        }
+/// Extract a revert reason from an RPC error, if the node reported a revert
+fn revert_reason(err: &TransportError, errors: &[ContractError]) -> Option<String> {
+    let payload = err.as_error_resp()?;
+    if let Some(data) = payload.as_revert_data() {
+        return Some(decode_revert(errors, &data));
     }
+    payload
+        .message
+        .contains("revert")
+        .then(|| payload.message.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_block() {
+        assert_eq!(resolve_block("latest", 500).unwrap(), 500);
+        assert_eq!(resolve_block("", 500).unwrap(), 500);
+        assert_eq!(resolve_block("earliest", 500).unwrap(), 0);
+        assert_eq!(resolve_block("latest-100", 500).unwrap(), 400);
+        assert_eq!(resolve_block("latest - 1000", 500).unwrap(), 0);
+        assert_eq!(resolve_block("123", 500).unwrap(), 123);
+        assert_eq!(resolve_block("0x10", 500).unwrap(), 16);
+        assert!(resolve_block("latest+5", 500).is_err());
+        assert!(resolve_block("soon", 500).is_err());
+    }
+
     #[test]
     fn test_is_read_only() {
         let view_func = ContractFunction {
@@ -296,6 +598,9 @@ mod tests {
             inputs: vec![],
             outputs: vec![],
             state_mutability: "view".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
         };
         assert!(ContractCaller::is_read_only(&view_func));
 
@@ -304,7 +609,157 @@ mod tests {
             inputs: vec![],
             outputs: vec![],
             state_mutability: "nonpayable".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
         };
         assert!(!ContractCaller::is_read_only(&write_func));
     }
+
+    /// Local anvil node, killed when dropped
+    struct Anvil {
+        child: std::process::Child,
+        port: u16,
+    }
+
+    impl Anvil {
+        fn spawn() -> Self {
+            let port = std::net::TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+            let child = std::process::Command::new("anvil")
+                .args(["--port", &port.to_string(), "--silent"])
+                .spawn()
+                .expect("anvil must be installed");
+            Self { child, port }
+        }
+    }
+
+    impl Drop for Anvil {
+        fn drop(&mut self) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+
+    /// Deploy a contract that emits `Ping(uint256)` with the first calldata word, then call it
+    async fn emit_ping(rpc_url: &str, value: u64) -> Address {
+        use alloy::network::TransactionBuilder;
+        use alloy::primitives::{Bytes, keccak256};
+
+        // Default anvil account #0
+        let signer: PrivateKeySigner =
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+                .parse()
+                .unwrap();
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .connect(rpc_url)
+            .await
+            .unwrap();
+
+        // CALLDATALOAD(0) -> MSTORE(0) -> LOG1(0, 32, topic) -> STOP
+        let mut runtime = vec![0x60, 0x00, 0x35, 0x60, 0x00, 0x52, 0x7f];
+        runtime.extend_from_slice(keccak256("Ping(uint256)").as_slice());
+        runtime.extend_from_slice(&[0x60, 0x20, 0x60, 0x00, 0xa1, 0x00]);
+        // CODECOPY the runtime that follows this 12-byte prefix and RETURN it
+        let mut init = vec![
+            0x60, 0x2d, 0x60, 0x0c, 0x60, 0x00, 0x39, 0x60, 0x2d, 0x60, 0x00, 0xf3,
+        ];
+        init.extend_from_slice(&runtime);
+
+        let deploy = TransactionRequest::default().with_deploy_code(Bytes::from(init));
+        let receipt = provider
+            .send_transaction(deploy)
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        let address = receipt.contract_address.unwrap();
+
+        // Give the tail time to subscribe before the log is emitted
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let call = TransactionRequest::default()
+            .with_to(address)
+            .with_input(U256::from(value).to_be_bytes::<32>().to_vec());
+        provider
+            .send_transaction(call)
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        address
+    }
+
+    async fn tail_first_log(rpc_url: &str) -> LogEntry {
+        use crate::contracts::abi::{EventParam, FunctionParam};
+
+        let events = vec![ContractEvent {
+            name: "Ping".to_string(),
+            inputs: vec![EventParam {
+                param: FunctionParam {
+                    name: "value".to_string(),
+                    param_type: "uint256".to_string(),
+                    components: None,
+                    doc: None,
+                },
+                indexed: false,
+            }],
+            anonymous: false,
+        }];
+
+        // First contract deployed by account #0 on a fresh node
+        let address = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+        let caller = ContractCaller::new(rpc_url, 31337);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let tail = caller.tail_logs(address, &events, |entry| tx.send(entry).is_ok());
+        let emit_and_wait = async {
+            let deployed = emit_ping(rpc_url, 42).await;
+            assert_eq!(deployed, address.parse::<Address>().unwrap());
+            tokio::time::timeout(Duration::from_secs(10), rx.recv()).await
+        };
+
+        tokio::select! {
+            biased;
+            result = tail => panic!("tail ended early: {:?}", result),
+            entry = emit_and_wait => entry.expect("timed out waiting for log").unwrap(),
+        }
+    }
+
+    async fn wait_for_node(rpc_url: &str) {
+        let provider = ProviderBuilder::new().connect(rpc_url).await.unwrap();
+        for _ in 0..50 {
+            if provider.get_block_number().await.is_ok() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("anvil did not start");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn test_tail_logs_http() {
+        let anvil = Anvil::spawn();
+        let rpc_url = format!("http://127.0.0.1:{}", anvil.port);
+        wait_for_node(&rpc_url).await;
+
+        let entry = tail_first_log(&rpc_url).await;
+        assert_eq!(entry.to_string(), "Ping(value: 42)");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn test_tail_logs_ws() {
+        let anvil = Anvil::spawn();
+        wait_for_node(&format!("http://127.0.0.1:{}", anvil.port)).await;
+
+        let entry = tail_first_log(&format!("ws://127.0.0.1:{}", anvil.port)).await;
+        assert_eq!(entry.to_string(), "Ping(value: 42)");
+    }
 }
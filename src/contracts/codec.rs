@@ -0,0 +1,1040 @@
+//! ABI encoding of user-entered function arguments and decoding of return data and logs.
+//!
+//! Every argument is typed in as text and coerced using the parameter's ABI type
+//! (`FunctionParam::param_type` plus `components` for structs):
+//!
+//! | Type              | Syntax                                                        |
+//! |-------------------|---------------------------------------------------------------|
+//! | `address`         | `0x` + 40 hex chars                                           |
+//! | `uintN`           | decimal, `0x` hex, or with a unit: `1.5 ether`, `20 gwei`     |
+//! | `intN`            | same as `uintN`, optionally signed: `-42`, `+7`               |
+//! | `bool`            | `true` / `false` (`1` / `0` also accepted at the top level)   |
+//! | `bytesN`, `bytes` | hex with or without `0x`                                      |
+//! | `string`          | raw text; wrap in `"..."` inside arrays or tuples             |
+//! | `T[]`, `T[k]`     | comma separated, in brackets: `[1, 2, 3]`                     |
+//! | tuple / struct    | components in order, in parentheses: `(0xabc..., 100)`        |
+//!
+//! Arrays and tuples nest freely, e.g. `(address,uint256)[]` is entered as
+//! `[(0x1111..., 1), (0x2222..., 2)]`.
+
+use alloy::dyn_abi::{DynSolType, DynSolValue};
+use alloy::primitives::{B256, I256, U256, keccak256, utils::format_ether};
+use alloy::sol_types::{Panic, Revert, SolError};
+use eyre::{Result, WrapErr, eyre};
+
+use super::abi::{
+    ContractError, ContractEvent, ContractFunction, FunctionParam, encode_param_type,
+    error_signature, event_signature, function_selector,
+};
+
+/// Resolve the dynamic ABI type of a parameter, expanding tuple components
+pub fn sol_type(param: &FunctionParam) -> Result<DynSolType> {
+    let type_str = encode_param_type(param);
+    DynSolType::parse(&type_str).wrap_err_with(|| format!("Unsupported ABI type: {}", type_str))
+}
+
+/// Parse a single user-entered value for the given parameter
+pub fn parse_value(param: &FunctionParam, input: &str) -> Result<DynSolValue> {
+    let ty = sol_type(param)?;
+    let trimmed = input.trim();
+
+    let normalized = match ty {
+        DynSolType::Bool => match trimmed.to_lowercase().as_str() {
+            "1" | "true" => "true".to_string(),
+            "0" | "false" => "false".to_string(),
+            other => other.to_string(),
+        },
+        _ => trimmed.to_string(),
+    };
+
+    let value = ty.coerce_str(&normalized).map_err(|e| {
+        let label = if param.name.is_empty() {
+            String::new()
+        } else {
+            format!("'{}' ", param.name)
+        };
+        eyre!("Invalid value for {}({}): {}", label, param.param_type, e)
+    })?;
+
+    check_int_bounds(&value)?;
+    Ok(value)
+}
+
+/// Encode call data (selector + arguments) for a function call
+pub fn encode_call_data(function: &ContractFunction, params: &[String]) -> Result<Vec<u8>> {
+    let selector = function_selector(function);
+
+    let values = function
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let value = params.get(i).map(|s| s.as_str()).unwrap_or("");
+            parse_value(param, value)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut calldata = selector.to_vec();
+    calldata.extend(DynSolValue::Tuple(values).abi_encode_params());
+    Ok(calldata)
+}
+
+/// Decode return data for a function's outputs into one `name: value` line per output
+pub fn decode_outputs(outputs: &[FunctionParam], data: &[u8]) -> Result<Vec<String>> {
+    if outputs.is_empty() {
+        return Ok(vec![]);
+    }
+    if data.is_empty() {
+        return Err(eyre!(
+            "Call returned no data (is a contract deployed at this address?)"
+        ));
+    }
+
+    let types = outputs.iter().map(sol_type).collect::<Result<Vec<_>>>()?;
+    let decoded = DynSolType::Tuple(types)
+        .abi_decode_params(data)
+        .wrap_err_with(|| format!("Malformed return data ({} bytes)", data.len()))?;
+
+    let DynSolValue::Tuple(values) = decoded else {
+        return Err(eyre!("Unexpected return data layout"));
+    };
+
+    Ok(outputs
+        .iter()
+        .zip(&values)
+        .enumerate()
+        .map(|(i, (param, value))| {
+            let label = if param.name.is_empty() {
+                format!("[{}]", i)
+            } else {
+                param.name.clone()
+            };
+            format!("{}: {}", label, format_value(value, Some(param), false))
+        })
+        .collect())
+}
+
+/// Describe the revert data returned by a failed call.
+///
+/// Recognises `Error(string)`, `Panic(uint256)` and the contract's custom errors;
+/// anything else is shown as raw hex.
+pub fn decode_revert(errors: &[ContractError], data: &[u8]) -> String {
+    if data.is_empty() {
+        return "reverted without a reason".to_string();
+    }
+    if let Ok(revert) = Revert::abi_decode(data) {
+        return revert.reason;
+    }
+    if let Ok(panic) = Panic::abi_decode(data) {
+        // The code comes from the chain, so it can be anything a uint256 holds
+        return match u64::try_from(panic.code) {
+            Ok(code) => format!("Panic(0x{:02x}): {}", code, panic_reason(code)),
+            Err(_) => format!("Panic: unknown panic code 0x{:x}", panic.code),
+        };
+    }
+
+    if let Some((selector, args)) = data.split_first_chunk::<4>() {
+        let decoded = errors
+            .iter()
+            .filter(|e| keccak256(error_signature(e).as_bytes())[..4] == *selector)
+            .find_map(|e| {
+                let fields = decode_outputs(&e.inputs, args).ok()?;
+                Some(format!("{}({})", e.name, fields.join(", ")))
+            });
+        if let Some(decoded) = decoded {
+            return decoded;
+        }
+    }
+
+    format!("reverted with unrecognised data 0x{}", hex::encode(data))
+}
+
+/// Meaning of a Solidity `Panic(uint256)` code
+fn panic_reason(code: u64) -> &'static str {
+    match code {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "incorrectly encoded storage byte array",
+        0x31 => "pop() on an empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory (allocation too large)",
+        0x51 => "call to an uninitialised internal function",
+        _ => "unknown panic code",
+    }
+}
+
+/// A log decoded against one of the contract's events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedLog {
+    pub name: String,
+    /// `(name, value)` pairs in declaration order
+    pub fields: Vec<(String, String)>,
+}
+
+impl std::fmt::Display for DecodedLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect();
+        write!(f, "{}({})", self.name, fields.join(", "))
+    }
+}
+
+/// Decode a log with the first event whose signature and layout match
+pub fn decode_log(events: &[ContractEvent], topics: &[B256], data: &[u8]) -> Option<DecodedLog> {
+    // Regular events are identified by topic0 = keccak256(signature)
+    if let Some((topic0, rest)) = topics.split_first() {
+        let decoded = events
+            .iter()
+            .filter(|e| !e.anonymous && keccak256(event_signature(e).as_bytes()) == *topic0)
+            .find_map(|e| decode_event(e, rest, data).ok());
+        if decoded.is_some() {
+            return decoded;
+        }
+    }
+
+    // Anonymous events have no selector topic, so fall back to any whose layout fits
+    events
+        .iter()
+        .filter(|e| e.anonymous)
+        .find_map(|e| decode_event(e, topics, data).ok())
+}
+
+/// Decode the indexed topics (without topic0) and data of a log for one event
+fn decode_event(event: &ContractEvent, topics: &[B256], data: &[u8]) -> Result<DecodedLog> {
+    let indexed_count = event.inputs.iter().filter(|p| p.indexed).count();
+    if indexed_count != topics.len() {
+        return Err(eyre!(
+            "Expected {} indexed topics, got {}",
+            indexed_count,
+            topics.len()
+        ));
+    }
+
+    let body_types = event
+        .inputs
+        .iter()
+        .filter(|p| !p.indexed)
+        .map(|p| sol_type(&p.param))
+        .collect::<Result<Vec<_>>>()?;
+    let DynSolValue::Tuple(body) = DynSolType::Tuple(body_types)
+        .abi_decode_params(data)
+        .wrap_err_with(|| format!("Malformed log data ({} bytes)", data.len()))?
+    else {
+        return Err(eyre!("Unexpected log data layout"));
+    };
+
+    let mut topics = topics.iter();
+    let mut body = body.iter();
+    let fields = event
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let label = if input.param.name.is_empty() {
+                format!("[{}]", i)
+            } else {
+                input.param.name.clone()
+            };
+            let value = if input.indexed {
+                let topic = topics.next().ok_or_else(|| eyre!("Missing topic"))?;
+                decode_topic(&input.param, topic)?
+            } else {
+                let value = body.next().ok_or_else(|| eyre!("Missing log value"))?;
+                format_value(value, Some(&input.param), false)
+            };
+            Ok((label, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(DecodedLog {
+        name: event.name.clone(),
+        fields,
+    })
+}
+
+/// Build the `eth_getLogs` topic filter for an event.
+///
+/// `filters` holds one user-entered value per indexed parameter; empty values
+/// match anything. Non-anonymous events also pin topic0 to the event signature.
+pub fn event_topics(event: &ContractEvent, filters: &[String]) -> Result<Vec<Option<B256>>> {
+    let mut topics = Vec::new();
+    if !event.anonymous {
+        topics.push(Some(keccak256(event_signature(event).as_bytes())));
+    }
+
+    for (i, input) in event.inputs.iter().filter(|p| p.indexed).enumerate() {
+        let filter = filters.get(i).map(|f| f.trim()).unwrap_or("");
+        if filter.is_empty() {
+            topics.push(None);
+        } else {
+            topics.push(Some(encode_topic(&input.param, filter)?));
+        }
+    }
+
+    Ok(topics)
+}
+
+/// Encode a value the way it is stored when its parameter is indexed
+pub fn encode_topic(param: &FunctionParam, input: &str) -> Result<B256> {
+    match parse_value(param, input)? {
+        // Dynamic values are stored as the hash of their contents
+        DynSolValue::String(s) => Ok(keccak256(s.as_bytes())),
+        DynSolValue::Bytes(bytes) => Ok(keccak256(bytes)),
+        DynSolValue::Array(_) | DynSolValue::FixedArray(_) | DynSolValue::Tuple(_) => Err(eyre!(
+            "Filtering on indexed {} values is not supported",
+            param.param_type
+        )),
+        value => Ok(B256::from_slice(&value.abi_encode())),
+    }
+}
+
+/// Decode an indexed parameter; reference types are only stored as a hash
+fn decode_topic(param: &FunctionParam, topic: &B256) -> Result<String> {
+    let ty = sol_type(param)?;
+    match ty {
+        DynSolType::Address
+        | DynSolType::Bool
+        | DynSolType::Int(_)
+        | DynSolType::Uint(_)
+        | DynSolType::FixedBytes(_)
+        | DynSolType::Function => {
+            let value = ty
+                .abi_decode(topic.as_slice())
+                .wrap_err_with(|| format!("Invalid topic for {}", param.param_type))?;
+            Ok(format_value(&value, Some(param), false))
+        }
+        _ => Ok(format!("{} (hashed)", topic)),
+    }
+}
+
+/// Render a decoded value, labelling struct fields with their component names
+pub fn format_value(value: &DynSolValue, param: Option<&FunctionParam>, nested: bool) -> String {
+    let components = param.and_then(|p| p.components.as_deref());
+
+    match value {
+        DynSolValue::Address(addr) => addr.to_checksum(None),
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::Int(int, _) => int.to_string(),
+        DynSolValue::Uint(uint, _) => uint.to_string(),
+        DynSolValue::FixedBytes(word, size) => format!("0x{}", hex::encode(&word[..*size])),
+        DynSolValue::Bytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        DynSolValue::Function(func) => format!("0x{}", hex::encode(func.as_slice())),
+        DynSolValue::String(s) if nested => format!("{:?}", s),
+        DynSolValue::String(s) => s.clone(),
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) => {
+            // Elements of a tuple array share the array's components
+            let element = param.map(|p| FunctionParam {
+                name: String::new(),
+                param_type: p.param_type.clone(),
+                components: p.components.clone(),
+                doc: None,
+            });
+            let inner: Vec<String> = items
+                .iter()
+                .map(|item| format_value(item, element.as_ref(), true))
+                .collect();
+            format!("[{}]", inner.join(", "))
+        }
+        DynSolValue::Tuple(items) => {
+            let inner: Vec<String> = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let component = components.and_then(|c| c.get(i));
+                    let formatted = format_value(item, component, true);
+                    match component {
+                        Some(c) if !c.name.is_empty() => format!("{}: {}", c.name, formatted),
+                        _ => formatted,
+                    }
+                })
+                .collect();
+            format!("({})", inner.join(", "))
+        }
+    }
+}
+
+/// Parse an amount of ETH to send with a payable call.
+///
+/// Accepts raw wei (`1000`, `0x3e8`) or a decimal amount with a unit
+/// (`0.1 ether`, `0.1 eth`, `15 gwei`, `7 wei`). Empty input means zero.
+pub fn parse_eth_value(input: &str) -> Result<U256> {
+    let mut normalized = input.trim().to_lowercase();
+    if normalized.is_empty() {
+        return Ok(U256::ZERO);
+    }
+    if normalized.ends_with("eth") {
+        normalized.push_str("er");
+    }
+
+    match DynSolType::Uint(256).coerce_str(&normalized) {
+        Ok(DynSolValue::Uint(value, _)) => Ok(value),
+        Ok(_) => Err(eyre!("Invalid value amount: {}", input)),
+        Err(e) => Err(eyre!(
+            "Invalid value amount '{}': {} (expected e.g. 0.1 ether, 15 gwei or wei)",
+            input.trim(),
+            e
+        )),
+    }
+}
+
+/// Format a wei amount for display, e.g. `0.1 ETH (100000000000000000 wei)`
+pub fn format_eth_value(value: U256) -> String {
+    let ether = format_ether(value);
+    let ether = ether.trim_end_matches('0').trim_end_matches('.');
+    format!("{} ETH ({} wei)", ether, value)
+}
+
+/// Signed values are coerced against 256 bits; reject ones that overflow smaller `intN`
+fn check_int_bounds(value: &DynSolValue) -> Result<()> {
+    match value {
+        DynSolValue::Int(int, bits) if *bits < 256 => {
+            let min = -(I256::ONE << (*bits - 1));
+            let max = (I256::ONE << (*bits - 1)) - I256::ONE;
+            if *int < min || *int > max {
+                return Err(eyre!("Value {} does not fit in int{}", int, bits));
+            }
+            Ok(())
+        }
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => values.iter().try_for_each(check_int_bounds),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, B256, I256, U256, address};
+    use alloy::sol_types::SolValue;
+
+    use crate::contracts::abi::EventParam;
+
+    fn param(name: &str, param_type: &str) -> FunctionParam {
+        FunctionParam {
+            name: name.to_string(),
+            param_type: param_type.to_string(),
+            components: None,
+            doc: None,
+        }
+    }
+
+    fn tuple(param_type: &str, components: Vec<FunctionParam>) -> FunctionParam {
+        FunctionParam {
+            name: "t".to_string(),
+            param_type: param_type.to_string(),
+            components: Some(components),
+            doc: None,
+        }
+    }
+
+    /// Encode a value, decode it again with the same type and check both directions
+    fn roundtrip(p: &FunctionParam, input: &str) -> DynSolValue {
+        let value = parse_value(p, input).unwrap();
+        let encoded = value.abi_encode_params();
+        let decoded = sol_type(p).unwrap().abi_decode_params(&encoded).unwrap();
+        assert_eq!(decoded, value, "roundtrip mismatch for {}", p.param_type);
+        value
+    }
+
+    const ALICE: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    const BOB: &str = "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC";
+
+    #[test]
+    fn test_address() {
+        let value = roundtrip(&param("to", "address"), ALICE);
+        assert_eq!(
+            value,
+            DynSolValue::Address(address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"))
+        );
+        assert!(parse_value(&param("to", "address"), "0x1234").is_err());
+    }
+
+    #[test]
+    fn test_uints() {
+        assert_eq!(
+            roundtrip(&param("a", "uint256"), "1000"),
+            DynSolValue::Uint(U256::from(1000), 256)
+        );
+        assert_eq!(
+            roundtrip(&param("a", "uint8"), "0xff"),
+            DynSolValue::Uint(U256::from(255), 8)
+        );
+        assert_eq!(
+            roundtrip(&param("a", "uint256"), "1.5 ether"),
+            DynSolValue::Uint(U256::from(1_500_000_000_000_000_000u128), 256)
+        );
+        assert!(parse_value(&param("a", "uint8"), "256").is_err());
+        assert!(parse_value(&param("a", "uint256"), "-1").is_err());
+    }
+
+    #[test]
+    fn test_signed_ints() {
+        let value = roundtrip(&param("delta", "int256"), "-42");
+        assert_eq!(value, DynSolValue::Int(I256::try_from(-42).unwrap(), 256));
+        assert_eq!(
+            value.abi_encode(),
+            I256::try_from(-42).unwrap().abi_encode()
+        );
+
+        assert_eq!(
+            roundtrip(&param("tick", "int24"), "-887272"),
+            DynSolValue::Int(I256::try_from(-887272).unwrap(), 24)
+        );
+        assert!(parse_value(&param("x", "int8"), "-129").is_err());
+        assert!(parse_value(&param("x", "int8"), "128").is_err());
+        roundtrip(&param("x", "int8"), "-128");
+    }
+
+    #[test]
+    fn test_bool() {
+        assert_eq!(
+            roundtrip(&param("flag", "bool"), "true"),
+            DynSolValue::Bool(true)
+        );
+        assert_eq!(
+            roundtrip(&param("flag", "bool"), "1"),
+            DynSolValue::Bool(true)
+        );
+        assert_eq!(
+            roundtrip(&param("flag", "bool"), "False"),
+            DynSolValue::Bool(false)
+        );
+        assert!(parse_value(&param("flag", "bool"), "yes").is_err());
+    }
+
+    #[test]
+    fn test_fixed_bytes() {
+        let value = roundtrip(&param("sig", "bytes4"), "0xa9059cbb");
+        assert_eq!(value.abi_encode(), {
+            let mut word = [0u8; 32];
+            word[..4].copy_from_slice(&[0xa9, 0x05, 0x9c, 0xbb]);
+            word.to_vec()
+        });
+
+        let hash = "0x1111111111111111111111111111111111111111111111111111111111111111";
+        assert_eq!(
+            roundtrip(&param("h", "bytes32"), hash),
+            DynSolValue::FixedBytes(hash.parse::<B256>().unwrap(), 32)
+        );
+        assert!(parse_value(&param("sig", "bytes4"), "0xa9059cbb00").is_err());
+    }
+
+    #[test]
+    fn test_dynamic_bytes_and_string() {
+        assert_eq!(
+            roundtrip(&param("data", "bytes"), "0xdeadbeef"),
+            DynSolValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(
+            roundtrip(&param("data", "bytes"), "0x"),
+            DynSolValue::Bytes(vec![])
+        );
+        assert_eq!(
+            roundtrip(&param("name", "string"), "hello world"),
+            DynSolValue::String("hello world".to_string())
+        );
+        assert_eq!(
+            roundtrip(&param("name", "string"), "\"quoted, with comma\""),
+            DynSolValue::String("quoted, with comma".to_string())
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let value = roundtrip(
+            &param("users", "address[]"),
+            &format!("[{}, {}]", ALICE, BOB),
+        );
+        let expected: Vec<Address> = vec![ALICE.parse().unwrap(), BOB.parse().unwrap()];
+        assert_eq!(value.abi_encode(), expected.abi_encode());
+
+        let value = roundtrip(&param("amounts", "uint256[]"), "[1, 2, 3]");
+        let expected: Vec<U256> = vec![U256::from(1), U256::from(2), U256::from(3)];
+        assert_eq!(value.abi_encode(), expected.abi_encode());
+
+        roundtrip(&param("empty", "uint256[]"), "[]");
+        roundtrip(&param("pair", "uint256[2]"), "[7, 8]");
+        assert!(parse_value(&param("pair", "uint256[2]"), "[7]").is_err());
+
+        roundtrip(&param("names", "string[]"), "[\"a\", \"b, c\"]");
+        roundtrip(&param("matrix", "uint8[][]"), "[[1, 2], [], [3]]");
+        roundtrip(&param("grid", "int16[2][]"), "[[-1, 1], [2, -2]]");
+    }
+
+    #[test]
+    fn test_tuples() {
+        let position = tuple(
+            "tuple",
+            vec![
+                param("owner", "address"),
+                param("liquidity", "uint128"),
+                param("tickLower", "int24"),
+                param("label", "string"),
+            ],
+        );
+        let value = roundtrip(&position, &format!("({}, 1000, -60, \"main\")", ALICE));
+        let expected = (
+            ALICE.parse::<Address>().unwrap(),
+            1000u128,
+            alloy::primitives::aliases::I24::try_from(-60).unwrap(),
+            "main".to_string(),
+        );
+        assert_eq!(value.abi_encode_params(), expected.abi_encode_params());
+
+        assert!(parse_value(&position, &format!("({}, 1000)", ALICE)).is_err());
+    }
+
+    #[test]
+    fn test_nested_tuples_and_tuple_arrays() {
+        let inner = tuple("tuple", vec![param("a", "uint256"), param("b", "bool")]);
+        let outer = tuple(
+            "tuple[]",
+            vec![
+                param("target", "address"),
+                FunctionParam {
+                    name: "inner".to_string(),
+                    ..inner.clone()
+                },
+                param("tags", "bytes32[]"),
+            ],
+        );
+
+        let input = format!(
+            "[({}, (1, true), []), ({}, (2, false), [0x{}])]",
+            ALICE,
+            BOB,
+            "ab".repeat(32)
+        );
+        let value = roundtrip(&outer, &input);
+        assert!(matches!(value, DynSolValue::Array(ref items) if items.len() == 2));
+
+        let fixed = tuple("tuple[2]", vec![param("x", "int8"), param("y", "int8")]);
+        roundtrip(&fixed, "[(-1, 1), (2, -2)]");
+        assert!(parse_value(&fixed, "[(-1, 1)]").is_err());
+    }
+
+    #[test]
+    fn test_encode_call_data_known_answer() {
+        let func = ContractFunction {
+            name: "transfer".to_string(),
+            inputs: vec![param("to", "address"), param("amount", "uint256")],
+            outputs: vec![],
+            state_mutability: "nonpayable".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
+        };
+
+        let calldata = encode_call_data(&func, &[ALICE.to_string(), "1000".to_string()]).unwrap();
+
+        let mut expected = vec![0xa9, 0x05, 0x9c, 0xbb];
+        expected.extend((ALICE.parse::<Address>().unwrap(), U256::from(1000)).abi_encode_params());
+        assert_eq!(calldata, expected);
+    }
+
+    #[test]
+    fn test_encode_call_data_struct_selector() {
+        // Uniswap v3 router: exactInputSingle((address,address,uint24,address,uint256,uint256,uint160))
+        let params = tuple(
+            "tuple",
+            vec![
+                param("tokenIn", "address"),
+                param("tokenOut", "address"),
+                param("fee", "uint24"),
+                param("recipient", "address"),
+                param("amountIn", "uint256"),
+                param("amountOutMinimum", "uint256"),
+                param("sqrtPriceLimitX96", "uint160"),
+            ],
+        );
+        let func = ContractFunction {
+            name: "exactInputSingle".to_string(),
+            inputs: vec![params],
+            outputs: vec![],
+            state_mutability: "payable".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
+        };
+
+        let input = format!("({}, {}, 3000, {}, 1 ether, 0, 0)", ALICE, BOB, ALICE);
+        let calldata = encode_call_data(&func, &[input]).unwrap();
+        assert_eq!(&calldata[..4], &[0x04, 0xe4, 0x5a, 0xaf]);
+        assert_eq!(calldata.len(), 4 + 7 * 32);
+    }
+
+    #[test]
+    fn test_encode_call_data_reports_param_name() {
+        let func = ContractFunction {
+            name: "setOwner".to_string(),
+            inputs: vec![param("newOwner", "address")],
+            outputs: vec![],
+            state_mutability: "nonpayable".to_string(),
+            notice: None,
+            details: None,
+            facet: None,
+        };
+
+        let err = encode_call_data(&func, &["nope".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("'newOwner' (address)"));
+    }
+
+    fn output(name: &str, param_type: &str) -> FunctionParam {
+        param(name, param_type)
+    }
+
+    #[test]
+    fn test_decode_named_scalars() {
+        // getReserves() returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast)
+        let outputs = vec![
+            output("reserve0", "uint112"),
+            output("reserve1", "uint112"),
+            output("blockTimestampLast", "uint32"),
+        ];
+        let data = (
+            U256::from(1_000_000u64),
+            U256::from(2_500u64),
+            U256::from(1_700_000_000u64),
+        )
+            .abi_encode_params();
+
+        assert_eq!(
+            decode_outputs(&outputs, &data).unwrap(),
+            vec![
+                "reserve0: 1000000",
+                "reserve1: 2500",
+                "blockTimestampLast: 1700000000"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_signed_and_fixed_bytes() {
+        let outputs = vec![
+            output("tick", "int24"),
+            output("", "bytes4"),
+            output("flag", "bool"),
+        ];
+        let data = (
+            I256::try_from(-60).unwrap(),
+            alloy::primitives::FixedBytes::<4>::from([0xa9, 0x05, 0x9c, 0xbb]),
+            true,
+        )
+            .abi_encode_params();
+
+        assert_eq!(
+            decode_outputs(&outputs, &data).unwrap(),
+            vec!["tick: -60", "[1]: 0xa9059cbb", "flag: true"]
+        );
+    }
+
+    #[test]
+    fn test_decode_dynamic_types() {
+        let outputs = vec![
+            output("name", "string"),
+            output("data", "bytes"),
+            output("holders", "address[]"),
+            output("pair", "uint256[2]"),
+        ];
+        let alice: Address = ALICE.parse().unwrap();
+        let data = (
+            "Token".to_string(),
+            alloy::primitives::Bytes::from(vec![0xde, 0xad]),
+            vec![alice],
+            [U256::from(1), U256::from(2)],
+        )
+            .abi_encode_params();
+
+        assert_eq!(
+            decode_outputs(&outputs, &data).unwrap(),
+            vec![
+                "name: Token".to_string(),
+                "data: 0xdead".to_string(),
+                format!("holders: [{}]", ALICE),
+                "pair: [1, 2]".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_nested_struct() {
+        // function positions(uint256) returns (Position memory)
+        let outputs = vec![FunctionParam {
+            name: "position".to_string(),
+            param_type: "tuple".to_string(),
+            components: Some(vec![
+                param("owner", "address"),
+                param("liquidity", "uint128"),
+                FunctionParam {
+                    name: "range".to_string(),
+                    param_type: "tuple".to_string(),
+                    components: Some(vec![param("lower", "int24"), param("upper", "int24")]),
+                    doc: None,
+                },
+                param("tags", "string[]"),
+            ]),
+            doc: None,
+        }];
+        let alice: Address = ALICE.parse().unwrap();
+        let data = ((
+            alice,
+            500u128,
+            (
+                alloy::primitives::aliases::I24::try_from(-120).unwrap(),
+                alloy::primitives::aliases::I24::try_from(120).unwrap(),
+            ),
+            vec!["core".to_string(), "a, b".to_string()],
+        ),)
+            .abi_encode_params();
+
+        assert_eq!(
+            decode_outputs(&outputs, &data).unwrap(),
+            vec![format!(
+                "position: (owner: {}, liquidity: 500, range: (lower: -120, upper: 120), tags: [\"core\", \"a, b\"])",
+                ALICE
+            )]
+        );
+    }
+
+    #[test]
+    fn test_decode_tuple_array_uses_component_names() {
+        let outputs = vec![FunctionParam {
+            name: "calls".to_string(),
+            param_type: "tuple[]".to_string(),
+            components: Some(vec![param("target", "address"), param("ok", "bool")]),
+            doc: None,
+        }];
+        let alice: Address = ALICE.parse().unwrap();
+        let data = (vec![(alice, true), (alice, false)],).abi_encode_params();
+
+        assert_eq!(
+            decode_outputs(&outputs, &data).unwrap(),
+            vec![format!(
+                "calls: [(target: {0}, ok: true), (target: {0}, ok: false)]",
+                ALICE
+            )]
+        );
+    }
+
+    #[test]
+    fn test_decode_malformed_data_is_an_error() {
+        let outputs = vec![output("name", "string")];
+
+        // Too short for even the offset word
+        assert!(decode_outputs(&outputs, &[0u8; 4]).is_err());
+
+        // Offset pointing past the end of the data
+        let mut data = vec![0u8; 32];
+        data[31] = 0xff;
+        assert!(decode_outputs(&outputs, &data).is_err());
+
+        // Length larger than the remaining data
+        let mut data = "hi".to_string().abi_encode();
+        data[63] = 0xff;
+        assert!(decode_outputs(&outputs, &data).is_err());
+
+        assert!(decode_outputs(&[output("x", "uint256")], &[]).is_err());
+        assert!(decode_outputs(&[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_event_topics() {
+        let to = "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC";
+        let topics = event_topics(&transfer_event(), &["".to_string(), to.to_string()]).unwrap();
+        assert_eq!(
+            topics,
+            vec![
+                Some(keccak256("Transfer(address,address,uint256)")),
+                None,
+                Some(to.parse::<Address>().unwrap().into_word()),
+            ]
+        );
+
+        // Anonymous events have no signature topic
+        let anon = event("Tagged", vec![(param("tag", "string"), true)], true);
+        let topics = event_topics(&anon, &["hello".to_string()]).unwrap();
+        assert_eq!(topics, vec![Some(keccak256("hello"))]);
+
+        assert!(encode_topic(&param("ids", "uint256[]"), "[1, 2]").is_err());
+        assert!(event_topics(&transfer_event(), &["0x1234".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_decode_revert_reason() {
+        let data = Revert::from("Ownable: caller is not the owner").abi_encode();
+        assert_eq!(
+            decode_revert(&[], &data),
+            "Ownable: caller is not the owner"
+        );
+        assert_eq!(decode_revert(&[], &[]), "reverted without a reason");
+        assert_eq!(
+            decode_revert(&[], &[0xde, 0xad, 0xbe, 0xef]),
+            "reverted with unrecognised data 0xdeadbeef"
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_panic() {
+        let data = Panic::from(0x11).abi_encode();
+        assert_eq!(
+            decode_revert(&[], &data),
+            "Panic(0x11): arithmetic overflow or underflow"
+        );
+        let data = Panic::from(0x32).abi_encode();
+        assert_eq!(
+            decode_revert(&[], &data),
+            "Panic(0x32): array index out of bounds"
+        );
+
+        let data = Panic {
+            code: U256::from(1) << 64,
+        }
+        .abi_encode();
+        assert_eq!(
+            decode_revert(&[], &data),
+            "Panic: unknown panic code 0x10000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_custom_error() {
+        let errors = vec![
+            ContractError {
+                name: "Unauthorized".to_string(),
+                inputs: vec![],
+            },
+            ContractError {
+                name: "InsufficientBalance".to_string(),
+                inputs: vec![param("available", "uint256"), param("required", "uint256")],
+            },
+        ];
+
+        let mut data = keccak256("InsufficientBalance(uint256,uint256)")[..4].to_vec();
+        data.extend((U256::from(10), U256::from(20)).abi_encode_params());
+        assert_eq!(
+            decode_revert(&errors, &data),
+            "InsufficientBalance(available: 10, required: 20)"
+        );
+
+        let data = keccak256("Unauthorized()")[..4].to_vec();
+        assert_eq!(decode_revert(&errors, &data), "Unauthorized()");
+    }
+
+    #[test]
+    fn test_parse_eth_value() {
+        let tenth = U256::from(100_000_000_000_000_000u64);
+        assert_eq!(parse_eth_value("0.1 ether").unwrap(), tenth);
+        assert_eq!(parse_eth_value("0.1ether").unwrap(), tenth);
+        assert_eq!(parse_eth_value("0.1 ETH").unwrap(), tenth);
+        assert_eq!(
+            parse_eth_value("15 gwei").unwrap(),
+            U256::from(15_000_000_000u64)
+        );
+        assert_eq!(parse_eth_value("1000").unwrap(), U256::from(1000));
+        assert_eq!(parse_eth_value("1000 wei").unwrap(), U256::from(1000));
+        assert_eq!(parse_eth_value("0x3e8").unwrap(), U256::from(1000));
+        assert_eq!(parse_eth_value("  ").unwrap(), U256::ZERO);
+
+        assert!(parse_eth_value("0.1").is_err());
+        assert!(parse_eth_value("-1 ether").is_err());
+        assert!(parse_eth_value("1 btc").is_err());
+    }
+
+    #[test]
+    fn test_format_eth_value() {
+        assert_eq!(
+            format_eth_value(U256::from(100_000_000_000_000_000u64)),
+            "0.1 ETH (100000000000000000 wei)"
+        );
+        assert_eq!(
+            format_eth_value(U256::from(2_000_000_000_000_000_000u128)),
+            "2 ETH (2000000000000000000 wei)"
+        );
+        assert_eq!(format_eth_value(U256::ZERO), "0 ETH (0 wei)");
+    }
+
+    fn event(name: &str, inputs: Vec<(FunctionParam, bool)>, anonymous: bool) -> ContractEvent {
+        ContractEvent {
+            name: name.to_string(),
+            inputs: inputs
+                .into_iter()
+                .map(|(param, indexed)| EventParam { param, indexed })
+                .collect(),
+            anonymous,
+        }
+    }
+
+    fn transfer_event() -> ContractEvent {
+        event(
+            "Transfer",
+            vec![
+                (param("from", "address"), true),
+                (param("to", "address"), true),
+                (param("value", "uint256"), false),
+            ],
+            false,
+        )
+    }
+
+    #[test]
+    fn test_decode_log_transfer() {
+        let from = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let to = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let topics = vec![
+            keccak256("Transfer(address,address,uint256)"),
+            from.into_word(),
+            to.into_word(),
+        ];
+        let data = U256::from(500).abi_encode();
+
+        let decoded = decode_log(&[transfer_event()], &topics, &data).unwrap();
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(
+            decoded.to_string(),
+            format!("Transfer(from: {}, to: {}, value: 500)", from, to)
+        );
+    }
+
+    #[test]
+    fn test_decode_log_hashed_and_anonymous() {
+        let named = event(
+            "Named",
+            vec![
+                (param("label", "string"), true),
+                (param("id", "uint8"), false),
+            ],
+            false,
+        );
+        let topic = keccak256("alice");
+        let topics = vec![keccak256("Named(string,uint8)"), topic];
+        let decoded = decode_log(&[named], &topics, &U256::from(7).abi_encode()).unwrap();
+        assert_eq!(
+            decoded.fields,
+            vec![
+                ("label".to_string(), format!("{} (hashed)", topic)),
+                ("id".to_string(), "7".to_string()),
+            ]
+        );
+
+        let anon = event("Ping", vec![(param("", "bool"), false)], true);
+        let decoded = decode_log(&[anon], &[], &true.abi_encode()).unwrap();
+        assert_eq!(decoded.to_string(), "Ping([0]: true)");
+    }
+
+    #[test]
+    fn test_decode_log_unknown() {
+        let topics = vec![keccak256("Approval(address,address,uint256)")];
+        assert!(decode_log(&[transfer_event()], &topics, &[]).is_none());
+
+        // Matching selector but wrong number of indexed topics
+        let topics = vec![keccak256("Transfer(address,address,uint256)")];
+        assert!(decode_log(&[transfer_event()], &topics, &[]).is_none());
+    }
+}
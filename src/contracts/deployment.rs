@@ -1,16 +1,31 @@
+mod artifacts;
+mod hardhat;
+
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use std::time::Duration;
 
+use alloy::primitives::Address;
+use alloy::providers::{Provider, ProviderBuilder};
 use eyre::{Result, WrapErr};
 use serde::Deserialize;
 use serde_json::Value;
 
-use super::abi::{ContractFunction, parse_abi};
+use super::abi::{
+    ContractError, ContractEvent, ContractFunction, apply_natspec, error_signature,
+    event_signature, function_selector, parse_abi, parse_errors, parse_events,
+};
+use super::diamond::{Facet, detect_diamond};
+use super::proxy::{ProxyInfo, detect_proxy};
 use crate::config::load_chain_names;
-use crate::project::Project;
+use crate::project::{Project, ProjectType};
+use artifacts::{ArtifactCollision, ArtifactIndex};
+
+/// Upper bound on proxy detection per chain, so a dead RPC doesn't stall startup
+const PROXY_DETECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 static CHAIN_NAMES: OnceLock<std::collections::HashMap<u64, String>> = OnceLock::new();
 
@@ -23,6 +38,80 @@ pub fn chain_id_to_network(chain_id: u64) -> String {
         .unwrap_or_else(|| format!("chain-{}", chain_id))
 }
 
+/// Load functions (with NatSpec), events and errors from a compiled artifact or bare ABI file.
+///
+/// Missing or unreadable files yield empty lists.
+fn load_artifact_abi(
+    path: &Path,
+) -> (
+    Vec<ContractFunction>,
+    Vec<ContractEvent>,
+    Vec<ContractError>,
+) {
+    match fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+    {
+        Some(json) => abi_from_artifact(&json),
+        None => (vec![], vec![], vec![]),
+    }
+}
+
+/// Functions (with NatSpec), events and errors of an artifact, deployment record or bare ABI
+fn abi_from_artifact(
+    json: &Value,
+) -> (
+    Vec<ContractFunction>,
+    Vec<ContractEvent>,
+    Vec<ContractError>,
+) {
+    // Check if it's an artifact with "abi" field
+    let abi_json = json.get("abi").unwrap_or(json);
+    let mut functions = parse_abi(abi_json).unwrap_or_default();
+    apply_natspec(&mut functions, json);
+    (
+        functions,
+        parse_events(abi_json).unwrap_or_default(),
+        parse_errors(abi_json).unwrap_or_default(),
+    )
+}
+
+/// Runtime bytecode without the trailing CBOR metadata, whose length is in the last two bytes
+fn strip_metadata(code: &[u8]) -> &[u8] {
+    match code.split_last_chunk::<2>() {
+        Some((rest, len)) => {
+            let len = u16::from_be_bytes(*len) as usize;
+            match rest.len().checked_sub(len) {
+                Some(end) => &rest[..end],
+                None => code,
+            }
+        }
+        None => code,
+    }
+}
+
+/// Outcome of reading one deployment's proxy slots and diamond facets
+#[derive(Debug, Default)]
+struct DetectedProxy {
+    index: usize,
+    proxy: Option<ProxyInfo>,
+    /// Artifact matching the implementation's code, when it isn't a known deployment
+    artifact: Option<PathBuf>,
+    /// Facets, if the contract is an EIP-2535 diamond
+    facets: Option<Vec<FacetAbi>>,
+}
+
+/// A diamond facet with the ABI of the deployment or artifact matching its address or code
+#[derive(Debug)]
+struct FacetAbi {
+    facet: Facet,
+    /// `None` if no ABI was found for the facet
+    name: Option<String>,
+    functions: Vec<ContractFunction>,
+    events: Vec<ContractEvent>,
+    errors: Vec<ContractError>,
+}
+
 /// Represents a deployed contract
 #[derive(Debug, Clone)]
 pub struct Deployment {
@@ -31,28 +120,81 @@ pub struct Deployment {
     pub callable_address: String, // Address to use for calls (proxy if available)
     pub network: String,
     pub chain_id: u64,
+    /// Workspace project the deployment was found in, set when lists are merged
+    pub project: Option<String>,
     pub abi_path: Option<PathBuf>,
     pub functions: Vec<ContractFunction>,
+    pub events: Vec<ContractEvent>,
+    pub errors: Vec<ContractError>,
     pub args: Option<Vec<String>>,
-    pub is_proxy: bool,                        // Whether this contract is behind a proxy
-    pub implementation_set: bool,              // Whether the user has confirmed/set the implementation
+    pub is_proxy: bool,           // Whether this contract is behind a proxy
+    pub implementation_set: bool, // Whether the user has confirmed/set the implementation
+    pub abi_chosen: bool,         // Whether the user picked the ABI from another deployment
+    pub proxy: Option<ProxyInfo>, // EIP-1967 slots read on-chain, if detected
+    /// Every address this contract has had on this chain, newest first
+    pub history: Vec<DeploymentInstance>,
+}
+
+/// One deployment of a contract, recorded in a broadcast run file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentInstance {
+    pub address: String,
+    pub args: Option<Vec<String>>,
+    /// Unix time of the run, in seconds
+    pub timestamp: Option<u64>,
+    pub tx_hash: Option<String>,
+    /// Git commit the run was made from
+    pub commit: Option<String>,
+}
+
+impl Deployment {
+    /// Index in `history` of the instance currently in use
+    pub fn current_instance(&self) -> Option<usize> {
+        self.history.iter().position(|i| i.address == self.address)
+    }
+
+    /// Use an earlier deployment from `history` instead of the newest
+    fn select_instance(&mut self, instance: usize) {
+        let Some(instance) = self.history.get(instance).cloned() else {
+            return;
+        };
+
+        // Calls routed through a proxy keep going through it
+        if self.callable_address == self.address {
+            self.callable_address = instance.address.clone();
+        }
+        self.address = instance.address;
+        self.args = instance.args;
+    }
 }
 
 /// Manager for scanning and tracking deployed contracts
 pub struct DeploymentManager {
     pub deployments: Vec<Deployment>,
+    project_type: ProjectType,
+    /// Foundry `broadcast/`, or hardhat-deploy `deployments/`
     broadcast_dir: PathBuf,
+    /// Hardhat Ignition `ignition/deployments/`
+    ignition_dir: PathBuf,
     out_dir: PathBuf,
+    /// Artifacts in `out_dir`, rebuilt on every scan
+    artifacts: ArtifactIndex,
+    /// Contract names that matched artifacts from several source files
+    pub collisions: Vec<ArtifactCollision>,
 }
 
 /// Foundry broadcast run artifact structure
 #[derive(Debug, Deserialize)]
 struct BroadcastRun {
     transactions: Option<Vec<Transaction>>,
+    /// Seconds in older Foundry versions, milliseconds in newer ones
+    timestamp: Option<u64>,
+    commit: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Transaction {
+    hash: Option<String>,
     #[serde(rename = "transactionType")]
     transaction_type: String,
     #[serde(rename = "contractName")]
@@ -60,18 +202,42 @@ struct Transaction {
     #[serde(rename = "contractAddress")]
     contract_address: Option<String>,
     arguments: Option<Vec<Value>>,
+    transaction: Option<TransactionFields>,
+    /// Contracts created inside the transaction, e.g. by a CREATE2 or clone factory
+    #[serde(rename = "additionalContracts", default)]
+    additional_contracts: Vec<AdditionalContract>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransactionFields {
+    #[serde(alias = "data")]
+    input: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdditionalContract {
+    #[serde(rename = "contractName")]
+    contract_name: Option<String>,
+    address: String,
+    #[serde(rename = "initCode")]
+    init_code: Option<String>,
 }
 
 impl DeploymentManager {
     pub fn new(project: &Project) -> Self {
         Self {
             deployments: Vec::new(),
+            project_type: project.project_type.clone(),
             broadcast_dir: project.broadcast_dir.clone(),
+            ignition_dir: project.root.join("ignition").join("deployments"),
             out_dir: project.out_dir.clone(),
+            artifacts: ArtifactIndex::default(),
+            collisions: Vec::new(),
         }
     }
 
-    fn parse_run_file(&mut self, path: &PathBuf) -> Result<()> {
+    /// Parse the contracts created by one broadcast run
+    fn parse_run_file(&self, path: &PathBuf) -> Result<Vec<Deployment>> {
         // Extract chain ID from path
         // path is like .../chain_id/run-latest.json
         let chain_id_str = path
@@ -79,76 +245,185 @@ impl DeploymentManager {
             .and_then(|p| p.file_name())
             .and_then(|n| n.to_str())
             .ok_or_else(|| eyre::eyre!("Invalid path structure"))?;
-        
-        let chain_id = chain_id_str.parse::<u64>().wrap_err("Failed to parse chain ID")?;
+
+        let chain_id = chain_id_str
+            .parse::<u64>()
+            .wrap_err("Failed to parse chain ID")?;
         let network = chain_id_to_network(chain_id);
 
         let content = fs::read_to_string(path)?;
         let run: BroadcastRun = serde_json::from_str(&content)?;
+        let timestamp = run
+            .timestamp
+            .map(|ts| if ts > 100_000_000_000 { ts / 1000 } else { ts });
 
-        if let Some(transactions) = run.transactions {
-            for tx in transactions {
-                if tx.transaction_type == "CREATE" {
-                    if let (Some(name), Some(address)) = (tx.contract_name, tx.contract_address) {
-                        // Find ABI
-                        // Typical foundry structure: out/ContractName.sol/ContractName.json
-                        // Or sometimes just out/ContractName.json depending on config, but standard is nested.
-                        // We'll try the nested one first.
-                        let mut abi_path = self.out_dir.join(format!("{}.sol", name)).join(format!("{}.json", name));
-                        
-                        if !abi_path.exists() {
-                             abi_path = self.out_dir.join(format!("{}.json", name));
-                        }
-
-                        let functions = if abi_path.exists() {
-                             if let Ok(content) = fs::read_to_string(&abi_path) {
-                                 if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                                     // Check if it's a Foundry artifact with "abi" field
-                                     let abi_json = if let Some(abi) = json.get("abi") {
-                                         abi
-                                     } else {
-                                         &json
-                                     };
-                                     parse_abi(abi_json).unwrap_or_default()
-                                 } else {
-                                     vec![]
-                                 }
-                             } else {
-                                 vec![]
-                             }
-                        } else {
-                            vec![]
-                        };
-                        
-                        let args = tx.arguments.map(|args| {
-                            args.iter()
-                                .map(|arg| {
-                                    if let Some(s) = arg.as_str() {
-                                        s.to_string()
-                                    } else {
-                                        arg.to_string()
-                                    }
-                                })
-                                .collect()
-                        });
-
-                        self.deployments.push(Deployment {
-                            name: name.clone(),
-                            address: address.clone(),
-                            callable_address: address, // Default to address
-                            network: network.clone(),
-                            chain_id,
-                            abi_path: if abi_path.exists() { Some(abi_path) } else { None },
-                            functions,
-                            args,
-                            is_proxy: false,
-                            implementation_set: false,
-                        });
-                    }
-                }
+        let deployed = |name: String,
+                        address: String,
+                        abi_path: Option<PathBuf>,
+                        args: Option<Vec<String>>,
+                        tx_hash: Option<String>| {
+            let (functions, events, errors) = abi_path
+                .as_deref()
+                .map(load_artifact_abi)
+                .unwrap_or_default();
+            let instance = DeploymentInstance {
+                address: address.clone(),
+                args: args.clone(),
+                timestamp,
+                tx_hash,
+                commit: run.commit.clone(),
+            };
+            Deployment {
+                name,
+                address: address.clone(),
+                callable_address: address, // Default to address
+                network: network.clone(),
+                chain_id,
+                project: None,
+                abi_path,
+                functions,
+                events,
+                errors,
+                args,
+                is_proxy: false,
+                implementation_set: false,
+                abi_chosen: false,
+                proxy: None,
+                history: vec![instance],
+            }
+        };
+
+        let mut deployments = Vec::new();
+        for tx in run.transactions.iter().flatten() {
+            if matches!(tx.transaction_type.as_str(), "CREATE" | "CREATE2")
+                && let (Some(name), Some(address)) = (&tx.contract_name, &tx.contract_address)
+            {
+                let args = tx.arguments.as_ref().map(|args| {
+                    args.iter()
+                        .map(|arg| {
+                            if let Some(s) = arg.as_str() {
+                                s.to_string()
+                            } else {
+                                arg.to_string()
+                            }
+                        })
+                        .collect()
+                });
+
+                // CREATE2 goes through a deployer that takes a 32-byte salt before the init code
+                let input = tx
+                    .transaction
+                    .as_ref()
+                    .and_then(|t| t.input.as_deref())
+                    .and_then(|input| hex::decode(input.trim_start_matches("0x")).ok());
+                let init_code = match tx.transaction_type.as_str() {
+                    "CREATE2" => input.as_ref().and_then(|input| input.get(32..)),
+                    _ => input.as_deref(),
+                };
+
+                deployments.push(deployed(
+                    contract_name(name),
+                    address.clone(),
+                    self.artifact_path(name, init_code),
+                    args,
+                    tx.hash.clone(),
+                ));
+            }
+
+            for created in &tx.additional_contracts {
+                // Prefer the artifact Foundry named; otherwise match the creation code
+                let init_code = created
+                    .init_code
+                    .as_deref()
+                    .and_then(|code| hex::decode(code.trim_start_matches("0x")).ok());
+                let abi_path = match &created.contract_name {
+                    Some(name) => self.artifact_path(name, init_code.as_deref()),
+                    None => init_code
+                        .as_deref()
+                        .and_then(|code| self.artifacts.find_by_init_code(code))
+                        .map(Path::to_path_buf),
+                };
+                let name = created
+                    .contract_name
+                    .as_deref()
+                    .map(contract_name)
+                    .or_else(|| abi_path.as_deref().and_then(artifact_name))
+                    .unwrap_or_else(|| "Unknown".to_string());
+
+                deployments.push(deployed(
+                    name,
+                    created.address.clone(),
+                    abi_path,
+                    None,
+                    tx.hash.clone(),
+                ));
+            }
+        }
+        Ok(deployments)
+    }
+
+    /// Compiled artifact for a contract name or `path:Name`, if exactly one matches.
+    ///
+    /// `init_code` tells apart contracts that share a name.
+    fn artifact_path(&self, name: &str, init_code: Option<&[u8]>) -> Option<PathBuf> {
+        match self.artifacts.resolve(name, init_code) {
+            Ok(path) => path.map(Path::to_path_buf),
+            Err(collision) => {
+                tracing::warn!(
+                    "Contract name {} matches several artifacts: {}",
+                    collision.name,
+                    collision.candidates.join(", ")
+                );
+                None
             }
         }
-        Ok(())
+    }
+
+    /// Switch a deployment to one of its earlier (or later) instances
+    pub fn select_instance(&mut self, index: usize, instance: usize) {
+        if let Some(deployment) = self.deployments.get_mut(index) {
+            deployment.select_instance(instance);
+        }
+    }
+
+    /// Line a fresh scan up with the list it replaces. Contracts found again (same
+    /// project, chain and address) keep their position and what the user chose for
+    /// them: a picked ABI, a confirmed implementation, an earlier instance. New
+    /// contracts are appended.
+    pub fn carry_over(&mut self, previous: &[Deployment]) {
+        let mut fresh = std::mem::take(&mut self.deployments);
+        for old in previous {
+            // An earlier instance the user picked is still found in the history
+            let picked_instance = old.current_instance().is_some_and(|i| i > 0);
+            let Some(i) = fresh.iter().position(|d| {
+                d.project == old.project
+                    && d.chain_id == old.chain_id
+                    && (d.address == old.address
+                        || picked_instance && d.history.iter().any(|h| h.address == old.address))
+            }) else {
+                continue;
+            };
+
+            let mut deployment = fresh.remove(i);
+            if old.abi_chosen {
+                deployment.functions = old.functions.clone();
+                deployment.events = old.events.clone();
+                deployment.errors = old.errors.clone();
+                deployment.abi_path = old.abi_path.clone();
+                deployment.abi_chosen = true;
+            }
+            deployment.implementation_set |= old.implementation_set;
+            if let Some(instance) = deployment
+                .history
+                .iter()
+                .position(|h| h.address == old.address)
+                && instance > 0
+            {
+                deployment.select_instance(instance);
+            }
+            self.deployments.push(deployment);
+        }
+        self.deployments.append(&mut fresh);
     }
 
     /// Process deployments to handle proxy patterns
@@ -165,12 +440,12 @@ impl DeploymentManager {
             } else {
                 deployment.name.clone()
             };
-            
+
             base_to_deployments
                 .entry((base_name, deployment.chain_id))
                 .or_default()
                 .push(i);
-                
+
             address_to_index.insert((deployment.address.clone(), deployment.chain_id), i);
         }
 
@@ -199,40 +474,292 @@ impl DeploymentManager {
                 }
             }
         }
-        
+
         // 2. Argument-based matching (ERC1967/Transparent proxies)
         // Check if any deployment has an argument that matches another deployment's address
         let mut links = Vec::new();
         for (proxy_idx, deployment) in self.deployments.iter().enumerate() {
             // Only consider deployments that haven't been hidden yet (or even if they have, maybe they are proxies?)
             // And usually proxies have arguments.
-            if let Some(args) = &deployment.args {
-                if !args.is_empty() {
-                    // Check first argument for implementation address
-                    let potential_impl = &args[0];
-                    if let Some(&impl_idx) = address_to_index.get(&(potential_impl.clone(), deployment.chain_id)) {
-                        if impl_idx != proxy_idx {
-                             links.push((proxy_idx, impl_idx));
-                        }
-                    }
+            if let Some(args) = &deployment.args
+                && !args.is_empty()
+            {
+                // Check first argument for implementation address
+                let potential_impl = &args[0];
+                if let Some(&impl_idx) =
+                    address_to_index.get(&(potential_impl.clone(), deployment.chain_id))
+                    && impl_idx != proxy_idx
+                {
+                    links.push((proxy_idx, impl_idx));
                 }
             }
         }
-        
+
         for (proxy_idx, impl_idx) in links {
-             // If we found a link, update the implementation to use proxy address
-             // Only if we haven't already updated it (or maybe we want to overwrite?)
-             // Let's assume argument-based linking is strong.
-
-             // Check if we already handled this via name matching
-             if !self.deployments[proxy_idx].name.ends_with("_hidden") {
-                  self.deployments[impl_idx].callable_address =
-                      self.deployments[proxy_idx].address.clone();
-                  self.deployments[impl_idx].is_proxy = true;
-
-                  self.deployments[proxy_idx].name =
-                      format!("{}_hidden", self.deployments[proxy_idx].name);
-             }
+            // If we found a link, update the implementation to use proxy address
+            // Only if we haven't already updated it (or maybe we want to overwrite?)
+            // Let's assume argument-based linking is strong.
+
+            // Check if we already handled this via name matching
+            if !self.deployments[proxy_idx].name.ends_with("_hidden") {
+                self.deployments[impl_idx].callable_address =
+                    self.deployments[proxy_idx].address.clone();
+                self.deployments[impl_idx].is_proxy = true;
+
+                self.deployments[proxy_idx].name =
+                    format!("{}_hidden", self.deployments[proxy_idx].name);
+            }
+        }
+    }
+
+    /// Detect proxies from their EIP-1967 storage slots, and diamonds from their loupe
+    /// functions, then attach the implementation or facet ABIs.
+    ///
+    /// Only chains in `rpc_urls` are checked. On those chains, the name and argument
+    /// guesses of `process_proxy_deployments` are replaced by what is on-chain.
+    pub async fn detect_proxies(&mut self, rpc_urls: &HashMap<u64, String>) {
+        let mut detected = Vec::new();
+        for (&chain_id, rpc_url) in rpc_urls {
+            match tokio::time::timeout(
+                PROXY_DETECTION_TIMEOUT,
+                self.read_proxies(chain_id, rpc_url),
+            )
+            .await
+            {
+                Ok(Ok(found)) => detected.extend(found),
+                Ok(Err(e)) => tracing::warn!("Proxy detection failed on chain {}: {}", chain_id, e),
+                Err(_) => tracing::warn!("Proxy detection timed out on chain {}", chain_id),
+            }
+        }
+        self.apply_detected_proxies(detected);
+    }
+
+    async fn read_proxies(&self, chain_id: u64, rpc_url: &str) -> Result<Vec<DetectedProxy>> {
+        let provider = ProviderBuilder::new()
+            .connect(rpc_url)
+            .await
+            .wrap_err("Failed to connect to RPC")?;
+
+        let mut detected = Vec::new();
+        for (index, deployment) in self.deployments.iter().enumerate() {
+            if deployment.chain_id != chain_id {
+                continue;
+            }
+            let Ok(address) = deployment.address.parse::<Address>() else {
+                continue;
+            };
+
+            let proxy = detect_proxy(&provider, address).await?;
+            let artifact = match &proxy {
+                Some(info)
+                    if self
+                        .find_deployment(&info.implementation, chain_id)
+                        .is_none() =>
+                {
+                    let code = provider
+                        .get_code_at(info.implementation)
+                        .await
+                        .wrap_err("Failed to fetch implementation code")?;
+                    self.artifacts.find_by_code(&code).map(Path::to_path_buf)
+                }
+                _ => None,
+            };
+            let facets = match proxy {
+                Some(_) => None,
+                None => match detect_diamond(&provider, address).await {
+                    Some(facets) => Some(self.resolve_facets(&provider, chain_id, facets).await?),
+                    None => None,
+                },
+            };
+            detected.push(DetectedProxy {
+                index,
+                proxy,
+                artifact,
+                facets,
+            });
+        }
+        Ok(detected)
+    }
+
+    /// Find each facet's ABI from a known deployment at its address, or an artifact matching its code
+    async fn resolve_facets<P: Provider>(
+        &self,
+        provider: &P,
+        chain_id: u64,
+        facets: Vec<Facet>,
+    ) -> Result<Vec<FacetAbi>> {
+        let mut resolved = Vec::with_capacity(facets.len());
+        for facet in facets {
+            let (name, (functions, events, errors)) =
+                if let Some(idx) = self.find_deployment(&facet.address, chain_id) {
+                    let d = &self.deployments[idx];
+                    (
+                        Some(d.name.clone()),
+                        (d.functions.clone(), d.events.clone(), d.errors.clone()),
+                    )
+                } else {
+                    let code = provider
+                        .get_code_at(facet.address)
+                        .await
+                        .wrap_err("Failed to fetch facet code")?;
+                    match self.artifacts.find_by_code(&code) {
+                        Some(path) => (artifact_name(path), load_artifact_abi(path)),
+                        None => (None, (vec![], vec![], vec![])),
+                    }
+                };
+            resolved.push(FacetAbi {
+                facet,
+                name,
+                functions,
+                events,
+                errors,
+            });
+        }
+        Ok(resolved)
+    }
+
+    /// Index of the deployment at `address` on `chain_id`
+    fn find_deployment(&self, address: &Address, chain_id: u64) -> Option<usize> {
+        self.deployments.iter().position(|d| {
+            d.chain_id == chain_id && d.address.parse::<Address>().ok().as_ref() == Some(address)
+        })
+    }
+
+    fn apply_detected_proxies(&mut self, detected: Vec<DetectedProxy>) {
+        // Undo guessed links for every contract whose slots were read
+        for checked in &detected {
+            let (address, chain_id) = {
+                let d = &mut self.deployments[checked.index];
+                if let Some(name) = d.name.strip_suffix("_hidden") {
+                    d.name = name.to_string();
+                }
+                (d.address.clone(), d.chain_id)
+            };
+            for d in &mut self.deployments {
+                if d.chain_id == chain_id && d.address != address && d.callable_address == address {
+                    d.callable_address = d.address.clone();
+                    d.is_proxy = false;
+                }
+            }
+        }
+
+        for DetectedProxy {
+            index,
+            proxy,
+            artifact,
+            facets,
+        } in detected
+        {
+            if let Some(facets) = facets {
+                self.apply_diamond_facets(index, facets);
+            }
+            let Some(mut info) = proxy else {
+                continue;
+            };
+            let (proxy_address, chain_id) = {
+                let d = &self.deployments[index];
+                (d.address.clone(), d.chain_id)
+            };
+
+            if let Some(impl_idx) = self.find_deployment(&info.implementation, chain_id) {
+                // Known implementation: route its ABI through the proxy, as name matching does
+                let implementation = &mut self.deployments[impl_idx];
+                info.implementation_name = Some(implementation.name.clone());
+                implementation.callable_address = proxy_address;
+                implementation.is_proxy = true;
+                implementation.implementation_set = true;
+                implementation.proxy = Some(info);
+
+                let proxy = &mut self.deployments[index];
+                proxy.name = format!("{}_hidden", proxy.name);
+            } else if let Some(path) = artifact {
+                // Implementation deployed elsewhere: use the matching artifact's ABI
+                let (functions, events, errors) = load_artifact_abi(&path);
+                info.implementation_name =
+                    path.file_stem().map(|s| s.to_string_lossy().into_owned());
+                let proxy = &mut self.deployments[index];
+                proxy.functions = functions;
+                proxy.events = events;
+                proxy.errors = errors;
+                proxy.abi_path = Some(path);
+                proxy.is_proxy = true;
+                proxy.implementation_set = true;
+                proxy.proxy = Some(info);
+            } else {
+                // Unknown implementation: the user picks an ABI when opening it
+                let proxy = &mut self.deployments[index];
+                proxy.is_proxy = true;
+                proxy.proxy = Some(info);
+            }
+        }
+    }
+
+    /// Merge the facet ABIs of a diamond into one function list at the diamond's address
+    fn apply_diamond_facets(&mut self, index: usize, facets: Vec<FacetAbi>) {
+        let mut functions = Vec::new();
+        let mut events: Vec<ContractEvent> = Vec::new();
+        let mut errors: Vec<ContractError> = Vec::new();
+
+        for facet in facets {
+            let Some(name) = facet.name else {
+                tracing::warn!(
+                    "No ABI found for facet {} ({} selectors)",
+                    facet.facet.address,
+                    facet.facet.selectors.len()
+                );
+                continue;
+            };
+
+            // Only the selectors the diamond routes to this facet are callable through it
+            functions.extend(
+                facet
+                    .functions
+                    .into_iter()
+                    .filter(|f| facet.facet.selectors.contains(&function_selector(f)))
+                    .map(|mut f| {
+                        f.facet = Some(name.clone());
+                        f
+                    }),
+            );
+            for event in facet.events {
+                if !events
+                    .iter()
+                    .any(|e| event_signature(e) == event_signature(&event))
+                {
+                    events.push(event);
+                }
+            }
+            for error in facet.errors {
+                if !errors
+                    .iter()
+                    .any(|e| error_signature(e) == error_signature(&error))
+                {
+                    errors.push(error);
+                }
+            }
+        }
+
+        if functions.is_empty() {
+            return;
+        }
+        let diamond = &mut self.deployments[index];
+        diamond.functions = functions;
+        diamond.events = events;
+        diamond.errors = errors;
+        diamond.implementation_set = true;
+    }
+
+    /// Append another workspace project's deployments, labelled with `project` if given
+    pub fn merge(&mut self, other: &DeploymentManager, project: Option<&str>) {
+        self.deployments
+            .extend(other.deployments.iter().map(|deployment| Deployment {
+                project: project.map(str::to_string),
+                ..deployment.clone()
+            }));
+        for collision in &other.collisions {
+            if !self.collisions.contains(collision) {
+                self.collisions.push(collision.clone());
+            }
         }
     }
 
@@ -240,18 +767,40 @@ impl DeploymentManager {
     /// Returns a list of chain IDs that don't have configured networks
     pub fn scan(&mut self) -> Result<Vec<u64>> {
         self.deployments.clear();
+        self.collisions.clear();
+        self.artifacts = ArtifactIndex::build(&self.out_dir);
 
-        if !self.broadcast_dir.exists() {
-            tracing::info!(
-                "Broadcast directory does not exist: {:?}",
-                self.broadcast_dir
-            );
-            return Ok(vec![]);
+        let mut runs = Vec::new();
+        match self.project_type {
+            ProjectType::Foundry => {
+                if !self.broadcast_dir.exists() {
+                    tracing::info!(
+                        "Broadcast directory does not exist: {:?}",
+                        self.broadcast_dir
+                    );
+                    return Ok(vec![]);
+                }
+
+                // Walk through broadcast directory structure:
+                // broadcast/<ScriptName>.s.sol/<ChainId>/run-<timestamp>.json (and run-latest.json)
+                self.scan_broadcast_dir(&self.broadcast_dir, &mut runs)?;
+            }
+            ProjectType::Hardhat => {
+                // deployments/<network>/<Name>.json and ignition/deployments/<id>/deployed_addresses.json
+                runs.extend(hardhat::read_hardhat_deploy(&self.broadcast_dir));
+                runs.extend(hardhat::read_ignition(&self.ignition_dir));
+            }
         }
+        self.deployments = merge_runs(runs);
 
-        // Walk through broadcast directory structure:
-        // broadcast/<ScriptName>.s.sol/<ChainId>/run-latest.json
-        self.scan_broadcast_dir(&self.broadcast_dir.clone())?;
+        // Report names we couldn't pick an artifact for
+        for deployment in self.deployments.iter().filter(|d| d.abi_path.is_none()) {
+            if let Err(collision) = self.artifacts.resolve(&deployment.name, None)
+                && !self.collisions.contains(&collision)
+            {
+                self.collisions.push(collision);
+            }
+        }
 
         // Post-process deployments to handle proxies
         self.process_proxy_deployments();
@@ -270,19 +819,24 @@ impl DeploymentManager {
         Ok(chain_ids)
     }
 
-    fn scan_broadcast_dir(&mut self, dir: &PathBuf) -> Result<()> {
+    fn scan_broadcast_dir(&self, dir: &PathBuf, runs: &mut Vec<Vec<Deployment>>) -> Result<()> {
         let entries = fs::read_dir(dir).wrap_err_with(|| format!("Failed to read {:?}", dir))?;
 
         for entry in entries.flatten() {
             let path = entry.path();
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
 
             if path.is_dir() {
-                // Recurse into subdirectories
-                self.scan_broadcast_dir(&path)?;
-            } else if path.file_name().is_some_and(|n| n == "run-latest.json") {
+                // Dry runs never deployed anything
+                if file_name != "dry-run" {
+                    self.scan_broadcast_dir(&path, runs)?;
+                }
+            } else if file_name.starts_with("run-") && file_name.ends_with(".json") {
                 // Parse the run file
-                if let Err(e) = self.parse_run_file(&path) {
-                    tracing::warn!("Failed to parse {:?}: {}", path, e);
+                match self.parse_run_file(&path) {
+                    Ok(run) => runs.push(run),
+                    Err(e) => tracing::warn!("Failed to parse {:?}: {}", path, e),
                 }
             }
         }
@@ -291,9 +845,78 @@ impl DeploymentManager {
     }
 }
 
+/// Contract name from a name or fully qualified `path:Name`
+fn contract_name(name: &str) -> String {
+    name.rsplit(':').next().unwrap_or(name).to_string()
+}
+
+/// Contract name from an artifact file name, `<Name>.json` or `<Name>.<solc version>.json`
+fn artifact_name(path: &Path) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    file_name.split('.').next().map(str::to_string)
+}
+
+/// Merge the deployments of every run into one entry per contract and chain.
+///
+/// The n-th `Name` created in a run is matched with the n-th `Name` of other runs,
+/// so several instances deployed together stay separate. Each entry uses its newest
+/// instance and keeps the rest in `history`. `run-latest.json` repeats the newest
+/// timestamped run, so instances are deduplicated by address and transaction.
+fn merge_runs(runs: Vec<Vec<Deployment>>) -> Vec<Deployment> {
+    let mut merged: Vec<Deployment> = Vec::new();
+    let mut index: HashMap<(u64, String, usize), usize> = HashMap::new();
+
+    for run in runs {
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        for deployment in run {
+            let occurrence = occurrences.entry(deployment.name.clone()).or_default();
+            let key = (deployment.chain_id, deployment.name.clone(), *occurrence);
+            *occurrence += 1;
+
+            let Some(&i) = index.get(&key) else {
+                index.insert(key, merged.len());
+                merged.push(deployment);
+                continue;
+            };
+
+            let existing = &mut merged[i];
+            for instance in &deployment.history {
+                if !existing
+                    .history
+                    .iter()
+                    .any(|h| h.address == instance.address && h.tx_hash == instance.tx_hash)
+                {
+                    existing.history.push(instance.clone());
+                }
+            }
+            let newest = |d: &Deployment| d.history.first().and_then(|h| h.timestamp);
+            if newest(&deployment) > newest(existing) {
+                let history = std::mem::take(&mut existing.history);
+                *existing = Deployment {
+                    history,
+                    ..deployment
+                };
+            }
+        }
+    }
+
+    for deployment in &mut merged {
+        deployment
+            .history
+            .sort_by_key(|h| std::cmp::Reverse(h.timestamp));
+        if let Some(newest) = deployment.history.first() {
+            deployment.address = newest.address.clone();
+            deployment.callable_address = newest.address.clone();
+            deployment.args = newest.args.clone();
+        }
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contracts::proxy::ProxyKind;
 
     #[test]
     fn test_chain_id_to_network() {
@@ -304,6 +927,7 @@ mod tests {
     fn test_process_proxy_deployments() {
         let project_root = PathBuf::from(".");
         let mut manager = DeploymentManager {
+            project_type: ProjectType::Foundry,
             deployments: vec![
                 Deployment {
                     name: "Counter".to_string(),
@@ -311,11 +935,17 @@ mod tests {
                     callable_address: "0xImpl".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 31337,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: None,
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
                 Deployment {
                     name: "CounterProxy".to_string(),
@@ -323,15 +953,24 @@ mod tests {
                     callable_address: "0xProxy".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 31337,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: None,
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
             ],
             broadcast_dir: project_root.join("broadcast"),
+            ignition_dir: project_root.join("ignition/deployments"),
             out_dir: project_root.join("out"),
+            artifacts: ArtifactIndex::default(),
+            collisions: vec![],
         };
 
         manager.process_proxy_deployments();
@@ -345,6 +984,7 @@ mod tests {
     fn test_process_proxy_deployments_cross_chain() {
         let project_root = PathBuf::from(".");
         let mut manager = DeploymentManager {
+            project_type: ProjectType::Foundry,
             deployments: vec![
                 Deployment {
                     name: "Counter".to_string(),
@@ -352,11 +992,17 @@ mod tests {
                     callable_address: "0xImpl".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 1,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: None,
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
                 Deployment {
                     name: "CounterProxy".to_string(),
@@ -364,15 +1010,24 @@ mod tests {
                     callable_address: "0xProxy".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 2,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: None,
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
             ],
             broadcast_dir: project_root.join("broadcast"),
+            ignition_dir: project_root.join("ignition/deployments"),
             out_dir: project_root.join("out"),
+            artifacts: ArtifactIndex::default(),
+            collisions: vec![],
         };
 
         manager.process_proxy_deployments();
@@ -387,6 +1042,7 @@ mod tests {
     fn test_process_proxy_deployments_with_args() {
         let project_root = PathBuf::from(".");
         let mut manager = DeploymentManager {
+            project_type: ProjectType::Foundry,
             deployments: vec![
                 Deployment {
                     name: "Counter".to_string(),
@@ -394,11 +1050,17 @@ mod tests {
                     callable_address: "0xImpl".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 31337,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: None,
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
                 Deployment {
                     name: "ERC1967Proxy".to_string(),
@@ -406,15 +1068,24 @@ mod tests {
                     callable_address: "0xProxy".to_string(),
                     network: "localhost".to_string(),
                     chain_id: 31337,
+                    project: None,
                     abi_path: None,
                     functions: vec![],
+                    events: vec![],
+                    errors: vec![],
                     args: Some(vec!["0xImpl".to_string(), "0xData".to_string()]),
                     is_proxy: false,
                     implementation_set: false,
+                    abi_chosen: false,
+                    proxy: None,
+                    history: vec![],
                 },
             ],
             broadcast_dir: project_root.join("broadcast"),
+            ignition_dir: project_root.join("ignition/deployments"),
             out_dir: project_root.join("out"),
+            artifacts: ArtifactIndex::default(),
+            collisions: vec![],
         };
 
         manager.process_proxy_deployments();
@@ -424,4 +1095,324 @@ mod tests {
         assert!(manager.deployments[0].is_proxy);
         assert_eq!(manager.deployments[1].name, "ERC1967Proxy_hidden");
     }
+
+    fn deployment(name: &str, address: &str) -> Deployment {
+        Deployment {
+            name: name.to_string(),
+            address: address.to_string(),
+            callable_address: address.to_string(),
+            network: "localhost".to_string(),
+            chain_id: 31337,
+            project: None,
+            abi_path: None,
+            functions: vec![],
+            events: vec![],
+            errors: vec![],
+            args: None,
+            is_proxy: false,
+            implementation_set: false,
+            abi_chosen: false,
+            proxy: None,
+            history: vec![],
+        }
+    }
+
+    const IMPL: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+    const PROXY: &str = "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512";
+    const ADMIN: &str = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0";
+
+    fn manager(deployments: Vec<Deployment>) -> DeploymentManager {
+        DeploymentManager {
+            deployments,
+            project_type: ProjectType::Foundry,
+            broadcast_dir: PathBuf::from("broadcast"),
+            ignition_dir: PathBuf::from("ignition/deployments"),
+            out_dir: PathBuf::from("out"),
+            artifacts: ArtifactIndex::default(),
+            collisions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_detected_proxy_links_known_implementation() {
+        // Deployed by a factory: neither names nor arguments give it away
+        let mut manager = manager(vec![deployment("Vault", IMPL), deployment("Clone", PROXY)]);
+        manager.process_proxy_deployments();
+        assert!(!manager.deployments[0].is_proxy);
+
+        let info = ProxyInfo {
+            kind: ProxyKind::Transparent,
+            implementation: IMPL.parse().unwrap(),
+            admin: Some(ADMIN.parse().unwrap()),
+            beacon: None,
+            implementation_name: None,
+        };
+        manager.apply_detected_proxies(vec![
+            DetectedProxy {
+                index: 0,
+                proxy: None,
+                artifact: None,
+                facets: None,
+            },
+            DetectedProxy {
+                index: 1,
+                proxy: Some(info),
+                artifact: None,
+                facets: None,
+            },
+        ]);
+
+        let vault = &manager.deployments[0];
+        assert_eq!(vault.callable_address, PROXY);
+        assert!(vault.is_proxy && vault.implementation_set);
+        let proxy = vault.proxy.as_ref().unwrap();
+        assert_eq!(proxy.admin, Some(ADMIN.parse().unwrap()));
+        assert_eq!(proxy.implementation_name.as_deref(), Some("Vault"));
+        assert_eq!(manager.deployments[1].name, "Clone_hidden");
+    }
+
+    #[test]
+    fn test_detected_proxies_undo_name_guess() {
+        // "CounterProxy" is not actually a proxy on-chain
+        let mut manager = manager(vec![
+            deployment("Counter", IMPL),
+            deployment("CounterProxy", PROXY),
+        ]);
+        manager.process_proxy_deployments();
+        assert_eq!(manager.deployments[0].callable_address, PROXY);
+
+        manager.apply_detected_proxies(vec![
+            DetectedProxy {
+                index: 0,
+                proxy: None,
+                artifact: None,
+                facets: None,
+            },
+            DetectedProxy {
+                index: 1,
+                proxy: None,
+                artifact: None,
+                facets: None,
+            },
+        ]);
+
+        assert_eq!(manager.deployments[0].callable_address, IMPL);
+        assert!(!manager.deployments[0].is_proxy);
+        assert_eq!(manager.deployments[1].name, "CounterProxy");
+    }
+
+    #[test]
+    fn test_diamond_facets_merge_into_one_contract() {
+        let abi: Value = serde_json::from_str(
+            r#"[
+                {"type":"function","name":"owner","inputs":[],"outputs":[{"name":"","type":"address"}],"stateMutability":"view"},
+                {"type":"function","name":"transferOwnership","inputs":[{"name":"newOwner","type":"address"}],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"event","name":"OwnershipTransferred","inputs":[{"name":"previousOwner","type":"address","indexed":true},{"name":"newOwner","type":"address","indexed":true}],"anonymous":false}
+            ]"#,
+        )
+        .unwrap();
+        let functions = parse_abi(&abi).unwrap();
+        let owner_selector = function_selector(&functions[0]);
+
+        let mut manager = manager(vec![deployment("Diamond", PROXY)]);
+        manager.apply_detected_proxies(vec![DetectedProxy {
+            index: 0,
+            facets: Some(vec![
+                FacetAbi {
+                    // Only owner() is routed to this facet
+                    facet: Facet {
+                        address: IMPL.parse().unwrap(),
+                        selectors: vec![owner_selector],
+                    },
+                    name: Some("OwnershipFacet".to_string()),
+                    functions,
+                    events: parse_events(&abi).unwrap(),
+                    errors: vec![],
+                },
+                FacetAbi {
+                    facet: Facet {
+                        address: ADMIN.parse().unwrap(),
+                        selectors: vec![[0x12, 0x34, 0x56, 0x78].into()],
+                    },
+                    name: None,
+                    functions: vec![],
+                    events: vec![],
+                    errors: vec![],
+                },
+            ]),
+            ..Default::default()
+        }]);
+
+        let diamond = &manager.deployments[0];
+        assert_eq!(diamond.callable_address, PROXY);
+        assert!(diamond.implementation_set);
+        assert_eq!(diamond.functions.len(), 1);
+        assert_eq!(diamond.functions[0].name, "owner");
+        assert_eq!(
+            diamond.functions[0].facet.as_deref(),
+            Some("OwnershipFacet")
+        );
+        assert_eq!(diamond.events.len(), 1);
+    }
+
+    fn deployed(name: &str, address: &str, timestamp: u64, tx: &str) -> Deployment {
+        Deployment {
+            history: vec![DeploymentInstance {
+                address: address.to_string(),
+                args: None,
+                timestamp: Some(timestamp),
+                tx_hash: Some(tx.to_string()),
+                commit: Some("abc1234".to_string()),
+            }],
+            ..deployment(name, address)
+        }
+    }
+
+    #[test]
+    fn test_merge_runs_builds_history() {
+        let runs = vec![
+            // run-latest.json repeats the newest run
+            vec![deployed("Token", PROXY, 200, "0x02")],
+            vec![
+                deployed("Token", IMPL, 100, "0x01"),
+                deployed("Token", ADMIN, 100, "0x03"),
+            ],
+            vec![deployed("Token", PROXY, 200, "0x02")],
+        ];
+        let merged = merge_runs(runs);
+
+        // The second Token of the first run is a separate contract
+        assert_eq!(merged.len(), 2);
+        let token = &merged[0];
+        assert_eq!(token.address, PROXY);
+        assert_eq!(token.callable_address, PROXY);
+        assert_eq!(token.current_instance(), Some(0));
+        let addresses: Vec<_> = token.history.iter().map(|h| h.address.as_str()).collect();
+        assert_eq!(addresses, [PROXY, IMPL]);
+        assert_eq!(merged[1].address, ADMIN);
+        assert_eq!(merged[1].history.len(), 1);
+    }
+
+    #[test]
+    fn test_select_instance() {
+        let mut token = deployed("Token", PROXY, 200, "0x02");
+        token
+            .history
+            .extend(deployed("Token", IMPL, 100, "0x01").history);
+        let mut manager = manager(vec![token]);
+
+        manager.select_instance(0, 1);
+        let token = &manager.deployments[0];
+        assert_eq!(token.address, IMPL);
+        assert_eq!(token.callable_address, IMPL);
+        assert_eq!(token.current_instance(), Some(1));
+
+        // Out of range is ignored
+        manager.select_instance(0, 5);
+        assert_eq!(manager.deployments[0].address, IMPL);
+    }
+
+    #[test]
+    fn test_carry_over_keeps_user_choices() {
+        let history = |d: Deployment, older: Deployment| Deployment {
+            history: [d.history.clone(), older.history].concat(),
+            ..d
+        };
+        let mut previous = manager(vec![
+            deployment("Vault", ADMIN),
+            history(
+                deployed("Token", PROXY, 200, "0x02"),
+                deployed("Token", IMPL, 100, "0x01"),
+            ),
+        ]);
+        previous.deployments[0].abi_path = Some(PathBuf::from("Picked.json"));
+        previous.deployments[0].abi_chosen = true;
+        previous.deployments[0].implementation_set = true;
+        previous.select_instance(1, 1);
+
+        // The rescan finds the same contracts in another order, plus a new one
+        let mut fresh = manager(vec![
+            deployment("Counter", "0xC0FFEE"),
+            history(
+                deployed("Token", PROXY, 200, "0x02"),
+                deployed("Token", IMPL, 100, "0x01"),
+            ),
+            deployment("Vault", ADMIN),
+        ]);
+        fresh.carry_over(&previous.deployments);
+
+        let names: Vec<_> = fresh.deployments.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["Vault", "Token", "Counter"]);
+        let vault = &fresh.deployments[0];
+        assert_eq!(vault.abi_path, Some(PathBuf::from("Picked.json")));
+        assert!(vault.abi_chosen && vault.implementation_set);
+        assert_eq!(fresh.deployments[1].address, IMPL);
+        assert!(!fresh.deployments[2].abi_chosen);
+    }
+
+    #[test]
+    fn test_parse_run_file_create2_and_additional_contracts() {
+        let root = std::env::temp_dir().join(format!("runic-run-{}", std::process::id()));
+        let out_dir = root.join("out");
+        let run_dir = root.join("broadcast/Deploy.s.sol/31337");
+        fs::create_dir_all(out_dir.join("Vault.sol")).unwrap();
+        fs::create_dir_all(out_dir.join("Clone.sol")).unwrap();
+        fs::create_dir_all(&run_dir).unwrap();
+
+        let abi = r#"[{"type":"function","name":"owner","inputs":[],"outputs":[{"name":"","type":"address"}],"stateMutability":"view"}]"#;
+        fs::write(
+            out_dir.join("Vault.sol/Vault.json"),
+            format!(r#"{{"abi":{},"bytecode":{{"object":"0x6080aa"}}}}"#, abi),
+        )
+        .unwrap();
+        fs::write(
+            out_dir.join("Clone.sol/Clone.json"),
+            format!(r#"{{"abi":{},"bytecode":{{"object":"0x6080bb"}}}}"#, abi),
+        )
+        .unwrap();
+
+        let run = format!(
+            r#"{{"transactions":[
+                {{"hash":"0x01","transactionType":"CREATE2","contractName":"Vault","contractAddress":"{IMPL}"}},
+                {{"hash":"0x02","transactionType":"CALL","contractName":null,"contractAddress":"{IMPL}",
+                  "additionalContracts":[
+                    {{"transactionType":"CREATE2","address":"{PROXY}","initCode":"0x6080bb0000"}},
+                    {{"transactionType":"CREATE","address":"{ADMIN}","initCode":"0xdead"}}
+                  ]}}
+            ],"timestamp":1700000000000,"commit":"abc1234"}}"#
+        );
+        let path = run_dir.join("run-1700000000000.json");
+        fs::write(&path, run).unwrap();
+
+        let manager = DeploymentManager {
+            deployments: vec![],
+            project_type: ProjectType::Foundry,
+            broadcast_dir: root.join("broadcast"),
+            ignition_dir: root.join("ignition/deployments"),
+            artifacts: ArtifactIndex::build(&out_dir),
+            out_dir,
+            collisions: vec![],
+        };
+        let deployments = manager.parse_run_file(&path);
+        fs::remove_dir_all(&root).unwrap();
+        let deployments = deployments.unwrap();
+
+        let names: Vec<_> = deployments.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["Vault", "Clone", "Unknown"]);
+        assert_eq!(deployments[0].functions.len(), 1);
+        assert_eq!(deployments[1].address, PROXY);
+        assert_eq!(deployments[1].functions.len(), 1);
+        assert_eq!(deployments[1].history[0].tx_hash.as_deref(), Some("0x02"));
+        assert_eq!(deployments[1].history[0].timestamp, Some(1_700_000_000));
+        assert!(deployments[2].abi_path.is_none());
+    }
+
+    #[test]
+    fn test_strip_metadata() {
+        // 0xa2 0x64 ... is CBOR; the last two bytes give its length (3)
+        let code = [0x60, 0x80, 0x60, 0x40, 0xa2, 0x64, 0x69, 0x00, 0x03];
+        assert_eq!(strip_metadata(&code), &[0x60, 0x80, 0x60, 0x40]);
+        assert_eq!(strip_metadata(&[0x00, 0xff]), &[0x00, 0xff]);
+        assert_eq!(strip_metadata(&[]), &[] as &[u8]);
+    }
 }
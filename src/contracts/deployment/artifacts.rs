@@ -0,0 +1,275 @@
+//! Index of compiled artifacts in Foundry `out/` and Hardhat `artifacts/` directories
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use alloy::primitives::{B256, keccak256};
+use serde_json::Value;
+
+use super::strip_metadata;
+
+/// A compiled contract found while indexing
+#[derive(Debug, Clone)]
+struct Artifact {
+    path: PathBuf,
+    /// Fully qualified `path/to/File.sol:Name`
+    id: String,
+    name: String,
+    creation_code: Vec<u8>,
+    /// Hash of the runtime bytecode without its metadata
+    runtime_hash: Option<B256>,
+}
+
+/// A contract name shared by artifacts from different source files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactCollision {
+    pub name: String,
+    /// Fully qualified identifiers of the candidates
+    pub candidates: Vec<String>,
+}
+
+/// Contract names and fully qualified identifiers mapped to artifact files, built once per scan
+#[derive(Debug, Default)]
+pub(super) struct ArtifactIndex {
+    artifacts: Vec<Artifact>,
+    by_name: HashMap<String, Vec<usize>>,
+    by_id: HashMap<String, Vec<usize>>,
+}
+
+impl ArtifactIndex {
+    /// Index every artifact under `dir`, skipping build-info and Hardhat debug files
+    pub fn build(dir: &Path) -> Self {
+        let mut index = Self::default();
+        index.walk(dir);
+        index
+    }
+
+    fn walk(&mut self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if path.is_dir() {
+                if file_name != "build-info" {
+                    self.walk(&path);
+                }
+            } else if file_name.ends_with(".json")
+                && !file_name.ends_with(".dbg.json")
+                && let Some(artifact) = read_artifact(&path)
+            {
+                let i = self.artifacts.len();
+                self.by_name
+                    .entry(artifact.name.clone())
+                    .or_default()
+                    .push(i);
+                self.by_id.entry(artifact.id.clone()).or_default().push(i);
+                self.artifacts.push(artifact);
+            }
+        }
+    }
+
+    /// Artifact for a contract name or `path:Name` identifier.
+    ///
+    /// When the name is shared by several source files, `init_code` picks the one whose
+    /// creation bytecode it starts with. Returns the candidates if that doesn't settle it.
+    pub fn resolve(
+        &self,
+        name_or_id: &str,
+        init_code: Option<&[u8]>,
+    ) -> Result<Option<&Path>, ArtifactCollision> {
+        let map = if name_or_id.contains(':') {
+            &self.by_id
+        } else {
+            &self.by_name
+        };
+        let Some(indices) = map.get(name_or_id) else {
+            return Ok(None);
+        };
+
+        // The same source compiled with several solc versions is still one contract
+        let mut candidates: Vec<&Artifact> = Vec::new();
+        for &i in indices {
+            let artifact = &self.artifacts[i];
+            if !candidates.iter().any(|c| c.id == artifact.id) {
+                candidates.push(artifact);
+            }
+        }
+        if let [artifact] = candidates.as_slice() {
+            return Ok(Some(&artifact.path));
+        }
+
+        if let Some(init_code) = init_code {
+            let matching: Vec<_> = candidates
+                .iter()
+                .filter(|a| !a.creation_code.is_empty() && init_code.starts_with(&a.creation_code))
+                .collect();
+            if let [artifact] = matching.as_slice() {
+                return Ok(Some(&artifact.path));
+            }
+        }
+
+        Err(ArtifactCollision {
+            name: name_or_id.to_string(),
+            candidates: candidates.iter().map(|a| a.id.clone()).collect(),
+        })
+    }
+
+    /// Artifact whose runtime bytecode matches `code`, ignoring metadata
+    pub fn find_by_code(&self, code: &[u8]) -> Option<&Path> {
+        let code = strip_metadata(code);
+        if code.is_empty() {
+            return None;
+        }
+        let hash = keccak256(code);
+        self.artifacts
+            .iter()
+            .find(|a| a.runtime_hash == Some(hash))
+            .map(|a| a.path.as_path())
+    }
+
+    /// Artifact whose creation bytecode starts `init_code`.
+    ///
+    /// Constructor arguments are appended to the creation code, so only the prefix is compared.
+    pub fn find_by_init_code(&self, init_code: &[u8]) -> Option<&Path> {
+        self.artifacts
+            .iter()
+            .find(|a| !a.creation_code.is_empty() && init_code.starts_with(&a.creation_code))
+            .map(|a| a.path.as_path())
+    }
+}
+
+/// Read a Foundry or Hardhat artifact, or `None` if the file isn't one
+fn read_artifact(path: &Path) -> Option<Artifact> {
+    let json: Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    json.get("abi")?;
+
+    // Hardhat records the source and contract name
+    let (source, name) = match (json["sourceName"].as_str(), json["contractName"].as_str()) {
+        (Some(source), Some(name)) => (source.to_string(), name.to_string()),
+        _ => foundry_target(path, &json)?,
+    };
+
+    Some(Artifact {
+        path: path.to_path_buf(),
+        id: format!("{}:{}", source, name),
+        name,
+        creation_code: artifact_bytecode(&json, "bytecode").unwrap_or_default(),
+        runtime_hash: artifact_bytecode(&json, "deployedBytecode")
+            .filter(|code| !code.is_empty())
+            .map(|code| keccak256(strip_metadata(&code))),
+    })
+}
+
+/// Source path and contract name of a Foundry artifact.
+///
+/// Taken from the metadata's compilation target, falling back to the
+/// `out/<File>.sol/<Name>[.<solc version>].json` layout.
+fn foundry_target(path: &Path, json: &Value) -> Option<(String, String)> {
+    let metadata = match &json["rawMetadata"] {
+        Value::String(raw) => serde_json::from_str(raw).ok(),
+        _ => None,
+    };
+    let target = json["metadata"]["settings"]["compilationTarget"]
+        .as_object()
+        .or_else(|| {
+            metadata
+                .as_ref()
+                .and_then(|m: &Value| m["settings"]["compilationTarget"].as_object())
+        })
+        .and_then(|target| target.iter().next())
+        .and_then(|(source, name)| Some((source.clone(), name.as_str()?.to_string())));
+    if target.is_some() {
+        return target;
+    }
+
+    let name = path.file_name()?.to_str()?.split('.').next()?.to_string();
+    let source = path.parent()?.file_name()?.to_str()?.to_string();
+    Some((source, name))
+}
+
+/// Bytecode under `key` of a compiled artifact.
+///
+/// Foundry nests the hex under "object"; Hardhat stores it directly.
+pub(super) fn artifact_bytecode(json: &Value, key: &str) -> Option<Vec<u8>> {
+    let code = json.get(key);
+    code.and_then(|c| c.get("object"))
+        .or(code)
+        .and_then(|o| o.as_str())
+        .and_then(|h| hex::decode(h.trim_start_matches("0x")).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_artifact_index() {
+        let root = std::env::temp_dir().join(format!("runic-artifacts-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        // Foundry: file name differs from the contract name
+        write(
+            &root.join("out/Tokens.sol/Token.json"),
+            r#"{"abi":[],"bytecode":{"object":"0x6001"},"deployedBytecode":{"object":"0x6002"},
+                "metadata":{"settings":{"compilationTarget":{"src/Tokens.sol":"Token"}}}}"#,
+        );
+        // Same contract compiled twice with different solc versions
+        write(
+            &root.join("out/Vault.sol/Vault.0.8.19.json"),
+            r#"{"abi":[],"bytecode":{"object":"0x6003"}}"#,
+        );
+        write(
+            &root.join("out/Vault.sol/Vault.0.8.20.json"),
+            r#"{"abi":[],"bytecode":{"object":"0x6004"}}"#,
+        );
+        // Hardhat: another Token in a different source file
+        write(
+            &root.join("out/contracts/legacy/Token.sol/Token.json"),
+            r#"{"contractName":"Token","sourceName":"contracts/legacy/Token.sol","abi":[],"bytecode":"0x6005","deployedBytecode":"0x6006"}"#,
+        );
+        write(
+            &root.join("out/contracts/legacy/Token.sol/Token.dbg.json"),
+            r#"{"buildInfo":"../../build-info/x.json"}"#,
+        );
+        write(&root.join("out/build-info/x.json"), r#"{"abi":[]}"#);
+
+        let index = ArtifactIndex::build(&root.join("out"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(index.artifacts.len(), 4);
+        assert!(
+            index
+                .resolve("src/Tokens.sol:Token", None)
+                .unwrap()
+                .unwrap()
+                .ends_with("Tokens.sol/Token.json")
+        );
+        assert!(index.resolve("Vault", None).unwrap().is_some());
+        assert_eq!(index.resolve("Missing", None), Ok(None));
+
+        let collision = index.resolve("Token", None).unwrap_err();
+        assert_eq!(collision.candidates.len(), 2);
+        assert!(
+            collision
+                .candidates
+                .contains(&"contracts/legacy/Token.sol:Token".to_string())
+        );
+
+        // Creation code with constructor arguments settles it
+        let legacy = index.resolve("Token", Some(&[0x60, 0x05, 0xff])).unwrap();
+        assert!(legacy.unwrap().ends_with("legacy/Token.sol/Token.json"));
+
+        assert!(index.find_by_code(&[0x60, 0x06]).is_some());
+        assert!(index.find_by_init_code(&[0x60, 0x01, 0x00]).is_some());
+        assert!(index.find_by_init_code(&[0x60, 0x07]).is_none());
+    }
+}
@@ -0,0 +1,308 @@
+//! Readers for Hardhat deployment records: hardhat-deploy and Hardhat Ignition
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{Deployment, DeploymentInstance, abi_from_artifact, chain_id_to_network};
+
+/// A hardhat-deploy record: `deployments/<network>/<Name>.json`
+#[derive(Debug, Deserialize)]
+struct HardhatDeployRecord {
+    address: String,
+    #[serde(rename = "transactionHash")]
+    transaction_hash: Option<String>,
+    args: Option<Vec<Value>>,
+}
+
+/// Read hardhat-deploy deployments, one run per network directory.
+///
+/// Each network directory holds a `.chainId` file and one JSON file per contract,
+/// containing its address, ABI and NatSpec.
+pub(super) fn read_hardhat_deploy(deployments_dir: &Path) -> Vec<Vec<Deployment>> {
+    let Ok(entries) = fs::read_dir(deployments_dir) else {
+        return vec![];
+    };
+
+    let mut runs = Vec::new();
+    for entry in entries.flatten() {
+        let network_dir = entry.path();
+        if !network_dir.is_dir() {
+            continue;
+        }
+        let Some(chain_id) = fs::read_to_string(network_dir.join(".chainId"))
+            .ok()
+            .and_then(|id| id.trim().parse::<u64>().ok())
+        else {
+            tracing::warn!("No .chainId in {:?}, skipping", network_dir);
+            continue;
+        };
+
+        let Ok(files) = fs::read_dir(&network_dir) else {
+            continue;
+        };
+        let mut run = Vec::new();
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().is_none_or(|e| e != "json") {
+                continue;
+            }
+            match read_hardhat_deploy_record(&path, chain_id) {
+                Ok(deployment) => run.push(deployment),
+                Err(e) => tracing::warn!("Failed to parse {:?}: {}", path, e),
+            }
+        }
+        runs.push(run);
+    }
+    runs
+}
+
+fn read_hardhat_deploy_record(path: &Path, chain_id: u64) -> eyre::Result<Deployment> {
+    let name = path
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| eyre::eyre!("Invalid file name"))?
+        .to_string();
+
+    let json: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let record: HardhatDeployRecord = serde_json::from_value(json.clone())?;
+    let args = record.args.map(|args| {
+        args.iter()
+            .map(|arg| match arg.as_str() {
+                Some(s) => s.to_string(),
+                None => arg.to_string(),
+            })
+            .collect()
+    });
+
+    Ok(deployment(
+        name,
+        record.address,
+        chain_id,
+        path,
+        &json,
+        args,
+        record.transaction_hash,
+    ))
+}
+
+/// Read Ignition deployments, one run per `ignition/deployments/<deployment-id>` directory.
+///
+/// Addresses come from `deployed_addresses.json`, keyed by future id (`Module#Contract`),
+/// ABIs from `artifacts/<future id>.json` and transaction hashes from `journal.jsonl`.
+pub(super) fn read_ignition(ignition_dir: &Path) -> Vec<Vec<Deployment>> {
+    let Ok(entries) = fs::read_dir(ignition_dir) else {
+        return vec![];
+    };
+
+    let mut runs = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let addresses_path = dir.join("deployed_addresses.json");
+        let Some(addresses) = fs::read_to_string(&addresses_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<HashMap<String, String>>(&content).ok())
+        else {
+            continue;
+        };
+        let journal = fs::read_to_string(dir.join("journal.jsonl")).unwrap_or_default();
+
+        // Default deployment ids are `chain-<id>`; custom ones record it in the journal
+        let chain_id = entry
+            .file_name()
+            .to_str()
+            .and_then(|n| n.strip_prefix("chain-"))
+            .and_then(|id| id.parse::<u64>().ok())
+            .or_else(|| journal_chain_id(&journal));
+        let Some(chain_id) = chain_id else {
+            tracing::warn!("Unknown chain for Ignition deployment {:?}, skipping", dir);
+            continue;
+        };
+        let tx_hashes = journal_tx_hashes(&journal);
+
+        let mut future_ids: Vec<_> = addresses.keys().cloned().collect();
+        future_ids.sort();
+        let run = future_ids
+            .into_iter()
+            .map(|future_id| {
+                let name = future_id
+                    .rsplit_once('#')
+                    .map_or(future_id.as_str(), |(_, name)| name)
+                    .to_string();
+                let artifact_path = dir.join("artifacts").join(format!("{}.json", future_id));
+                let artifact = fs::read_to_string(&artifact_path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+                    .unwrap_or(Value::Null);
+                deployment(
+                    name,
+                    addresses[&future_id].clone(),
+                    chain_id,
+                    &artifact_path,
+                    &artifact,
+                    None,
+                    tx_hashes.get(&future_id).cloned(),
+                )
+            })
+            .collect();
+        runs.push(run);
+    }
+    runs
+}
+
+/// Chain id from the journal's `DEPLOYMENT_INITIALIZE` message
+fn journal_chain_id(journal: &str) -> Option<u64> {
+    journal
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .find(|msg| msg["type"] == "DEPLOYMENT_INITIALIZE")
+        .and_then(|msg| msg["chainId"].as_u64())
+}
+
+/// Confirmed transaction hash of each future, from `TRANSACTION_CONFIRM` messages
+fn journal_tx_hashes(journal: &str) -> HashMap<String, String> {
+    journal
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|msg| msg["type"] == "TRANSACTION_CONFIRM")
+        .filter_map(|msg| {
+            Some((
+                msg["futureId"].as_str()?.to_string(),
+                msg["hash"].as_str()?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+fn deployment(
+    name: String,
+    address: String,
+    chain_id: u64,
+    abi_path: &Path,
+    artifact: &Value,
+    args: Option<Vec<String>>,
+    tx_hash: Option<String>,
+) -> Deployment {
+    let (functions, events, errors) = abi_from_artifact(artifact);
+    Deployment {
+        name,
+        address: address.clone(),
+        callable_address: address.clone(),
+        network: chain_id_to_network(chain_id),
+        chain_id,
+        project: None,
+        abi_path: abi_path.exists().then(|| abi_path.to_path_buf()),
+        functions,
+        events,
+        errors,
+        args: args.clone(),
+        is_proxy: false,
+        implementation_set: false,
+        abi_chosen: false,
+        proxy: None,
+        history: vec![DeploymentInstance {
+            address,
+            args,
+            timestamp: None,
+            tx_hash,
+            commit: None,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(label: &str) -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("runic-{}-{}", label, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    const ABI: &str = r#"[{"type":"function","name":"unlockTime","inputs":[],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"}]"#;
+
+    #[test]
+    fn test_read_hardhat_deploy() {
+        let root = temp_project("hardhat-deploy");
+        let sepolia = root.join("deployments/sepolia");
+        fs::create_dir_all(sepolia.join("solcInputs")).unwrap();
+        fs::write(sepolia.join(".chainId"), "11155111\n").unwrap();
+        fs::write(
+            sepolia.join("Lock.json"),
+            format!(
+                r#"{{"address":"0x5FbDB2315678afecb367f032d93F642f64180aa3","abi":{},"transactionHash":"0xabc","args":["1700000000",5],"userdoc":{{"methods":{{"unlockTime()":{{"notice":"When funds unlock"}}}}}}}}"#,
+                ABI
+            ),
+        )
+        .unwrap();
+        // Without a chain id the network is skipped
+        fs::create_dir_all(root.join("deployments/unknown")).unwrap();
+
+        let runs = read_hardhat_deploy(&root.join("deployments"));
+        fs::remove_dir_all(&root).unwrap();
+
+        let deployments: Vec<_> = runs.into_iter().flatten().collect();
+        assert_eq!(deployments.len(), 1);
+        let lock = &deployments[0];
+        assert_eq!(lock.name, "Lock");
+        assert_eq!(lock.chain_id, 11155111);
+        assert_eq!(lock.functions.len(), 1);
+        assert_eq!(
+            lock.functions[0].notice.as_deref(),
+            Some("When funds unlock")
+        );
+        assert_eq!(
+            lock.args,
+            Some(vec!["1700000000".to_string(), "5".to_string()])
+        );
+        assert_eq!(lock.history[0].tx_hash.as_deref(), Some("0xabc"));
+    }
+
+    #[test]
+    fn test_read_ignition() {
+        let root = temp_project("ignition");
+        let deployment_dir = root.join("ignition/deployments/chain-31337");
+        fs::create_dir_all(deployment_dir.join("artifacts")).unwrap();
+        fs::write(
+            deployment_dir.join("deployed_addresses.json"),
+            r#"{"LockModule#Lock":"0x5FbDB2315678afecb367f032d93F642f64180aa3"}"#,
+        )
+        .unwrap();
+        fs::write(
+            deployment_dir.join("artifacts/LockModule#Lock.json"),
+            format!(r#"{{"contractName":"Lock","abi":{}}}"#, ABI),
+        )
+        .unwrap();
+        fs::write(
+            deployment_dir.join("journal.jsonl"),
+            concat!(
+                r#"{"chainId":31337,"type":"DEPLOYMENT_INITIALIZE"}"#,
+                "\n",
+                r#"{"futureId":"LockModule#Lock","hash":"0xdef","networkInteractionId":1,"type":"TRANSACTION_CONFIRM"}"#,
+                "\n"
+            ),
+        )
+        .unwrap();
+
+        let runs = read_ignition(&root.join("ignition/deployments"));
+        fs::remove_dir_all(&root).unwrap();
+
+        let deployments: Vec<_> = runs.into_iter().flatten().collect();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].name, "Lock");
+        assert_eq!(deployments[0].chain_id, 31337);
+        assert_eq!(deployments[0].functions.len(), 1);
+        assert_eq!(deployments[0].history[0].tx_hash.as_deref(), Some("0xdef"));
+    }
+
+    #[test]
+    fn test_journal_chain_id() {
+        let journal = r#"{"chainId":10,"type":"DEPLOYMENT_INITIALIZE"}"#;
+        assert_eq!(journal_chain_id(journal), Some(10));
+        assert_eq!(journal_chain_id("not json"), None);
+    }
+}
@@ -0,0 +1,114 @@
+use alloy::{
+    primitives::{Address, Bytes, Selector, keccak256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol_types::SolValue,
+};
+
+/// A facet of an EIP-2535 diamond and the selectors routed to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub address: Address,
+    pub selectors: Vec<Selector>,
+}
+
+/// Read a diamond's facets through the DiamondLoupe interface.
+///
+/// Tries `facets()` first, then `facetAddresses()` with `facetFunctionSelectors(address)`.
+/// Returns `None` if the contract doesn't implement either.
+pub async fn detect_diamond<P: Provider>(provider: &P, address: Address) -> Option<Vec<Facet>> {
+    if let Some(output) = loupe_call(provider, address, "facets()", &[]).await
+        && let Some(facets) = decode_facets(&output)
+    {
+        return Some(facets);
+    }
+
+    let output = loupe_call(provider, address, "facetAddresses()", &[]).await?;
+    let facet_addresses = Vec::<Address>::abi_decode(&output).ok()?;
+    if facet_addresses.is_empty() {
+        return None;
+    }
+
+    let mut facets = Vec::with_capacity(facet_addresses.len());
+    for facet in facet_addresses {
+        let output = loupe_call(
+            provider,
+            address,
+            "facetFunctionSelectors(address)",
+            &facet.abi_encode(),
+        )
+        .await?;
+        facets.push(Facet {
+            address: facet,
+            selectors: Vec::<Selector>::abi_decode(&output).ok()?,
+        });
+    }
+    Some(facets)
+}
+
+/// Call a loupe function, treating reverts and errors as "not a diamond"
+async fn loupe_call<P: Provider>(
+    provider: &P,
+    address: Address,
+    signature: &str,
+    args: &[u8],
+) -> Option<Bytes> {
+    let mut input = keccak256(signature)[..4].to_vec();
+    input.extend_from_slice(args);
+    let tx = TransactionRequest::default()
+        .to(address)
+        .input(input.into());
+    provider.call(tx).await.ok()
+}
+
+/// Decode the `(address facetAddress, bytes4[] functionSelectors)[]` returned by `facets()`
+fn decode_facets(data: &[u8]) -> Option<Vec<Facet>> {
+    let facets = Vec::<(Address, Vec<Selector>)>::abi_decode(data).ok()?;
+    if facets.is_empty() {
+        return None;
+    }
+    Some(
+        facets
+            .into_iter()
+            .map(|(address, selectors)| Facet { address, selectors })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_facets() {
+        let cut: Address = "0x5FbDB2315678afecb367f032d93F642f64180aa3"
+            .parse()
+            .unwrap();
+        let loupe: Address = "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512"
+            .parse()
+            .unwrap();
+        let data = vec![
+            (cut, vec![Selector::from([0x1f, 0x93, 0x1c, 0x1c])]),
+            (
+                loupe,
+                vec![
+                    Selector::from([0x7a, 0x0e, 0xd6, 0x27]),
+                    Selector::from([0x52, 0xef, 0x6b, 0x2c]),
+                ],
+            ),
+        ]
+        .abi_encode();
+
+        let facets = decode_facets(&data).unwrap();
+        assert_eq!(facets.len(), 2);
+        assert_eq!(facets[0].address, cut);
+        assert_eq!(facets[1].selectors.len(), 2);
+        assert_eq!(facets[1].selectors[0].to_string(), "0x7a0ed627");
+
+        assert_eq!(
+            decode_facets(&Vec::<(Address, Vec<Selector>)>::new().abi_encode()),
+            None
+        );
+        assert_eq!(decode_facets(&[0x00, 0x01]), None);
+    }
+}
@@ -1,7 +1,14 @@
 mod abi;
 mod caller;
+mod codec;
 mod deployment;
+mod diamond;
+mod proxy;
 
-pub use abi::ContractFunction;
-pub use caller::{CallResult, ContractCaller};
+pub use abi::{
+    ContractEvent, ContractFunction, FunctionParam, apply_natspec, encode_param_type,
+    function_matches, function_selector, function_signature, parse_abi,
+};
+pub use caller::{CallResult, ContractCaller, LogEntry, LogQuery, TxPreview, TxReceipt};
+pub use codec::{encode_call_data, format_eth_value, parse_eth_value};
 pub use deployment::{DeploymentManager, chain_id_to_network};
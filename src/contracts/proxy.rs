@@ -0,0 +1,138 @@
+use alloy::{
+    primitives::{Address, B256, U256, b256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+};
+use eyre::{Result, WrapErr};
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+const IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+/// `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`
+const ADMIN_SLOT: B256 = b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+/// `bytes32(uint256(keccak256("eip1967.proxy.beacon")) - 1)`
+const BEACON_SLOT: B256 = b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+/// Selector of the beacon's `implementation()`
+const IMPLEMENTATION_SELECTOR: [u8; 4] = [0x5c, 0x60, 0xda, 0x1b];
+
+/// Kind of EIP-1967 proxy, told apart by which slots are set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyKind {
+    /// Implementation and admin slots set
+    Transparent,
+    /// Implementation slot set without an admin; upgrades go through the implementation
+    Uups,
+    /// Beacon slot set; the implementation comes from the beacon
+    Beacon,
+}
+
+impl std::fmt::Display for ProxyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProxyKind::Transparent => write!(f, "ERC-1967 Transparent"),
+            ProxyKind::Uups => write!(f, "ERC-1967 UUPS"),
+            ProxyKind::Beacon => write!(f, "ERC-1967 Beacon"),
+        }
+    }
+}
+
+/// Proxy details read from a contract's EIP-1967 storage slots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyInfo {
+    pub kind: ProxyKind,
+    pub implementation: Address,
+    pub admin: Option<Address>,
+    pub beacon: Option<Address>,
+    /// Deployment or artifact the implementation was matched to
+    pub implementation_name: Option<String>,
+}
+
+/// Read a contract's EIP-1967 slots. Returns `None` if it is not a proxy.
+pub async fn detect_proxy<P: Provider>(
+    provider: &P,
+    address: Address,
+) -> Result<Option<ProxyInfo>> {
+    let read_slot = |slot: B256| async move {
+        provider
+            .get_storage_at(address, slot.into())
+            .await
+            .map(slot_address)
+            .wrap_err("Failed to read storage slot")
+    };
+
+    let admin = read_slot(ADMIN_SLOT).await?;
+
+    if let Some(implementation) = read_slot(IMPLEMENTATION_SLOT).await? {
+        let kind = if admin.is_some() {
+            ProxyKind::Transparent
+        } else {
+            ProxyKind::Uups
+        };
+        return Ok(Some(ProxyInfo {
+            kind,
+            implementation,
+            admin,
+            beacon: None,
+            implementation_name: None,
+        }));
+    }
+
+    let Some(beacon) = read_slot(BEACON_SLOT).await? else {
+        return Ok(None);
+    };
+    let tx = TransactionRequest::default()
+        .to(beacon)
+        .input(IMPLEMENTATION_SELECTOR.to_vec().into());
+    let output = provider
+        .call(tx)
+        .await
+        .wrap_err("Failed to call implementation() on beacon")?;
+    let implementation = output
+        .get(..32)
+        .and_then(|word| slot_address(U256::from_be_slice(word)))
+        .ok_or_else(|| eyre::eyre!("Beacon {} returned no implementation", beacon))?;
+
+    Ok(Some(ProxyInfo {
+        kind: ProxyKind::Beacon,
+        implementation,
+        admin,
+        beacon: Some(beacon),
+        implementation_name: None,
+    }))
+}
+
+/// Address stored in the low 20 bytes of a slot, or `None` if the slot is empty
+fn slot_address(value: U256) -> Option<Address> {
+    let address = Address::from_word(value.into());
+    (!address.is_zero()).then_some(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::keccak256;
+
+    #[test]
+    fn test_slot_constants() {
+        for (slot, label) in [
+            (IMPLEMENTATION_SLOT, "eip1967.proxy.implementation"),
+            (ADMIN_SLOT, "eip1967.proxy.admin"),
+            (BEACON_SLOT, "eip1967.proxy.beacon"),
+        ] {
+            let expected = U256::from_be_bytes(keccak256(label).0) - U256::from(1);
+            assert_eq!(U256::from_be_bytes(slot.0), expected, "{}", label);
+        }
+    }
+
+    #[test]
+    fn test_slot_address() {
+        assert_eq!(slot_address(U256::ZERO), None);
+
+        let address: Address = "0x5FbDB2315678afecb367f032d93F642f64180aa3"
+            .parse()
+            .unwrap();
+        let word = U256::from_be_slice(address.as_slice());
+        assert_eq!(slot_address(word), Some(address));
+    }
+}
@@ -26,6 +26,14 @@ struct Cli {
     #[arg(long, value_parser = ["foundry", "hardhat"])]
     project_type: Option<String>,
 
+    /// Foundry profile to use (defaults to $FOUNDRY_PROFILE, then "default")
+    #[arg(long)]
+    profile: Option<String>,
+
+    /// Find every Foundry/Hardhat project under the path (monorepos)
+    #[arg(long)]
+    workspace: bool,
+
     /// Force re-run setup wizard
     #[arg(long)]
     setup: bool,
@@ -72,15 +80,19 @@ async fn main() -> Result<()> {
         config = AppConfig::load()?;
     }
 
-    // Detect project type
-    let project = match cli.project_type.as_deref() {
-        Some("foundry") => project::Project::new_foundry(&project_path)?,
-        Some("hardhat") => project::Project::new_hardhat(&project_path)?,
-        _ => project::detect(&project_path)?,
+    // Detect project type; a root without a config of its own is searched as a workspace
+    let profile = cli.profile.as_deref();
+    let projects = match cli.project_type.as_deref() {
+        Some("foundry") => vec![project::Project::new_foundry(&project_path, profile)?],
+        Some("hardhat") => vec![project::Project::new_hardhat(&project_path)?],
+        _ if cli.workspace || project::is_valid_project(&project_path).is_none() => {
+            project::discover(&project_path, profile)?
+        }
+        _ => vec![project::detect(&project_path, profile)?],
     };
 
     // Run the TUI application
-    app::run(project).await
+    app::run(projects).await
 }
 
 fn test_keychain() -> Result<()> {
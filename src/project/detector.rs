@@ -1,11 +1,14 @@
+use std::fs;
 use std::path::Path;
 
-use eyre::{Result, eyre};
+use eyre::{Result, WrapErr, eyre};
 
 use super::{Project, ProjectType, foundry, hardhat};
 
-/// Detect the project type based on configuration files present in the directory
-pub fn detect(path: &Path) -> Result<Project> {
+/// Detect the project type based on configuration files present in the directory.
+///
+/// `profile` selects the foundry.toml profile and is ignored for Hardhat projects.
+pub fn detect(path: &Path, profile: Option<&str>) -> Result<Project> {
     let foundry_config = path.join("foundry.toml");
     let hardhat_config_js = path.join("hardhat.config.js");
     let hardhat_config_ts = path.join("hardhat.config.ts");
@@ -13,7 +16,7 @@ pub fn detect(path: &Path) -> Result<Project> {
     // Check for Foundry first (foundry.toml)
     if foundry_config.exists() {
         tracing::info!("Detected Foundry project at {:?}", path);
-        return foundry::load_project(path);
+        return foundry::load_project(path, profile);
     }
 
     // Check for Hardhat (hardhat.config.js or hardhat.config.ts)
@@ -29,8 +32,48 @@ pub fn detect(path: &Path) -> Result<Project> {
     ))
 }
 
+/// Dependency directories, which hold other projects' configs but never ours
+const SKIPPED_DIRS: [&str; 2] = ["lib", "node_modules"];
+
+/// Find every Foundry and Hardhat project under `root`, including `root` itself.
+///
+/// Dependency and hidden directories are not searched. Projects are sorted by path.
+pub fn discover(root: &Path, profile: Option<&str>) -> Result<Vec<Project>> {
+    let mut projects = Vec::new();
+    walk(root, profile, &mut projects)?;
+
+    if projects.is_empty() {
+        return Err(eyre!(
+            "No Foundry or Hardhat projects found under {:?}\n\
+             Expected: foundry.toml, hardhat.config.js, or hardhat.config.ts",
+            root
+        ));
+    }
+    projects.sort_by(|a, b| a.root.cmp(&b.root));
+    tracing::info!("Found {} projects under {:?}", projects.len(), root);
+    Ok(projects)
+}
+
+fn walk(dir: &Path, profile: Option<&str>, projects: &mut Vec<Project>) -> Result<()> {
+    if is_valid_project(dir).is_some() {
+        projects.push(detect(dir, profile).wrap_err_with(|| format!("In {:?}", dir))?);
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if path.is_dir() && !file_name.starts_with('.') && !SKIPPED_DIRS.contains(&&*file_name) {
+            walk(&path, profile, projects)?;
+        }
+    }
+    Ok(())
+}
+
 /// Check if a path contains a valid project
-#[allow(dead_code)]
 pub fn is_valid_project(path: &Path) -> Option<ProjectType> {
     if path.join("foundry.toml").exists() {
         Some(ProjectType::Foundry)
@@ -40,3 +83,31 @@ pub fn is_valid_project(path: &Path) -> Option<ProjectType> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_skips_dependencies() {
+        let root = std::env::temp_dir().join(format!("runic-workspace-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in [
+            "contracts/core",
+            "contracts/periphery",
+            "contracts/core/lib/forge-std",
+            "node_modules/some-package",
+            ".git/modules/x",
+        ] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            fs::write(root.join(dir).join("foundry.toml"), "[profile.default]\n").unwrap();
+        }
+
+        let projects = discover(&root, None).unwrap();
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["core", "periphery"]);
+
+        assert!(discover(&root.join("node_modules/missing"), None).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
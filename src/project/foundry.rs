@@ -12,6 +12,15 @@ use super::{Project, ProjectConfig, ProjectType};
 pub struct FoundryConfig {
     #[serde(default)]
     pub profile: HashMap<String, ProfileConfig>,
+    /// Top-level `[rpc_endpoints]`, shared by all profiles
+    #[serde(default)]
+    pub rpc_endpoints: HashMap<String, RpcEndpoint>,
+    /// Top-level `[etherscan]`, shared by all profiles
+    #[serde(default)]
+    pub etherscan: HashMap<String, EtherscanConfig>,
+    /// Profile merged over `default`, from `--profile` or `FOUNDRY_PROFILE`
+    #[serde(skip)]
+    selected_profile: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -27,16 +36,63 @@ pub struct ProfileConfig {
     pub evm_version: Option<String>,
     pub remappings: Option<Vec<String>>,
     #[serde(default)]
-    pub rpc_endpoints: HashMap<String, String>,
+    pub rpc_endpoints: HashMap<String, RpcEndpoint>,
     #[serde(default)]
     pub etherscan: HashMap<String, EtherscanConfig>,
 }
 
+impl ProfileConfig {
+    /// Overlay `other` on this profile: its settings win, tables are merged key by key
+    fn merge(mut self, other: &ProfileConfig) -> Self {
+        self.src = other.src.clone().or(self.src);
+        self.out = other.out.clone().or(self.out);
+        self.libs = other.libs.clone().or(self.libs);
+        self.script = other.script.clone().or(self.script);
+        self.broadcast = other.broadcast.clone().or(self.broadcast);
+        self.solc = other.solc.clone().or(self.solc);
+        self.optimizer = other.optimizer.or(self.optimizer);
+        self.optimizer_runs = other.optimizer_runs.or(self.optimizer_runs);
+        self.evm_version = other.evm_version.clone().or(self.evm_version);
+        self.remappings = other.remappings.clone().or(self.remappings);
+        self.rpc_endpoints.extend(other.rpc_endpoints.clone());
+        self.etherscan.extend(other.etherscan.clone());
+        self
+    }
+}
+
+/// An `rpc_endpoints` entry: a URL, or a table with the URL under `endpoint`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RpcEndpoint {
+    Url(String),
+    Detailed {
+        #[serde(alias = "url")]
+        endpoint: String,
+    },
+}
+
+impl RpcEndpoint {
+    /// The URL as written, possibly with `${VAR}` references
+    pub fn url(&self) -> &str {
+        match self {
+            RpcEndpoint::Url(url) | RpcEndpoint::Detailed { endpoint: url } => url,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EtherscanConfig {
     pub key: Option<String>,
     pub url: Option<String>,
-    pub chain: Option<u64>,
+    pub chain: Option<ChainRef>,
+}
+
+/// A chain given by id or by name (e.g. `chain = "mainnet"`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChainRef {
+    Id(u64),
+    Name(String),
 }
 
 impl FoundryConfig {
@@ -59,28 +115,94 @@ impl FoundryConfig {
         self.profile.get("default").unwrap_or(&DEFAULT)
     }
 
-    pub fn src_dir(&self) -> &str {
-        self.default_profile().src.as_deref().unwrap_or("src")
+    /// Name of the selected profile
+    pub fn profile_name(&self) -> &str {
+        self.selected_profile.as_deref().unwrap_or("default")
+    }
+
+    /// Profiles defined in foundry.toml, `default` first
+    pub fn profile_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .profile
+            .keys()
+            .filter(|name| *name != "default")
+            .cloned()
+            .collect();
+        names.sort();
+        names.insert(0, "default".to_string());
+        names
     }
 
-    pub fn out_dir(&self) -> &str {
-        self.default_profile().out.as_deref().unwrap_or("out")
+    /// Select the profile merged over `default`, as forge does for `FOUNDRY_PROFILE`
+    pub fn select_profile(&mut self, name: &str) -> Result<()> {
+        if name != "default" && !self.profile.contains_key(name) {
+            return Err(eyre!(
+                "Profile '{}' not found in foundry.toml (available: {})",
+                name,
+                self.profile_names().join(", ")
+            ));
+        }
+        self.selected_profile = (name != "default").then(|| name.to_string());
+        Ok(())
     }
 
-    pub fn script_dir(&self) -> &str {
-        self.default_profile().script.as_deref().unwrap_or("script")
+    /// The default profile with the selected one merged over it
+    pub fn active_profile(&self) -> ProfileConfig {
+        let default = self.default_profile().clone();
+        match self
+            .selected_profile
+            .as_ref()
+            .and_then(|name| self.profile.get(name))
+        {
+            Some(selected) => default.merge(selected),
+            None => default,
+        }
     }
 
-    pub fn broadcast_dir(&self) -> &str {
-        self.default_profile()
+    pub fn src_dir(&self) -> String {
+        self.active_profile()
+            .src
+            .unwrap_or_else(|| "src".to_string())
+    }
+
+    pub fn out_dir(&self) -> String {
+        self.active_profile()
+            .out
+            .unwrap_or_else(|| "out".to_string())
+    }
+
+    pub fn script_dir(&self) -> String {
+        self.active_profile()
+            .script
+            .unwrap_or_else(|| "script".to_string())
+    }
+
+    pub fn broadcast_dir(&self) -> String {
+        self.active_profile()
             .broadcast
-            .as_deref()
-            .unwrap_or("broadcast")
+            .unwrap_or_else(|| "broadcast".to_string())
+    }
+
+    /// RPC endpoints from the top-level table, overridden by the active profile's
+    pub fn rpc_endpoints(&self) -> HashMap<String, RpcEndpoint> {
+        let mut endpoints = self.rpc_endpoints.clone();
+        endpoints.extend(self.active_profile().rpc_endpoints);
+        endpoints
+    }
+
+    /// Etherscan settings from the top-level table, overridden by the active profile's
+    pub fn etherscan(&self) -> HashMap<String, EtherscanConfig> {
+        let mut etherscan = self.etherscan.clone();
+        etherscan.extend(self.active_profile().etherscan);
+        etherscan
     }
 }
 
-/// Load a Foundry project from the given path
-pub fn load_project(path: &Path) -> Result<Project> {
+/// Load a Foundry project from the given path.
+///
+/// `profile` overrides the `FOUNDRY_PROFILE` environment variable; without either the
+/// `default` profile is used.
+pub fn load_project(path: &Path, profile: Option<&str>) -> Result<Project> {
     let config_path = path.join("foundry.toml");
 
     if !config_path.exists() {
@@ -90,9 +212,17 @@ pub fn load_project(path: &Path) -> Result<Project> {
     let config_content = fs::read_to_string(&config_path)
         .wrap_err_with(|| format!("Failed to read {:?}", config_path))?;
 
-    let config: FoundryConfig =
+    let mut config: FoundryConfig =
         toml::from_str(&config_content).wrap_err("Failed to parse foundry.toml")?;
 
+    let profile = profile
+        .map(str::to_string)
+        .or_else(|| std::env::var("FOUNDRY_PROFILE").ok())
+        .filter(|name| !name.is_empty());
+    if let Some(profile) = profile {
+        config.select_profile(&profile)?;
+    }
+
     // Extract project name from directory name
     let name = path
         .file_name()
@@ -147,4 +277,84 @@ sepolia = "https://eth-sepolia.g.alchemy.com/v2/xxx"
                 .contains_key("mainnet")
         );
     }
+
+    #[test]
+    fn test_parse_top_level_rpc_and_etherscan() {
+        let content = r#"
+[profile.default]
+src = "src"
+
+[profile.default.rpc_endpoints]
+sepolia = "https://override.example"
+
+[rpc_endpoints]
+mainnet = "${MAINNET_RPC_URL}"
+sepolia = "https://rpc.sepolia.org"
+base = { endpoint = "https://mainnet.base.org", retries = 3 }
+
+[etherscan]
+mainnet = { key = "${ETHERSCAN_API_KEY}" }
+base = { key = "${BASESCAN_API_KEY}", chain = "base", url = "https://api.basescan.org/api" }
+unichain = { key = "abc", chain = 130 }
+"#;
+        let config: FoundryConfig = toml::from_str(content).unwrap();
+        let endpoints = config.rpc_endpoints();
+        assert_eq!(endpoints["mainnet"].url(), "${MAINNET_RPC_URL}");
+        assert_eq!(endpoints["sepolia"].url(), "https://override.example");
+        assert_eq!(endpoints["base"].url(), "https://mainnet.base.org");
+
+        let etherscan = config.etherscan();
+        assert_eq!(
+            etherscan["base"].chain,
+            Some(ChainRef::Name("base".to_string()))
+        );
+        assert_eq!(etherscan["unichain"].chain, Some(ChainRef::Id(130)));
+    }
+
+    #[test]
+    fn test_select_profile_merges_over_default() {
+        let content = r#"
+[profile.default]
+src = "contracts"
+out = "out"
+optimizer = false
+
+[profile.default.rpc_endpoints]
+mainnet = "https://default.example"
+
+[profile.ci]
+out = "out-ci"
+broadcast = "broadcast-ci"
+optimizer = true
+
+[profile.ci.rpc_endpoints]
+sepolia = "https://ci.example"
+
+[profile.optimized]
+optimizer_runs = 1000000
+"#;
+        let mut config: FoundryConfig = toml::from_str(content).unwrap();
+        assert_eq!(config.profile_name(), "default");
+        assert_eq!(config.profile_names(), ["default", "ci", "optimized"]);
+
+        config.select_profile("ci").unwrap();
+        assert_eq!(config.profile_name(), "ci");
+        assert_eq!(config.src_dir(), "contracts");
+        assert_eq!(config.out_dir(), "out-ci");
+        assert_eq!(config.broadcast_dir(), "broadcast-ci");
+        assert_eq!(config.active_profile().optimizer, Some(true));
+        let endpoints = config.rpc_endpoints();
+        assert!(endpoints.contains_key("mainnet") && endpoints.contains_key("sepolia"));
+
+        let err = config.select_profile("missing").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("available: default, ci, optimized")
+        );
+        assert_eq!(config.profile_name(), "ci");
+
+        config.select_profile("default").unwrap();
+        assert_eq!(config.out_dir(), "out");
+        assert!(!config.rpc_endpoints().contains_key("sepolia"));
+    }
 }
@@ -1,14 +1,590 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
 use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
-use eyre::{Result, eyre};
+use eyre::{Result, WrapErr, eyre};
 use serde::{Deserialize, Serialize};
 
 use super::{Project, ProjectConfig, ProjectType};
 
-/// Hardhat configuration (stub for MVP - Foundry-first approach)
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// How long `npx hardhat` gets to print the resolved config before falling back
+const HELPER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// What the helper puts in place of key-like URL parts it can't trace to an env var
+const REDACTED_URL_PART: &str = "<redacted>";
+
+/// Prefix of the helper's output line, so plugin logging doesn't break parsing
+const HELPER_MARKER: &str = "RUNIC_HARDHAT_CONFIG ";
+
+/// Prints the resolved config as JSON. Secrets are never printed: accounts and
+/// etherscan keys are reported as the name of the environment variable holding them,
+/// and environment values in network URLs are put back as `${VAR}`; other key-like
+/// parts of a URL's path or query (e.g. from `vars`) become `<redacted>`.
+const HELPER_JS: &str = r#"
+const path = require("path");
+const config = hre.config;
+const root = config.paths.root;
+const envRef = (value) => {
+  if (typeof value !== "string" || value === "") return null;
+  return Object.keys(process.env).find((k) => process.env[k] === value) ?? null;
+};
+const envValues = Object.entries(process.env)
+  .filter(([, value]) => typeof value === "string" && value.length >= 8)
+  .sort(([, a], [, b]) => b.length - a.length);
+const unresolveUrl = (url) => {
+  if (typeof url !== "string") return null;
+  for (const [name, value] of envValues) url = url.split(value).join("${" + name + "}");
+  const start = url.indexOf("://");
+  const pathStart = url.indexOf("/", start < 0 ? 0 : start + 3);
+  if (pathStart < 0) return url;
+  const rest = url
+    .slice(pathStart)
+    .replace(/\$\{[^}]*\}|[A-Za-z0-9_-]{16,}/g, (part) => (part.startsWith("${") ? part : "<redacted>"));
+  return url.slice(0, pathStart) + rest;
+};
+const networks = {};
+for (const [name, network] of Object.entries(config.networks)) {
+  let accounts = [];
+  if (Array.isArray(network.accounts)) {
+    accounts = network.accounts.map((a) => envRef(typeof a === "string" ? a : a.privateKey));
+  } else if (network.accounts && typeof network.accounts === "object") {
+    accounts = [null];
+  }
+  networks[name] = { url: unresolveUrl(network.url), chainId: network.chainId ?? null, accounts };
+}
+const etherscan = { apiKeys: {}, customChains: [] };
+if (config.etherscan) {
+  const apiKey = config.etherscan.apiKey;
+  if (typeof apiKey === "string") {
+    if (apiKey !== "") etherscan.apiKeys["*"] = envRef(apiKey);
+  } else if (apiKey) {
+    for (const [network, key] of Object.entries(apiKey)) etherscan.apiKeys[network] = envRef(key);
+  }
+  etherscan.customChains = (config.etherscan.customChains ?? []).map((c) => ({
+    network: c.network,
+    chainId: c.chainId,
+    apiUrl: c.urls?.apiURL ?? null,
+    browserUrl: c.urls?.browserURL ?? null,
+  }));
+}
+const paths = {
+  sources: path.relative(root, config.paths.sources),
+  artifacts: path.relative(root, config.paths.artifacts),
+  scripts: null,
+};
+console.log("RUNIC_HARDHAT_CONFIG " + JSON.stringify({ paths, networks, etherscan }));
+"#;
+
+/// Hardhat configuration extracted from hardhat.config.js/ts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HardhatConfig {
-    // TODO: Parse hardhat.config.js/ts when Hardhat support is added
+    #[serde(default)]
+    pub paths: HardhatPaths,
+    #[serde(default)]
+    pub networks: BTreeMap<String, HardhatNetwork>,
+    #[serde(default)]
+    pub etherscan: HardhatEtherscan,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardhatPaths {
+    pub sources: Option<String>,
+    pub artifacts: Option<String>,
+    pub scripts: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardhatNetwork {
+    /// RPC URL; may contain `${VAR}` references to environment variables
+    pub url: Option<String>,
+    pub chain_id: Option<u64>,
+    /// Environment variable holding each account's key, or `None` for
+    /// literals, mnemonics and Hardhat configuration variables
+    #[serde(default)]
+    pub accounts: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardhatEtherscan {
+    /// Environment variable holding the API key, per network (`*` for a single key)
+    #[serde(default)]
+    pub api_keys: BTreeMap<String, Option<String>>,
+    #[serde(default)]
+    pub custom_chains: Vec<HardhatCustomChain>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardhatCustomChain {
+    pub network: String,
+    pub chain_id: u64,
+    pub api_url: Option<String>,
+    pub browser_url: Option<String>,
+}
+
+impl HardhatConfig {
+    /// Read the config file without running it; `evaluate` resolves it fully but is slow
+    pub fn load(config_path: &Path) -> Self {
+        fs::read_to_string(config_path)
+            .map(|source| parse_static(&source))
+            .unwrap_or_default()
+    }
+
+    /// Evaluate the config with the project's own Hardhat, so plugins and TypeScript
+    /// configs are honoured. Can take many seconds; run it off the UI thread.
+    pub fn evaluate(root: &Path) -> Result<Self> {
+        load_with_hardhat(root)
+    }
+
+    pub fn src_dir(&self) -> &str {
+        self.paths.sources.as_deref().unwrap_or("contracts")
+    }
+
+    pub fn out_dir(&self) -> &str {
+        self.paths.artifacts.as_deref().unwrap_or("artifacts")
+    }
+
+    pub fn script_dir(&self) -> &str {
+        self.paths.scripts.as_deref().unwrap_or("scripts")
+    }
+}
+
+impl HardhatNetwork {
+    /// URL with `${VAR}` references replaced from the environment; `None` if part of it
+    /// was redacted when the config was evaluated
+    pub fn resolved_url(&self) -> Option<String> {
+        self.url
+            .as_deref()
+            .filter(|url| !url.contains(REDACTED_URL_PART))
+            .and_then(|url| crate::config::resolve_env_vars(url).ok())
+    }
+}
+
+/// Run the JSON helper through `npx hardhat run`, so plugins and TypeScript configs are honoured
+fn load_with_hardhat(root: &Path) -> Result<HardhatConfig> {
+    // Never let npx download Hardhat: only run one the project has installed
+    if !hardhat_installed(root) {
+        return Err(eyre!("Hardhat is not installed in node_modules"));
+    }
+
+    // Created with O_EXCL and mode 0600 under a random name, and removed on drop
+    let mut helper = tempfile::Builder::new()
+        .prefix("runic-hardhat-config-")
+        .suffix(".js")
+        .tempfile()
+        .wrap_err("Failed to create Hardhat helper")?;
+    helper
+        .write_all(HELPER_JS.as_bytes())
+        .and_then(|_| helper.flush())
+        .wrap_err("Failed to write Hardhat helper")?;
+
+    let output = run_with_timeout(
+        Command::new("npx")
+            .args(["--no-install", "hardhat", "run", "--no-compile"])
+            .arg(helper.path())
+            .current_dir(root),
+        HELPER_TIMEOUT,
+    );
+    drop(helper);
+
+    let line = output?
+        .lines()
+        .find_map(|line| line.strip_prefix(HELPER_MARKER))
+        .map(str::to_string)
+        .ok_or_else(|| eyre!("Hardhat helper printed no config"))?;
+    serde_json::from_str(&line).wrap_err("Failed to parse Hardhat helper output")
+}
+
+/// Whether `node_modules/.bin/hardhat` exists in the project or a parent (hoisted installs)
+fn hardhat_installed(root: &Path) -> bool {
+    root.ancestors().any(|dir| {
+        let bin = dir.join("node_modules").join(".bin");
+        bin.join("hardhat").exists() || bin.join("hardhat.cmd").exists()
+    })
+}
+
+/// Run a command and return its stdout, killing it if it takes longer than `timeout`
+fn run_with_timeout(cmd: &mut Command, timeout: Duration) -> Result<String> {
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .wrap_err("Failed to spawn command")?;
+
+    // Drain stdout on a thread so a full pipe can't block the child
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = std::thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(eyre!("Timed out after {}s", timeout.as_secs()));
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+
+    let output = reader.join().unwrap_or_default();
+    if !status.success() {
+        return Err(eyre!("Exited with {}", status));
+    }
+    Ok(output)
+}
+
+/// Extract paths, networks and etherscan settings from the config source without running it.
+///
+/// Understands object literals with string, number, `process.env.X`, template literal and
+/// `vars.get("X")` values, which covers the configs Hardhat's templates generate.
+fn parse_static(source: &str) -> HardhatConfig {
+    let source = strip_comments(source);
+    let mut config = HardhatConfig::default();
+
+    if let Some(paths) = object_value(&source, "paths") {
+        for (key, value) in entries(paths) {
+            let value = string_value(value);
+            match key.as_str() {
+                "sources" => config.paths.sources = value,
+                "artifacts" => config.paths.artifacts = value,
+                "scripts" => config.paths.scripts = value,
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(networks) = object_value(&source, "networks") {
+        for (name, body) in entries(networks) {
+            let Some(body) = braced(body, '{', '}') else {
+                continue;
+            };
+            let mut network = HardhatNetwork::default();
+            for (key, value) in entries(body) {
+                match key.as_str() {
+                    "url" => network.url = string_value(value),
+                    "chainId" => network.chain_id = value.replace('_', "").parse().ok(),
+                    "accounts" => network.accounts = account_refs(value),
+                    _ => {}
+                }
+            }
+            config.networks.insert(name, network);
+        }
+    }
+
+    if let Some(etherscan) = object_value(&source, "etherscan") {
+        for (key, value) in entries(etherscan) {
+            match key.as_str() {
+                "apiKey" => match braced(value, '{', '}') {
+                    Some(keys) => {
+                        for (network, key) in entries(keys) {
+                            config.etherscan.api_keys.insert(network, env_ref(key));
+                        }
+                    }
+                    None => {
+                        config
+                            .etherscan
+                            .api_keys
+                            .insert("*".to_string(), env_ref(value));
+                    }
+                },
+                "customChains" => {
+                    let Some(chains) = braced(value, '[', ']') else {
+                        continue;
+                    };
+                    for chain in split_top_level(chains, ',') {
+                        if let Some(chain) = braced(chain, '{', '}')
+                            && let Some(chain) = custom_chain(chain)
+                        {
+                            config.etherscan.custom_chains.push(chain);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    config
+}
+
+fn custom_chain(body: &str) -> Option<HardhatCustomChain> {
+    let mut chain = HardhatCustomChain::default();
+    for (key, value) in entries(body) {
+        match key.as_str() {
+            "network" => chain.network = string_value(value)?,
+            "chainId" => chain.chain_id = value.replace('_', "").parse().ok()?,
+            "urls" => {
+                for (key, value) in entries(braced(value, '{', '}')?) {
+                    match key.as_str() {
+                        "apiURL" => chain.api_url = string_value(value),
+                        "browserURL" => chain.browser_url = string_value(value),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    (!chain.network.is_empty()).then_some(chain)
+}
+
+/// Remove `//` and `/* */` comments, leaving string contents (e.g. `https://`) alone
+fn strip_comments(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            result.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        result.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            ('"' | '\'' | '`', _) => {
+                quote = Some(c);
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Body of the object literal assigned to the first `key:` in `source`
+fn object_value<'a>(source: &'a str, key: &str) -> Option<&'a str> {
+    let mut offset = 0;
+    while let Some(found) = source[offset..].find(key) {
+        let start = offset + found;
+        offset = start + key.len();
+
+        let preceded_by_ident = source[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.');
+        if preceded_by_ident {
+            continue;
+        }
+        let rest = source[offset..].trim_start();
+        if let Some(value) = rest.strip_prefix(':')
+            && let Some(body) = braced(value, '{', '}')
+        {
+            return Some(body);
+        }
+    }
+    None
+}
+
+/// Contents between `open` at the start of `value` and its matching `close`
+fn braced(value: &str, open: char, close: char) -> Option<&str> {
+    let value = value.trim_start();
+    if !value.starts_with(open) {
+        return None;
+    }
+    let end = matching_close(value)?;
+    value[end..].starts_with(close).then(|| &value[1..end])
+}
+
+/// Byte index of the bracket closing the one `value` starts with
+fn matching_close(value: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split at `separator` outside of brackets and strings
+fn split_top_level(body: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&body[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&body[start..]);
+    parts.retain(|p| !p.trim().is_empty());
+    parts
+}
+
+/// `key: value` pairs of an object literal body; spreads and shorthand properties are skipped
+fn entries(body: &str) -> Vec<(String, &str)> {
+    split_top_level(body, ',')
+        .into_iter()
+        .filter_map(|entry| {
+            let (key, value) = entry.split_once(':')?;
+            let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+            Some((key.to_string(), value.trim()))
+        })
+        .collect()
+}
+
+/// A string value, with environment variables written as `${VAR}`
+fn string_value(value: &str) -> Option<String> {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote)
+            && let Some(end) = inner.find(quote)
+        {
+            return Some(inner[..end].to_string());
+        }
+    }
+    let Some(template) = value.strip_prefix('`') else {
+        return env_ref(value).map(|name| format!("${{{}}}", name));
+    };
+    let template = &template[..template.find('`')?];
+
+    // Rewrite `${process.env.X}` placeholders as `${X}`
+    let mut result = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        let len = rest[start..].find('}')?;
+        result.push_str(&rest[..start]);
+        let placeholder = &rest[start + 2..start + len];
+        let name = env_ref(placeholder).unwrap_or_else(|| placeholder.trim().to_string());
+        result.push_str(&format!("${{{}}}", name));
+        rest = &rest[start + len + 1..];
+    }
+    result.push_str(rest);
+    Some(result)
+}
+
+/// Environment variable an expression reads first: `process.env.X`, `process.env["X"]`,
+/// or `vars.get("X")`, which Hardhat also reads from `HARDHAT_VAR_X`
+fn env_ref(expression: &str) -> Option<String> {
+    let (name, _) = env_refs(expression).into_iter().next()?;
+    Some(name)
+}
+
+/// Every environment variable referenced in an expression, with its byte offset
+fn env_refs(expression: &str) -> Vec<(String, usize)> {
+    let identifier = |s: &str| -> String {
+        s.chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect()
+    };
+    let quoted = |s: &str| -> Option<String> {
+        let s = s.trim_start();
+        let quote = s.chars().next().filter(|q| matches!(q, '"' | '\''))?;
+        let inner = &s[1..];
+        Some(inner[..inner.find(quote)?].to_string())
+    };
+
+    let mut refs = Vec::new();
+    for (offset, _) in expression.match_indices("process.env") {
+        let rest = &expression[offset + "process.env".len()..];
+        let name = match rest.chars().next() {
+            Some('.') => Some(identifier(&rest[1..])),
+            Some('[') => quoted(&rest[1..]),
+            _ => None,
+        };
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            refs.push((name, offset));
+        }
+    }
+    for (offset, _) in expression.match_indices("vars.get(") {
+        if let Some(name) = quoted(&expression[offset + "vars.get(".len()..]) {
+            refs.push((format!("HARDHAT_VAR_{}", name), offset));
+        }
+    }
+    refs.sort_by_key(|(_, offset)| *offset);
+    refs
+}
+
+/// Environment variables an `accounts` value reads keys from.
+///
+/// Handles the common `process.env.KEY ? [process.env.KEY] : []` guard by deduplicating.
+fn account_refs(value: &str) -> Vec<Option<String>> {
+    let mut refs: Vec<Option<String>> = Vec::new();
+    for (name, _) in env_refs(value) {
+        if !refs.contains(&Some(name.clone())) {
+            refs.push(Some(name));
+        }
+    }
+    let value = value.trim();
+    if refs.is_empty() && value != "[]" && value != "\"remote\"" && value != "'remote'" {
+        refs.push(None);
+    }
+    refs
 }
 
 /// Load a Hardhat project from the given path
@@ -16,13 +592,22 @@ pub fn load_project(path: &Path) -> Result<Project> {
     let config_js = path.join("hardhat.config.js");
     let config_ts = path.join("hardhat.config.ts");
 
-    if !config_js.exists() && !config_ts.exists() {
+    let config_path = if config_ts.exists() {
+        config_ts
+    } else if config_js.exists() {
+        config_js
+    } else {
         return Err(eyre!(
             "hardhat.config.js or hardhat.config.ts not found at {:?}",
             path
         ));
-    }
+    };
+
+    Ok(project_with_config(path, HardhatConfig::load(&config_path)))
+}
 
+/// A Hardhat project at `path` laid out according to `config`
+pub fn project_with_config(path: &Path, config: HardhatConfig) -> Project {
     // Extract project name from directory name
     let name = path
         .file_name()
@@ -30,15 +615,166 @@ pub fn load_project(path: &Path) -> Result<Project> {
         .unwrap_or("unknown")
         .to_string();
 
-    // Hardhat uses conventional directories
-    Ok(Project {
+    Project {
         project_type: ProjectType::Hardhat,
         root: path.to_path_buf(),
         name,
-        src_dir: path.join("contracts"),
-        out_dir: path.join("artifacts"),
-        script_dir: path.join("scripts"),
+        src_dir: path.join(config.src_dir()),
+        out_dir: path.join(config.out_dir()),
+        script_dir: path.join(config.script_dir()),
         broadcast_dir: path.join("deployments"),
-        config: ProjectConfig::Hardhat(HardhatConfig::default()),
-    })
+        config: ProjectConfig::Hardhat(config),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+import { HardhatUserConfig, vars } from "hardhat/config";
+import "@nomicfoundation/hardhat-toolbox";
+
+const INFURA_KEY = vars.get("INFURA_API_KEY");
+
+const config: HardhatUserConfig = {
+  solidity: { version: "0.8.24", settings: { optimizer: { enabled: true, runs: 200 } } },
+  paths: {
+    sources: "./src", // not the default
+    artifacts: './build/artifacts',
+  },
+  networks: {
+    hardhat: {},
+    sepolia: {
+      url: process.env.SEPOLIA_RPC_URL || "",
+      chainId: 11_155_111,
+      accounts: process.env.PRIVATE_KEY !== undefined ? [process.env.PRIVATE_KEY] : [],
+    },
+    /* mainnet: { url: "https://disabled" }, */
+    base: {
+      url: `https://base-mainnet.infura.io/v3/${process.env.INFURA_KEY}`,
+      accounts: [vars.get("DEPLOYER_KEY"), "0xabc"],
+    },
+    "local-node": { url: "http://127.0.0.1:8545", accounts: { mnemonic: "test test" } },
+  },
+  etherscan: {
+    apiKey: {
+      sepolia: process.env.ETHERSCAN_API_KEY,
+      base: vars.get("BASESCAN_KEY"),
+    },
+    customChains: [
+      {
+        network: "base",
+        chainId: 8453,
+        urls: { apiURL: "https://api.basescan.org/api", browserURL: "https://basescan.org" },
+      },
+    ],
+  },
+};
+
+export default config;
+"#;
+
+    #[test]
+    fn test_parse_static_config() {
+        let config = parse_static(CONFIG);
+
+        assert_eq!(config.src_dir(), "./src");
+        assert_eq!(config.out_dir(), "./build/artifacts");
+        assert_eq!(config.script_dir(), "scripts");
+
+        let names: Vec<_> = config.networks.keys().map(String::as_str).collect();
+        assert_eq!(names, ["base", "hardhat", "local-node", "sepolia"]);
+
+        let sepolia = &config.networks["sepolia"];
+        assert_eq!(sepolia.url.as_deref(), Some("${SEPOLIA_RPC_URL}"));
+        assert_eq!(sepolia.chain_id, Some(11155111));
+        assert_eq!(sepolia.accounts, vec![Some("PRIVATE_KEY".to_string())]);
+
+        let base = &config.networks["base"];
+        assert_eq!(
+            base.url.as_deref(),
+            Some("https://base-mainnet.infura.io/v3/${INFURA_KEY}")
+        );
+        assert_eq!(
+            base.accounts,
+            vec![Some("HARDHAT_VAR_DEPLOYER_KEY".to_string())]
+        );
+
+        let local = &config.networks["local-node"];
+        assert_eq!(local.url.as_deref(), Some("http://127.0.0.1:8545"));
+        assert_eq!(local.accounts, vec![None]);
+        assert!(config.networks["hardhat"].accounts.is_empty());
+
+        assert_eq!(
+            config.etherscan.api_keys["sepolia"].as_deref(),
+            Some("ETHERSCAN_API_KEY")
+        );
+        assert_eq!(
+            config.etherscan.api_keys["base"].as_deref(),
+            Some("HARDHAT_VAR_BASESCAN_KEY")
+        );
+        assert_eq!(
+            config.etherscan.custom_chains,
+            vec![HardhatCustomChain {
+                network: "base".to_string(),
+                chain_id: 8453,
+                api_url: Some("https://api.basescan.org/api".to_string()),
+                browser_url: Some("https://basescan.org".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_helper_output() {
+        let line = r#"{"paths":{"sources":"contracts","artifacts":"artifacts","scripts":null},
+            "networks":{"hardhat":{"url":null,"chainId":31337,"accounts":[null]},
+                        "sepolia":{"url":"https://rpc.sepolia.org","chainId":11155111,"accounts":["PRIVATE_KEY"]}},
+            "etherscan":{"apiKeys":{"*":"ETHERSCAN_API_KEY"},"customChains":[]}}"#;
+        let config: HardhatConfig = serde_json::from_str(line).unwrap();
+        assert_eq!(config.networks["hardhat"].chain_id, Some(31337));
+        assert_eq!(
+            config.networks["sepolia"].accounts,
+            vec![Some("PRIVATE_KEY".to_string())]
+        );
+        assert_eq!(
+            config.etherscan.api_keys["*"].as_deref(),
+            Some("ETHERSCAN_API_KEY")
+        );
+    }
+
+    #[test]
+    fn test_redacted_url_is_unusable() {
+        let network = HardhatNetwork {
+            url: Some("https://arb-sepolia.g.alchemy.com/v2/<redacted>".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(network.resolved_url(), None);
+
+        let network = HardhatNetwork {
+            url: Some("http://127.0.0.1:8545".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            network.resolved_url().as_deref(),
+            Some("http://127.0.0.1:8545")
+        );
+    }
+
+    #[test]
+    fn test_hardhat_installed() {
+        let root = std::env::temp_dir().join(format!("runic-hardhat-bin-{}", std::process::id()));
+        let package = root.join("packages").join("contracts");
+        fs::create_dir_all(&package).unwrap();
+        assert!(!hardhat_installed(&package));
+        assert!(load_with_hardhat(&package).is_err());
+
+        // Hoisted to the workspace root
+        let bin = root.join("node_modules").join(".bin");
+        fs::create_dir_all(&bin).unwrap();
+        fs::write(bin.join("hardhat"), "").unwrap();
+        assert!(hardhat_installed(&package));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
 }
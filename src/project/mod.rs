@@ -2,10 +2,11 @@ mod detector;
 mod foundry;
 mod hardhat;
 
-pub use detector::detect;
-pub use foundry::FoundryConfig;
-pub use hardhat::HardhatConfig;
+pub use detector::{detect, discover, is_valid_project};
+pub use foundry::{ChainRef, EtherscanConfig, FoundryConfig};
+pub use hardhat::{HardhatConfig, HardhatNetwork};
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use eyre::Result;
@@ -41,28 +42,63 @@ pub struct Project {
 
 #[derive(Debug, Clone)]
 pub enum ProjectConfig {
-    #[allow(dead_code)]
     Foundry(FoundryConfig),
-    #[allow(dead_code)]
     Hardhat(HardhatConfig),
 }
 
 impl Project {
-    pub fn new_foundry(path: &Path) -> Result<Self> {
-        foundry::load_project(path)
+    pub fn new_foundry(path: &Path, profile: Option<&str>) -> Result<Self> {
+        foundry::load_project(path, profile)
     }
 
     pub fn new_hardhat(path: &Path) -> Result<Self> {
         hardhat::load_project(path)
     }
 
-    #[allow(dead_code)]
     pub fn is_foundry(&self) -> bool {
         self.project_type == ProjectType::Foundry
     }
 
-    #[allow(dead_code)]
     pub fn is_hardhat(&self) -> bool {
         self.project_type == ProjectType::Hardhat
     }
+
+    /// Parsed foundry.toml (`None` for Hardhat projects)
+    pub fn foundry_config(&self) -> Option<&FoundryConfig> {
+        match &self.config {
+            ProjectConfig::Foundry(config) => Some(config),
+            ProjectConfig::Hardhat(_) => None,
+        }
+    }
+
+    /// Selected foundry.toml profile (`None` for Hardhat projects)
+    pub fn foundry_profile(&self) -> Option<&str> {
+        self.foundry_config().map(|config| config.profile_name())
+    }
+
+    /// Reload a Foundry project with another profile, re-reading foundry.toml
+    pub fn with_profile(&self, profile: &str) -> Result<Self> {
+        foundry::load_project(&self.root, Some(profile))
+    }
+
+    /// The same Hardhat project with a freshly evaluated config
+    pub fn with_hardhat_config(&self, config: HardhatConfig) -> Self {
+        hardhat::project_with_config(&self.root, config)
+    }
+
+    /// Parsed hardhat.config (`None` for Foundry projects)
+    pub fn hardhat_config(&self) -> Option<&HardhatConfig> {
+        match &self.config {
+            ProjectConfig::Hardhat(config) => Some(config),
+            ProjectConfig::Foundry(_) => None,
+        }
+    }
+
+    /// Networks defined in the Hardhat config (empty for Foundry projects)
+    pub fn hardhat_networks(&self) -> BTreeMap<String, HardhatNetwork> {
+        match &self.config {
+            ProjectConfig::Hardhat(config) => config.networks.clone(),
+            ProjectConfig::Foundry(_) => BTreeMap::new(),
+        }
+    }
 }
@@ -0,0 +1,521 @@
+//! Saved script runs under the runic data directory, as an audit trail
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use super::{Script, ScriptOutput};
+use crate::config::data_dir;
+
+/// Replaces secrets in saved runs
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Flags whose value is a secret (or, for RPC URLs, often embeds one)
+const SENSITIVE_FLAGS: [&str; 10] = [
+    "--private-key",
+    "--private-keys",
+    "--mnemonic",
+    "--mnemonics",
+    "--password",
+    "--etherscan-api-key",
+    "--verifier-api-key",
+    "--rpc-url",
+    "--fork-url",
+    "-f",
+];
+
+/// Shorter strings are too likely to appear by chance to be worth redacting
+const MIN_SECRET_LEN: usize = 8;
+
+/// Longest line-by-line diff computed exactly; larger ones show both sides whole
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// One script run, as saved to `runs/<id>.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub id: String,
+    pub script: String,
+    pub script_path: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub network: String,
+    /// Wallet name, or where the key came from
+    pub wallet: String,
+    /// OS user who started the run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Flags passed to forge or Hardhat, with secret values redacted
+    pub flags: Vec<String>,
+    /// RFC 3339 local times
+    pub started_at: String,
+    pub ended_at: String,
+    pub duration_ms: u64,
+    pub exit_code: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Why the script couldn't be run, if it couldn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl RunRecord {
+    /// Record a finished run; its output is expected to be redacted already
+    pub fn new(
+        script: &Script,
+        network: &str,
+        wallet: &str,
+        flags: &[String],
+        started: DateTime<Local>,
+        result: &Result<ScriptOutput>,
+    ) -> Self {
+        let ended = Local::now();
+        let safe_name: String = script
+            .name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        let mut record = Self {
+            id: format!("{}-{}", started.format("%Y%m%d-%H%M%S%.3f"), safe_name),
+            script: script.name.clone(),
+            script_path: script.path.clone(),
+            project: script.project.clone(),
+            network: network.to_string(),
+            wallet: wallet.to_string(),
+            user: std::env::var("USER")
+                .or_else(|_| std::env::var("USERNAME"))
+                .ok(),
+            flags: redact_args(flags),
+            started_at: started.to_rfc3339(),
+            ended_at: ended.to_rfc3339(),
+            duration_ms: (ended - started).num_milliseconds().max(0) as u64,
+            exit_code: None,
+            signal: None,
+            cancelled: false,
+            error: None,
+            stdout: String::new(),
+            stderr: String::new(),
+        };
+        match result {
+            Ok(output) => {
+                record.exit_code = output.exit.code;
+                record.signal = output.exit.signal;
+                record.cancelled = output.exit.cancelled;
+                record.duration_ms = output.exit.elapsed.as_millis() as u64;
+                record.stdout = output.stdout.clone();
+                record.stderr = output.stderr.clone();
+            }
+            Err(e) => record.error = Some(format!("{:#}", e)),
+        }
+        record
+    }
+
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0) && !self.cancelled
+    }
+
+    /// Exit status and duration, e.g. `exit code 0 after 12.3s`
+    pub fn status(&self) -> String {
+        let status = match (&self.error, self.exit_code, self.signal) {
+            (Some(_), _, _) => "failed to run".to_string(),
+            (None, Some(code), _) => format!("exit code {}", code),
+            (None, None, Some(signal)) => format!("killed by signal {}", signal),
+            (None, None, None) => "no exit status".to_string(),
+        };
+        let cancelled = if self.cancelled { "cancelled, " } else { "" };
+        format!(
+            "{}{} after {:.1}s",
+            cancelled,
+            status,
+            self.duration_ms as f64 / 1000.0
+        )
+    }
+
+    /// Start time without the offset, for lists
+    pub fn started_label(&self) -> String {
+        DateTime::parse_from_rfc3339(&self.started_at)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|_| self.started_at.clone())
+    }
+
+    /// Everything the run printed, stderr after stdout
+    pub fn log(&self) -> String {
+        let mut log = self.stdout.clone();
+        if !self.stderr.is_empty() {
+            log.push_str("─── stderr ───\n");
+            log.push_str(&self.stderr);
+        }
+        if let Some(error) = &self.error {
+            log.push_str("─── error ───\n");
+            log.push_str(error);
+        }
+        log
+    }
+
+    /// Case-insensitive match against the run's details and output
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        [
+            self.script.as_str(),
+            self.network.as_str(),
+            self.wallet.as_str(),
+            self.user.as_deref().unwrap_or(""),
+            self.project.as_deref().unwrap_or(""),
+            self.started_at.as_str(),
+            &self.flags.join(" "),
+            &self.status(),
+            &self.log(),
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+/// Saved runs, newest first
+#[derive(Debug, Default)]
+pub struct RunHistory {
+    /// `None` when there's no data directory to save to
+    pub dir: Option<PathBuf>,
+    pub runs: Vec<RunRecord>,
+}
+
+impl RunHistory {
+    /// Load from `<data dir>/runs`; an unreadable history starts empty
+    pub fn load() -> Self {
+        let dir = match data_dir() {
+            Ok(dir) => dir.join("runs"),
+            Err(e) => {
+                tracing::warn!("Run history disabled: {}", e);
+                return Self::default();
+            }
+        };
+        Self::load_from(dir)
+    }
+
+    fn load_from(dir: PathBuf) -> Self {
+        let mut runs: Vec<RunRecord> = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let content = fs::read_to_string(&path).ok()?;
+                serde_json::from_str(&content)
+                    .inspect_err(|e| tracing::warn!("Skipping run {:?}: {}", path, e))
+                    .ok()
+            })
+            .collect();
+        // Ids start with the start time
+        runs.sort_by(|a, b| b.id.cmp(&a.id));
+
+        Self {
+            dir: Some(dir),
+            runs,
+        }
+    }
+
+    /// Write a run to `dir`; logs can be sensitive, so only the user can read them
+    pub fn save(dir: &Path, record: &RunRecord) -> Result<PathBuf> {
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+            builder.mode(0o700);
+            builder
+                .create(dir)
+                .and_then(|_| fs::set_permissions(dir, fs::Permissions::from_mode(0o700)))
+                .wrap_err_with(|| format!("Failed to create history directory: {:?}", dir))?;
+        }
+        #[cfg(not(unix))]
+        builder
+            .create(dir)
+            .wrap_err_with(|| format!("Failed to create history directory: {:?}", dir))?;
+
+        let path = dir.join(format!("{}.json", record.id));
+        let content = serde_json::to_string_pretty(record).wrap_err("Failed to serialize run")?;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(&path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .wrap_err_with(|| format!("Failed to write run history: {:?}", path))?;
+        Ok(path)
+    }
+
+    /// Indices of the runs matching `query`, all of them if it's empty
+    pub fn filtered(&self, query: &str) -> Vec<usize> {
+        self.runs
+            .iter()
+            .enumerate()
+            .filter(|(_, run)| query.is_empty() || run.matches(query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Strings to redact from a run's output: the private key with and without `0x`,
+/// the RPC URL and any tokens in its path or query, and values of sensitive flags
+pub fn secrets(private_key: Option<&str>, rpc_url: &str, args: &[String]) -> Vec<String> {
+    let mut secrets = Vec::new();
+    if let Some(key) = private_key {
+        secrets.push(key.to_string());
+        secrets.push(key.trim_start_matches("0x").to_string());
+    }
+
+    secrets.push(rpc_url.to_string());
+    // e.g. the key in https://eth-mainnet.g.alchemy.com/v2/<key>
+    secrets.extend(
+        rpc_url
+            .split(['/', '?', '&', '=', '@', ':'])
+            .filter(|part| part.len() >= 16 && !part.contains('.'))
+            .map(str::to_string),
+    );
+
+    for (i, arg) in args.iter().enumerate() {
+        if let Some((flag, value)) = arg.split_once('=')
+            && SENSITIVE_FLAGS.contains(&flag)
+        {
+            secrets.push(value.to_string());
+        } else if SENSITIVE_FLAGS.contains(&arg.as_str())
+            && let Some(value) = args.get(i + 1)
+        {
+            secrets.push(value.to_string());
+        }
+    }
+
+    secrets.retain(|secret| secret.len() >= MIN_SECRET_LEN);
+    // Longest first, so a key isn't half-replaced through a shorter form of it; equal
+    // secrets end up next to each other for `dedup`
+    secrets.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    secrets.dedup();
+    secrets
+}
+
+/// Replace every occurrence of the secrets in `text`
+pub fn redact(text: &str, secrets: &[String]) -> String {
+    secrets.iter().fold(text.to_string(), |text, secret| {
+        text.replace(secret, REDACTED)
+    })
+}
+
+/// Arguments with the values of sensitive flags replaced
+pub fn redact_args(args: &[String]) -> Vec<String> {
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut hide_next = false;
+    for arg in args {
+        if hide_next {
+            redacted.push(REDACTED.to_string());
+            hide_next = false;
+        } else if let Some((flag, _)) = arg.split_once('=')
+            && SENSITIVE_FLAGS.contains(&flag)
+        {
+            redacted.push(format!("{}={}", flag, REDACTED));
+        } else {
+            hide_next = SENSITIVE_FLAGS.contains(&arg.as_str());
+            redacted.push(arg.clone());
+        }
+    }
+    redacted
+}
+
+/// A line of a diff between two runs' logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff from `old` to `new` (longest common subsequence)
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Only the part between a common prefix and suffix needs the table
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let mut diff: Vec<DiffLine> = old[..prefix].iter().map(|l| DiffLine::Same(l)).collect();
+
+    if (a.len() + 1) * (b.len() + 1) > MAX_DIFF_CELLS {
+        diff.extend(a.iter().map(|l| DiffLine::Removed(l)));
+        diff.extend(b.iter().map(|l| DiffLine::Added(l)));
+    } else {
+        // lcs[i][j]: longest common subsequence of a[i..] and b[j..]
+        let width = b.len() + 1;
+        let mut lcs = vec![0u32; (a.len() + 1) * width];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                lcs[i * width + j] = if a[i] == b[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            if a[i] == b[j] {
+                diff.push(DiffLine::Same(a[i]));
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                diff.push(DiffLine::Removed(a[i]));
+                i += 1;
+            } else {
+                diff.push(DiffLine::Added(b[j]));
+                j += 1;
+            }
+        }
+        diff.extend(a[i..].iter().map(|l| DiffLine::Removed(l)));
+        diff.extend(b[j..].iter().map(|l| DiffLine::Added(l)));
+    }
+
+    diff.extend(old[old.len() - suffix..].iter().map(|l| DiffLine::Same(l)));
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    #[test]
+    fn test_redact_secrets() {
+        let args = vec![
+            "--broadcast".to_string(),
+            "--etherscan-api-key".to_string(),
+            "ABCDEF123456".to_string(),
+            "--password=hunter2hunter2".to_string(),
+        ];
+        let secrets = secrets(
+            Some(KEY),
+            "https://eth-mainnet.g.alchemy.com/v2/abcdefghijklmnop1234",
+            &args,
+        );
+
+        let output = format!(
+            "key {}\nraw {}\nurl https://eth-mainnet.g.alchemy.com/v2/abcdefghijklmnop1234\n\
+             error sending request for url (abcdefghijklmnop1234)\napi ABCDEF123456",
+            KEY,
+            &KEY[2..]
+        );
+        let redacted = redact(&output, &secrets);
+        assert!(!redacted.contains(&KEY[2..]));
+        assert!(!redacted.contains("abcdefghijklmnop1234"));
+        assert!(!redacted.contains("ABCDEF123456"));
+        assert_eq!(redacted.matches(REDACTED).count(), 5);
+
+        assert_eq!(
+            redact_args(&args),
+            [
+                "--broadcast",
+                "--etherscan-api-key",
+                REDACTED,
+                "--password=[REDACTED]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_secrets_deduplicated() {
+        let other = "f".repeat(KEY.len() - 2);
+        let args = vec![
+            "--etherscan-api-key".to_string(),
+            other.clone(),
+            "--private-key".to_string(),
+            KEY[2..].to_string(),
+        ];
+        let secrets = secrets(Some(KEY), "http://localhost:8545", &args);
+        assert_eq!(secrets, [KEY, &KEY[2..], &other, "http://localhost:8545"]);
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let old = "Compiling\nnonce 1\ndeployed 0xaaa\ndone";
+        let new = "Compiling\nnonce 2\ndeployed 0xaaa\nverified\ndone";
+        assert_eq!(
+            diff_lines(old, new),
+            [
+                DiffLine::Same("Compiling"),
+                DiffLine::Removed("nonce 1"),
+                DiffLine::Added("nonce 2"),
+                DiffLine::Same("deployed 0xaaa"),
+                DiffLine::Added("verified"),
+                DiffLine::Same("done"),
+            ]
+        );
+        assert!(
+            diff_lines("a\nb", "a\nb")
+                .iter()
+                .all(|l| matches!(l, DiffLine::Same(_)))
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_history() {
+        let dir = std::env::temp_dir().join(format!("runic-history-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let record = |id: &str, network: &str| RunRecord {
+            id: id.to_string(),
+            script: "Deploy".to_string(),
+            script_path: PathBuf::from("script/Deploy.s.sol"),
+            project: None,
+            network: network.to_string(),
+            wallet: "deployer".to_string(),
+            user: Some("alice".to_string()),
+            flags: vec!["--broadcast".to_string()],
+            started_at: "2026-10-17T10:00:00+00:00".to_string(),
+            ended_at: "2026-10-17T10:00:05+00:00".to_string(),
+            duration_ms: 5000,
+            exit_code: Some(0),
+            signal: None,
+            cancelled: false,
+            error: None,
+            stdout: "ONCHAIN EXECUTION COMPLETE & SUCCESSFUL.\n".to_string(),
+            stderr: String::new(),
+        };
+        RunHistory::save(&dir, &record("20261017-100000.000-Deploy", "sepolia")).unwrap();
+        RunHistory::save(&dir, &record("20261017-110000.000-Deploy", "mainnet")).unwrap();
+
+        let history = RunHistory::load_from(dir.clone());
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.runs[0].network, "mainnet");
+        assert_eq!(history.runs[0].status(), "exit code 0 after 5.0s");
+        assert_eq!(history.filtered("SEPOLIA"), [1]);
+        assert_eq!(history.filtered("successful").len(), 2);
+        assert!(history.filtered("nope").is_empty());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&dir), 0o700);
+            assert_eq!(mode(&dir.join("20261017-100000.000-Deploy.json")), 0o600);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
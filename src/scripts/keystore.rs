@@ -0,0 +1,160 @@
+//! Throwaway encrypted keystore for handing a wallet's key to forge
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use alloy::signers::local::PrivateKeySigner;
+use eyre::{Result, WrapErr};
+use rand::Rng;
+use tempfile::TempDir;
+use zeroize::Zeroizing;
+
+/// Temp directory names are `<prefix><pid>-<random>`, so leftovers can be traced to a process
+const DIR_PREFIX: &str = "runic-keystore-";
+const KEYSTORE_FILE: &str = "keystore.json";
+const PASSWORD_FILE: &str = "password";
+
+/// A web3 secret-storage keystore and its password, in a temp directory only we can
+/// enter (0700). The directory is removed when this is dropped or `remove`d.
+pub struct TempKeystore {
+    dir: TempDir,
+}
+
+impl TempKeystore {
+    pub fn new(signer: &PrivateKeySigner) -> Result<Self> {
+        remove_stale_dirs();
+
+        let mut builder = tempfile::Builder::new();
+        let prefix = format!("{}{}-", DIR_PREFIX, std::process::id());
+        builder.prefix(&prefix);
+        #[cfg(unix)]
+        builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o700));
+        let dir = builder
+            .tempdir()
+            .wrap_err("Failed to create a temporary keystore directory")?;
+
+        let mut rng = rand::thread_rng();
+        let password = Zeroizing::new(hex::encode(rng.r#gen::<[u8; 32]>()));
+        let key = Zeroizing::new(signer.to_bytes().0);
+        PrivateKeySigner::encrypt_keystore(
+            dir.path(),
+            &mut rng,
+            key.as_ref(),
+            password.as_bytes(),
+            Some(KEYSTORE_FILE),
+        )
+        .wrap_err("Failed to write the temporary keystore")?;
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(dir.path().join(PASSWORD_FILE))
+            .and_then(|mut file| file.write_all(password.as_bytes()))
+            .wrap_err("Failed to write the temporary keystore password")?;
+
+        Ok(Self { dir })
+    }
+
+    pub fn keystore_path(&self) -> PathBuf {
+        self.dir.path().join(KEYSTORE_FILE)
+    }
+
+    pub fn password_path(&self) -> PathBuf {
+        self.dir.path().join(PASSWORD_FILE)
+    }
+
+    /// Delete the keystore and password now, logging (rather than failing on) errors
+    pub fn remove(self) {
+        let path = self.dir.path().to_path_buf();
+        if let Err(e) = self.dir.close() {
+            tracing::warn!("Failed to remove temporary keystore {:?}: {}", path, e);
+        }
+    }
+}
+
+/// Remove keystore directories left behind by runic processes that were killed mid-run
+fn remove_stale_dirs() {
+    let Ok(entries) = fs::read_dir(std::env::temp_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some(pid) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(DIR_PREFIX))
+            .and_then(|rest| rest.split_once('-'))
+            .and_then(|(pid, _)| pid.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if !process_alive(pid) {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks the process exists; EPERM means it does but isn't ours
+    let exists = unsafe { libc::kill(pid, 0) } == 0;
+    exists || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Without a cheap liveness check, leave other processes' directories alone
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let signer: PrivateKeySigner =
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+                .parse()
+                .unwrap();
+        let keystore = TempKeystore::new(&signer).unwrap();
+        let dir = keystore.dir.path().to_path_buf();
+
+        let password = fs::read_to_string(keystore.password_path()).unwrap();
+        let decrypted =
+            PrivateKeySigner::decrypt_keystore(keystore.keystore_path(), password).unwrap();
+        assert_eq!(decrypted.address(), signer.address());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&dir), 0o700);
+            assert_eq!(mode(&keystore.password_path()), 0o600);
+        }
+
+        keystore.remove();
+        assert!(!dir.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_stale_dirs() {
+        // No process has this id, so its directory is a leftover
+        let stale = std::env::temp_dir().join(format!("{}{}-test", DIR_PREFIX, i32::MAX));
+        let ours = std::env::temp_dir().join(format!("{}{}-test", DIR_PREFIX, std::process::id()));
+        fs::create_dir_all(&stale).unwrap();
+        fs::create_dir_all(&ours).unwrap();
+
+        remove_stale_dirs();
+        assert!(!stale.exists());
+        assert!(ours.exists());
+        fs::remove_dir_all(&ours).unwrap();
+    }
+}
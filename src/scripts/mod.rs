@@ -1,3 +1,12 @@
+mod history;
+mod keystore;
+mod options;
 mod runner;
+mod summary;
 
-pub use runner::ScriptManager;
+pub use history::{DiffLine, RunHistory, RunRecord, diff_lines};
+pub use options::{RunOptionField, RunOptions};
+pub use runner::{
+    CANCEL_GRACE, Script, ScriptExit, ScriptIo, ScriptManager, ScriptOutput, ScriptType,
+};
+pub use summary::{ScriptSummary, ScriptTx};
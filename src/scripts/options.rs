@@ -0,0 +1,248 @@
+use eyre::{Result, eyre};
+use serde::{Deserialize, Serialize};
+
+/// How to run a script, chosen in the run-options step and remembered per script and network
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunOptions {
+    /// Send transactions; otherwise forge only simulates
+    pub broadcast: bool,
+    pub verify: bool,
+    pub slow: bool,
+    pub legacy: bool,
+    pub resume: bool,
+    pub skip_simulation: bool,
+    /// Value for `--with-gas-price`, e.g. `20gwei` (empty to let forge estimate)
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub with_gas_price: String,
+    /// Extra arguments appended to the command, split like a shell would
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub extra_args: String,
+    /// ABI-encoded call of the chosen entry point, passed as `--sig`; `None` runs `run()`.
+    /// Chosen for each run, so never remembered.
+    #[serde(skip)]
+    pub calldata: Option<Vec<u8>>,
+}
+
+/// A row of the run-options step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOptionField {
+    Broadcast,
+    Verify,
+    Slow,
+    Legacy,
+    Resume,
+    SkipSimulation,
+    GasPrice,
+    ExtraArgs,
+}
+
+impl RunOptionField {
+    /// Rows offered for forge scripts
+    pub const FORGE: [RunOptionField; 8] = [
+        Self::Broadcast,
+        Self::Verify,
+        Self::Slow,
+        Self::Legacy,
+        Self::Resume,
+        Self::SkipSimulation,
+        Self::GasPrice,
+        Self::ExtraArgs,
+    ];
+    /// Rows offered for Hardhat scripts, which take none of forge's flags
+    pub const HARDHAT: [RunOptionField; 1] = [Self::ExtraArgs];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Broadcast => "Mode",
+            Self::Verify => "--verify",
+            Self::Slow => "--slow",
+            Self::Legacy => "--legacy",
+            Self::Resume => "--resume",
+            Self::SkipSimulation => "--skip-simulation",
+            Self::GasPrice => "--with-gas-price",
+            Self::ExtraArgs => "Extra args",
+        }
+    }
+}
+
+impl RunOptions {
+    /// Key under which options are remembered for a script on a network
+    pub fn key(script_path: &std::path::Path, network: &str) -> String {
+        format!("{}@{}", script_path.display(), network)
+    }
+
+    /// `forge script` flags for these options
+    pub fn forge_args(&self) -> Result<Vec<String>> {
+        let flags = [
+            (self.broadcast, "--broadcast"),
+            (self.verify, "--verify"),
+            (self.slow, "--slow"),
+            (self.legacy, "--legacy"),
+            (self.resume, "--resume"),
+            (self.skip_simulation, "--skip-simulation"),
+        ];
+        let mut args: Vec<String> = flags
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, flag)| flag.to_string())
+            .collect();
+
+        if let Some(calldata) = &self.calldata {
+            args.push("--sig".to_string());
+            args.push(format!("0x{}", hex::encode(calldata)));
+        }
+
+        let gas_price = self.with_gas_price.trim();
+        if !gas_price.is_empty() {
+            args.push("--with-gas-price".to_string());
+            args.push(gas_price.to_string());
+        }
+
+        args.extend(self.extra_args()?);
+        Ok(args)
+    }
+
+    /// On/off state of a toggle row, `None` for text rows
+    pub fn flag(&self, field: RunOptionField) -> Option<bool> {
+        match field {
+            RunOptionField::Broadcast => Some(self.broadcast),
+            RunOptionField::Verify => Some(self.verify),
+            RunOptionField::Slow => Some(self.slow),
+            RunOptionField::Legacy => Some(self.legacy),
+            RunOptionField::Resume => Some(self.resume),
+            RunOptionField::SkipSimulation => Some(self.skip_simulation),
+            RunOptionField::GasPrice | RunOptionField::ExtraArgs => None,
+        }
+    }
+
+    /// Flip a toggle row; text rows are left alone
+    pub fn toggle(&mut self, field: RunOptionField) {
+        let flag = match field {
+            RunOptionField::Broadcast => &mut self.broadcast,
+            RunOptionField::Verify => &mut self.verify,
+            RunOptionField::Slow => &mut self.slow,
+            RunOptionField::Legacy => &mut self.legacy,
+            RunOptionField::Resume => &mut self.resume,
+            RunOptionField::SkipSimulation => &mut self.skip_simulation,
+            RunOptionField::GasPrice | RunOptionField::ExtraArgs => return,
+        };
+        *flag = !*flag;
+    }
+
+    /// Value of a text row, `None` for toggles
+    pub fn text(&self, field: RunOptionField) -> Option<&String> {
+        match field {
+            RunOptionField::GasPrice => Some(&self.with_gas_price),
+            RunOptionField::ExtraArgs => Some(&self.extra_args),
+            _ => None,
+        }
+    }
+
+    pub fn text_mut(&mut self, field: RunOptionField) -> Option<&mut String> {
+        match field {
+            RunOptionField::GasPrice => Some(&mut self.with_gas_price),
+            RunOptionField::ExtraArgs => Some(&mut self.extra_args),
+            _ => None,
+        }
+    }
+
+    /// The free-form extra arguments, split on whitespace outside quotes
+    pub fn extra_args(&self) -> Result<Vec<String>> {
+        split_args(&self.extra_args)
+    }
+}
+
+/// Split a command line into arguments, honouring single and double quotes and `\` escapes
+fn split_args(line: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"') | None, '\\') => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| eyre!("Trailing \\ in extra arguments"))?;
+                current.push(escaped);
+                in_arg = true;
+            }
+            (Some(_), c) => current.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if let Some(q) = quote {
+        return Err(eyre!("Unclosed {} in extra arguments", q));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forge_args() {
+        let options = RunOptions {
+            broadcast: true,
+            slow: true,
+            skip_simulation: true,
+            with_gas_price: " 20gwei ".to_string(),
+            extra_args: "--sig 'run(uint256)' 42 --json".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.forge_args().unwrap(),
+            [
+                "--broadcast",
+                "--slow",
+                "--skip-simulation",
+                "--with-gas-price",
+                "20gwei",
+                "--sig",
+                "run(uint256)",
+                "42",
+                "--json"
+            ]
+        );
+
+        let call = RunOptions {
+            calldata: Some(vec![0xc0, 0x40, 0x62, 0x26]),
+            ..Default::default()
+        };
+        assert_eq!(call.forge_args().unwrap(), ["--sig", "0xc0406226"]);
+
+        // Simulation by default
+        assert!(RunOptions::default().forge_args().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(
+            split_args(r#"a "b c" d\ e ''"#).unwrap(),
+            ["a", "b c", "d e", ""]
+        );
+        assert!(split_args("\"unclosed").is_err());
+    }
+}
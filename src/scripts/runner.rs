@@ -1,14 +1,23 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
+use std::time::{Duration, SystemTime};
 
-use eyre::{Result, WrapErr};
+use alloy::signers::local::PrivateKeySigner;
+use eyre::{Result, WrapErr, eyre};
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::{OnceCell, oneshot};
+use tokio::time::Instant;
 
+use super::keystore::TempKeystore;
+use super::summary::{ScriptSummary, readable_lines};
+use super::{RunOptions, history};
 use crate::config::AppConfig;
-use crate::project::Project;
+use crate::contracts::{ContractFunction, apply_natspec, parse_abi};
+use crate::project::{HardhatNetwork, Project};
 
 /// Type of script
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,14 +34,89 @@ pub struct Script {
     pub description: Option<String>,
     pub contract_name: Option<String>,
     pub script_type: ScriptType,
+    /// Public entry points from the compiled script's ABI (empty until it's built)
+    pub functions: Vec<ContractFunction>,
+    /// Root of the project the script belongs to
+    pub project_root: PathBuf,
+    /// Workspace project name, set when lists are merged
+    pub project: Option<String>,
 }
 
+/// How long a cancelled script gets to exit after SIGINT before it's killed
+pub const CANCEL_GRACE: Duration = Duration::from_secs(5);
+
 /// Output from running a script
 #[derive(Debug, Clone)]
 pub struct ScriptOutput {
     pub success: bool,
     pub stdout: String,
     pub stderr: String,
+    pub exit: ScriptExit,
+    /// Planned or sent transactions, when forge ran with `--json`
+    pub summary: Option<ScriptSummary>,
+}
+
+/// How a script process ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptExit {
+    pub code: Option<i32>,
+    /// Signal that terminated the process, if it didn't exit on its own
+    pub signal: Option<i32>,
+    pub elapsed: Duration,
+    pub cancelled: bool,
+}
+
+impl ScriptExit {
+    fn new(status: ExitStatus, elapsed: Duration, cancelled: bool) -> Self {
+        #[cfg(unix)]
+        let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+        #[cfg(not(unix))]
+        let signal = None;
+
+        Self {
+            code: status.code(),
+            signal,
+            elapsed,
+            cancelled,
+        }
+    }
+
+    /// A run that never produced an exit status, e.g. because it failed to start
+    pub fn failed(elapsed: Duration) -> Self {
+        Self {
+            code: None,
+            signal: None,
+            elapsed,
+            cancelled: false,
+        }
+    }
+
+    pub fn success(&self) -> bool {
+        self.code == Some(0) && !self.cancelled
+    }
+}
+
+impl std::fmt::Display for ScriptExit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.cancelled {
+            write!(f, "cancelled, ")?;
+        }
+        match (self.code, self.signal) {
+            (Some(code), _) => write!(f, "exit code {}", code)?,
+            (None, Some(signal)) => write!(f, "killed by signal {}", signal)?,
+            (None, None) => write!(f, "failed")?,
+        }
+        write!(f, " after {:.1}s", self.elapsed.as_secs_f64())
+    }
+}
+
+/// Where a running script's output goes and how it's told to stop
+#[derive(Debug, Default)]
+pub struct ScriptIo {
+    /// Receives each line the script prints, as it's printed
+    pub lines: Option<UnboundedSender<String>>,
+    /// Fires when the run should be cancelled
+    pub cancel: Option<oneshot::Receiver<()>>,
 }
 
 impl From<ScriptOutput> for String {
@@ -46,6 +130,14 @@ pub struct ScriptManager {
     pub scripts: Vec<Script>,
     script_dir: PathBuf,
     project_root: PathBuf,
+    /// Compiled artifacts, where script ABIs are read from
+    out_dir: PathBuf,
+    /// Where forge records each run's transactions
+    broadcast_dir: PathBuf,
+    /// Networks from hardhat.config, which `--network` must name
+    pub hardhat_networks: BTreeMap<String, HardhatNetwork>,
+    /// foundry.toml profile forge should run with
+    foundry_profile: Option<String>,
 }
 
 impl ScriptManager {
@@ -54,6 +146,10 @@ impl ScriptManager {
             scripts: Vec::new(),
             script_dir: project.script_dir.clone(),
             project_root: project.root.clone(),
+            out_dir: project.out_dir.clone(),
+            broadcast_dir: project.broadcast_dir.clone(),
+            hardhat_networks: project.hardhat_networks(),
+            foundry_profile: project.foundry_profile().map(str::to_string),
         }
     }
 
@@ -104,6 +200,8 @@ impl ScriptManager {
             let content = fs::read_to_string(path).ok()?;
             let description = extract_natspec_description(&content);
             let contract_name = extract_contract_name(&content);
+            let functions =
+                self.entry_points(&file_name, contract_name.as_deref().unwrap_or(&name));
 
             return Some(Script {
                 name,
@@ -111,6 +209,9 @@ impl ScriptManager {
                 description,
                 contract_name,
                 script_type: ScriptType::Foundry,
+                functions,
+                project_root: self.project_root.clone(),
+                project: None,
             });
         } else if file_name.ends_with(".js") || file_name.ends_with(".ts") {
             // Hardhat script (exclude config files)
@@ -130,30 +231,64 @@ impl ScriptManager {
                 description: None, // TODO: Parse JS comments
                 contract_name: None,
                 script_type: ScriptType::Hardhat,
+                functions: Vec::new(),
+                project_root: self.project_root.clone(),
+                project: None,
             });
         }
 
         None
     }
 
+    /// Functions forge can call on a compiled script: state-changing ones other than `setUp`
+    fn entry_points(&self, file_name: &str, contract: &str) -> Vec<ContractFunction> {
+        let artifact_path = self
+            .out_dir
+            .join(file_name)
+            .join(format!("{}.json", contract));
+        let Some(artifact) = fs::read_to_string(&artifact_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut functions = parse_abi(&artifact["abi"]).unwrap_or_default();
+        apply_natspec(&mut functions, &artifact);
+        functions.retain(|f| {
+            f.name != "setUp" && f.state_mutability != "view" && f.state_mutability != "pure"
+        });
+        functions
+    }
+
+    /// Append another workspace project's scripts, labelled with `project` if given
+    pub fn merge(&mut self, other: &ScriptManager, project: Option<&str>) {
+        self.scripts
+            .extend(other.scripts.iter().map(|script| Script {
+                project: project.map(str::to_string),
+                ..script.clone()
+            }));
+        self.scripts
+            .sort_by(|a, b| (&a.name, &a.project).cmp(&(&b.name, &b.project)));
+    }
+
     /// Run a script
     pub async fn run(
         &self,
         script: &Script,
         network: &str,
         rpc_url: &str,
-        broadcast: bool,
-        verify: bool,
         private_key: Option<&str>,
-        tx: Option<UnboundedSender<String>>,
+        options: &RunOptions,
+        io: ScriptIo,
     ) -> Result<ScriptOutput> {
         match script.script_type {
             ScriptType::Foundry => {
-                self.run_foundry(script, rpc_url, broadcast, verify, private_key, tx)
+                self.run_foundry(script, rpc_url, private_key, options, io)
                     .await
             }
             ScriptType::Hardhat => {
-                self.run_hardhat(script, network, rpc_url, private_key, tx)
+                self.run_hardhat(script, network, rpc_url, private_key, options, io)
                     .await
             }
         }
@@ -163,10 +298,9 @@ impl ScriptManager {
         &self,
         script: &Script,
         rpc_url: &str,
-        broadcast: bool,
-        verify: bool,
         private_key: Option<&str>,
-        tx: Option<UnboundedSender<String>>,
+        options: &RunOptions,
+        io: ScriptIo,
     ) -> Result<ScriptOutput> {
         let script_path = script
             .path
@@ -186,21 +320,74 @@ impl ScriptManager {
             .arg(rpc_url)
             .current_dir(&self.project_root);
 
-        if broadcast {
-            cmd.arg("--broadcast");
+        if let Some(profile) = &self.foundry_profile {
+            cmd.env("FOUNDRY_PROFILE", profile);
         }
 
-        if verify {
-            cmd.arg("--verify");
+        let forge_args = options.forge_args()?;
+        if !forge_args.iter().any(|arg| arg == "--json") && forge_supports_json().await {
+            cmd.arg("--json");
         }
-
-        // Pass private key directly and via env var
+        cmd.args(forge_args);
+
+        // Nothing secret goes on the command line, where every user can read it through
+        // `ps`. forge signs with a throwaway keystore and password file in a directory only
+        // we can enter, removed once forge exits however the run ended (a leftover from a
+        // killed runic is swept on the next run). Scripts that read
+        // `vm.envUint("PRIVATE_KEY")` get the key from the environment.
+        let mut keystore = None;
         if let Some(pk) = private_key {
-            cmd.env("PRIVATE_KEY", pk);
-            cmd.arg("--private-key").arg(pk);
+            let signer: PrivateKeySigner = pk
+                .parse()
+                .map_err(|_| eyre!("Invalid private key for {}", script.name))?;
+            let temp = TempKeystore::new(&signer)?;
+            cmd.arg("--keystore")
+                .arg(temp.keystore_path())
+                .arg("--password-file")
+                .arg(temp.password_path())
+                .arg("--sender")
+                .arg(signer.address().to_string())
+                .env("PRIVATE_KEY", pk);
+            keystore = Some(temp);
+        }
+
+        let started = SystemTime::now();
+        let result = self.execute_command(cmd, &script.name, io).await;
+        if let Some(keystore) = keystore {
+            keystore.remove();
         }
+        let mut output = result?;
 
-        self.execute_command(cmd, &script.name, tx).await
+        output.summary = ScriptSummary::parse(&output.stdout);
+        if let Some(summary) = &mut output.summary
+            && let Some(run) = self.latest_run_file(script, started)
+        {
+            summary.apply_run_file(&run);
+        }
+        Ok(output)
+    }
+
+    /// Run file forge wrote for this script since `since`, broadcast or dry-run
+    fn latest_run_file(&self, script: &Script, since: SystemTime) -> Option<serde_json::Value> {
+        let file_name = script.path.file_name()?;
+        // Allow for filesystems with coarse modification times
+        let since = since.checked_sub(Duration::from_secs(2)).unwrap_or(since);
+
+        let (_, path) = fs::read_dir(self.broadcast_dir.join(file_name))
+            .ok()?
+            .flatten()
+            .flat_map(|chain| {
+                [
+                    chain.path().join("run-latest.json"),
+                    chain.path().join("dry-run").join("run-latest.json"),
+                ]
+            })
+            .filter_map(|path| Some((fs::metadata(&path).ok()?.modified().ok()?, path)))
+            .filter(|(modified, _)| *modified >= since)
+            .max()?;
+
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
     }
 
     async fn run_hardhat(
@@ -209,7 +396,8 @@ impl ScriptManager {
         network: &str,
         rpc_url: &str,
         private_key: Option<&str>,
-        tx: Option<UnboundedSender<String>>,
+        options: &RunOptions,
+        io: ScriptIo,
     ) -> Result<ScriptOutput> {
         let script_path = script
             .path
@@ -225,9 +413,24 @@ impl ScriptManager {
         // Special handling: if network is "custom" or "env", skip --network flag
         // and let script use RPC_URL env var directly
         if network != "custom" && network != "env" {
+            // Hardhat only accepts networks from its config; fail before spawning it
+            if !self.hardhat_networks.is_empty() && !self.hardhat_networks.contains_key(network) {
+                return Err(eyre::eyre!(
+                    "Network '{}' is not defined in the Hardhat config. Available: {}",
+                    network,
+                    self.hardhat_networks
+                        .keys()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
             cmd.arg("--network").arg(network);
         }
 
+        // forge flags don't apply; only the extra arguments are passed on
+        cmd.args(options.extra_args()?);
+
         // Pass RPC URL and private key as environment variables
         // Scripts can use these directly with:
         //   const provider = new ethers.JsonRpcProvider(process.env.RPC_URL)
@@ -239,20 +442,28 @@ impl ScriptManager {
             cmd.env("PRIVATE_KEY", pk);
         }
 
-        self.execute_command(cmd, &script.name, tx).await
+        self.execute_command(cmd, &script.name, io).await
     }
 
     async fn execute_command(
         &self,
         mut cmd: Command,
         script_name: &str,
-        tx: Option<UnboundedSender<String>>,
+        io: ScriptIo,
     ) -> Result<ScriptOutput> {
-        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        cmd.stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        // Own process group, so cancelling reaches everything the script started
+        #[cfg(unix)]
+        cmd.process_group(0);
 
         tracing::info!("Running script: {:?}", script_name);
 
+        let started = Instant::now();
         let mut child = cmd.spawn().wrap_err("Failed to spawn command")?;
+        #[cfg(unix)]
+        let pid = child.id();
 
         let stdout = child.stdout.take().unwrap();
         let stderr = child.stderr.take().unwrap();
@@ -265,27 +476,39 @@ impl ScriptManager {
 
         let mut stdout_output = String::new();
         let mut stderr_output = String::new();
-
-        // Read output concurrently
-        loop {
+        let mut stdout_open = true;
+        let mut stderr_open = true;
+
+        let ScriptIo {
+            lines: tx,
+            mut cancel,
+        } = io;
+        let mut cancelled = false;
+        // When to follow a cancelling SIGINT up with SIGKILL
+        let mut kill_at: Option<Instant> = None;
+
+        // Read output concurrently, then wait for the process to exit
+        let status = loop {
             tokio::select! {
-                line = stdout_lines.next_line() => {
+                line = stdout_lines.next_line(), if stdout_open => {
                     match line {
                         Ok(Some(l)) => {
                             if let Some(tx) = &tx {
-                                let _ = tx.send(l.clone());
+                                for line in readable_lines(&l) {
+                                    let _ = tx.send(line);
+                                }
                             }
                             stdout_output.push_str(&l);
                             stdout_output.push('\n');
                         }
-                        Ok(None) => break,
+                        Ok(None) => stdout_open = false,
                         Err(e) => {
                             tracing::warn!("Error reading stdout: {}", e);
-                            break;
+                            stdout_open = false;
                         }
                     }
                 }
-                line = stderr_lines.next_line() => {
+                line = stderr_lines.next_line(), if stderr_open => {
                     match line {
                         Ok(Some(l)) => {
                             if let Some(tx) = &tx {
@@ -294,38 +517,68 @@ impl ScriptManager {
                             stderr_output.push_str(&l);
                             stderr_output.push('\n');
                         }
-                        Ok(None) => {} // stderr might close before stdout
+                        Ok(None) => stderr_open = false,
                         Err(e) => {
                             tracing::warn!("Error reading stderr: {}", e);
+                            stderr_open = false;
                         }
                     }
                 }
+                _ = cancel_requested(&mut cancel) => {
+                    cancelled = true;
+                    if let Some(tx) = &tx {
+                        let _ = tx.send("─── Cancelling ───".to_string());
+                    }
+                    #[cfg(unix)]
+                    {
+                        signal_group(pid, libc::SIGINT);
+                        kill_at = Some(Instant::now() + CANCEL_GRACE);
+                    }
+                    #[cfg(not(unix))]
+                    let _ = child.start_kill();
+                }
+                _ = tokio::time::sleep_until(kill_at.unwrap_or_else(Instant::now)), if kill_at.is_some() => {
+                    kill_at = None;
+                    #[cfg(unix)]
+                    signal_group(pid, libc::SIGKILL);
+                }
+                status = child.wait(), if !stdout_open && !stderr_open => {
+                    break status.wrap_err("Failed to wait for command")?;
+                }
             }
-        }
-
-        let status = child.wait().await.wrap_err("Failed to wait for command")?;
+        };
 
         Ok(ScriptOutput {
             success: status.success(),
             stdout: stdout_output,
             stderr: stderr_output,
+            exit: ScriptExit::new(status, started.elapsed(), cancelled),
+            summary: None,
         })
     }
 
     /// Run a script with explicit wallet selection
-    /// If wallet_name is None, uses PRIVATE_KEY environment variable
+    /// If wallet_name is None, uses PRIVATE_KEY environment variable.
+    /// The returned output and errors have the key and RPC URL redacted.
     pub async fn run_with_wallet(
         &self,
         script: &Script,
         network_name: &str,
         wallet_name: Option<&str>,
         config: &AppConfig,
-        broadcast: bool,
-        verify: bool,
-        tx: Option<UnboundedSender<String>>,
+        options: &RunOptions,
+        io: ScriptIo,
     ) -> Result<ScriptOutput> {
         let rpc_url = match config.resolve_rpc_url(network_name)? {
             Some(url) => url,
+            // Hardhat resolves its own networks; the URL is only passed on as RPC_URL
+            None if script.script_type == ScriptType::Hardhat
+                && self.hardhat_networks.contains_key(network_name) =>
+            {
+                self.hardhat_networks[network_name]
+                    .resolved_url()
+                    .unwrap_or_default()
+            }
             None => {
                 return Err(eyre::eyre!(
                     "Network '{}' not found in config",
@@ -382,16 +635,64 @@ impl ScriptManager {
             }
         };
 
-        self.run(
-            script,
-            network_name,
-            &rpc_url,
-            broadcast,
-            verify,
+        let secrets = history::secrets(
             private_key.as_deref(),
-            tx,
-        )
+            &rpc_url,
+            &options.extra_args().unwrap_or_default(),
+        );
+        let mut output = self
+            .run(
+                script,
+                network_name,
+                &rpc_url,
+                private_key.as_deref(),
+                options,
+                io,
+            )
+            .await
+            .map_err(|e| eyre!(history::redact(&format!("{:#}", e), &secrets)))?;
+        output.stdout = history::redact(&output.stdout, &secrets);
+        output.stderr = history::redact(&output.stderr, &secrets);
+        Ok(output)
+    }
+}
+
+/// Whether the installed forge accepts `--json` for scripts, checked once
+async fn forge_supports_json() -> bool {
+    static SUPPORTED: OnceCell<bool> = OnceCell::const_new();
+    *SUPPORTED
+        .get_or_init(|| async {
+            Command::new("forge")
+                .args(["script", "--help"])
+                .output()
+                .await
+                .map(|output| String::from_utf8_lossy(&output.stdout).contains("--json"))
+                .unwrap_or(false)
+        })
         .await
+}
+
+/// Resolves once cancellation is requested; never if the sender is dropped without asking
+async fn cancel_requested(cancel: &mut Option<oneshot::Receiver<()>>) {
+    let requested = match cancel.as_mut() {
+        Some(rx) => rx.await.is_ok(),
+        None => false,
+    };
+    // A finished receiver must not be polled again
+    *cancel = None;
+    if !requested {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Send `signal` to the script's process group
+#[cfg(unix)]
+fn signal_group(pid: Option<u32>, signal: libc::c_int) {
+    if let Some(pid) = pid {
+        // SAFETY: killpg has no memory-safety preconditions
+        unsafe {
+            libc::killpg(pid as libc::pid_t, signal);
+        }
     }
 }
 
@@ -498,6 +799,73 @@ contract DeployToken is Script {
         );
     }
 
+    #[test]
+    fn test_entry_points_from_artifact() {
+        let root = std::env::temp_dir().join(format!("runic-script-abi-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("script")).unwrap();
+        fs::create_dir_all(root.join("out/Deploy.s.sol")).unwrap();
+        fs::write(root.join("foundry.toml"), "[profile.default]\n").unwrap();
+        fs::write(
+            root.join("script/Deploy.s.sol"),
+            "contract DeployToken is Script {\n    function run() external {}\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("out/Deploy.s.sol/DeployToken.json"),
+            r#"{"abi":[
+                {"type":"function","name":"setUp","inputs":[],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"function","name":"IS_SCRIPT","inputs":[],"outputs":[{"name":"","type":"bool"}],"stateMutability":"view"},
+                {"type":"function","name":"run","inputs":[],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"function","name":"deployTo","inputs":[{"name":"owner","type":"address"},{"name":"supply","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let project = Project::new_foundry(&root, None).unwrap();
+        let mut manager = ScriptManager::new(&project);
+        manager.scan().unwrap();
+        let names: Vec<_> = manager.scripts[0]
+            .functions
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(names, ["run", "deployTo"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_cancel_interrupts_process_group() {
+        let manager = ScriptManager {
+            scripts: Vec::new(),
+            script_dir: PathBuf::new(),
+            project_root: PathBuf::new(),
+            out_dir: PathBuf::new(),
+            broadcast_dir: PathBuf::new(),
+            hardhat_networks: BTreeMap::new(),
+            foundry_profile: None,
+        };
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg("echo started; exec sleep 30");
+
+        let (line_tx, mut line_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let io = ScriptIo {
+            lines: Some(line_tx),
+            cancel: Some(cancel_rx),
+        };
+        let run = tokio::spawn(async move { manager.execute_command(cmd, "sleep", io).await });
+
+        assert_eq!(line_rx.recv().await.as_deref(), Some("started"));
+        cancel_tx.send(()).unwrap();
+        let output = run.await.unwrap().unwrap();
+
+        assert!(output.exit.cancelled);
+        assert!(!output.exit.success());
+        assert!(output.exit.elapsed < CANCEL_GRACE);
+    }
+
     #[test]
     fn test_normalize_private_key() {
         // Valid key with 0x prefix
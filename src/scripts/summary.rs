@@ -0,0 +1,403 @@
+//! Structured results of `forge script --json`
+
+use std::str::FromStr;
+
+use alloy::primitives::U256;
+use serde_json::Value;
+
+/// Transactions a forge script planned or sent, parsed from its `--json` output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptSummary {
+    pub transactions: Vec<ScriptTx>,
+    /// Estimated cost of broadcasting, per chain
+    pub costs: Vec<ChainCost>,
+    /// Contracts the script creates, with their (predicted, if not broadcast) addresses
+    pub deployed: Vec<DeployedContract>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptTx {
+    pub chain_id: Option<u64>,
+    /// Called address, `None` for contract creations
+    pub to: Option<String>,
+    /// Contract the transaction creates or calls, when forge knows it
+    pub contract: Option<String>,
+    /// Called function's signature, or its selector when forge couldn't name it
+    pub function: Option<String>,
+    pub value: U256,
+    /// Gas limit forge estimated
+    pub gas: Option<u64>,
+    /// Set once the transaction is sent
+    pub hash: Option<String>,
+    /// Receipt status, once mined
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainCost {
+    pub chain_id: u64,
+    pub total_gas: u64,
+    /// Estimated gas price, in gwei as forge prints it
+    pub gas_price: Option<String>,
+    /// Estimated total, in the chain's native token
+    pub amount: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployedContract {
+    pub chain_id: Option<u64>,
+    pub name: Option<String>,
+    pub address: String,
+}
+
+impl ScriptSummary {
+    /// Parse the JSON lines forge prints with `--json`; `None` if there were none
+    pub fn parse(stdout: &str) -> Option<Self> {
+        let mut summary = Self::default();
+        let mut found = false;
+
+        for value in stdout.lines().filter_map(json_object) {
+            if let Some(transactions) = value.get("transactions").and_then(Value::as_array) {
+                summary
+                    .transactions
+                    .extend(transactions.iter().map(planned_tx));
+                found = true;
+            } else if let Some(total_gas) = value.get("estimated_total_gas_used").and_then(quantity)
+            {
+                summary.costs.push(ChainCost {
+                    chain_id: value.get("chain").and_then(quantity).unwrap_or_default(),
+                    total_gas,
+                    gas_price: value
+                        .get("estimated_gas_price")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    amount: value
+                        .get("estimated_amount_required")
+                        .and_then(Value::as_str)
+                        .unwrap_or("?")
+                        .to_string(),
+                    symbol: value
+                        .get("token_symbol")
+                        .and_then(Value::as_str)
+                        .unwrap_or("ETH")
+                        .to_string(),
+                });
+                found = true;
+            } else if let Some(hash) = value.get("tx_hash").and_then(Value::as_str) {
+                summary.apply_receipt(&value, hash);
+                found = true;
+            }
+        }
+
+        found.then_some(summary)
+    }
+
+    /// Fill in what the JSON output leaves out from the run file forge wrote
+    /// (`broadcast/…/run-latest.json`, or `dry-run/` when simulating)
+    pub fn apply_run_file(&mut self, run: &Value) {
+        let Some(transactions) = run.get("transactions").and_then(Value::as_array) else {
+            return;
+        };
+        let chain_id = run.get("chain").and_then(quantity);
+
+        for (i, recorded) in transactions.iter().enumerate() {
+            if self.transactions.len() <= i {
+                self.transactions.push(planned_tx(recorded));
+            }
+            let tx = &mut self.transactions[i];
+            let fields = &recorded["transaction"];
+
+            tx.chain_id = tx.chain_id.or(chain_id);
+            if let Some(function) = recorded.get("function").and_then(Value::as_str) {
+                tx.function = Some(function.to_string());
+            }
+            if let Some(contract) = recorded.get("contractName").and_then(Value::as_str) {
+                tx.contract = Some(contract.to_string());
+            }
+            if tx.gas.is_none() {
+                tx.gas = fields.get("gas").and_then(quantity);
+            }
+            if tx.hash.is_none() {
+                tx.hash = recorded
+                    .get("hash")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+            }
+
+            let creates = matches!(
+                recorded.get("transactionType").and_then(Value::as_str),
+                Some("CREATE" | "CREATE2")
+            );
+            let created = recorded
+                .get("additionalContracts")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|c| Some((c.get("contractName"), c.get("address")?.as_str()?)));
+            let primary = recorded
+                .get("contractAddress")
+                .and_then(Value::as_str)
+                .filter(|_| creates)
+                .map(|address| (recorded.get("contractName"), address));
+
+            let tx_chain_id = tx.chain_id;
+            for (name, address) in primary.into_iter().chain(created) {
+                self.add_deployed(DeployedContract {
+                    chain_id: tx_chain_id,
+                    name: name.and_then(Value::as_str).map(str::to_string),
+                    address: address.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Whether any transaction was actually sent
+    pub fn broadcast(&self) -> bool {
+        self.transactions.iter().any(|tx| tx.hash.is_some())
+    }
+
+    /// Record a receipt against the first sent-but-unconfirmed transaction on its chain
+    fn apply_receipt(&mut self, receipt: &Value, hash: &str) {
+        let chain_id = receipt.get("chain").and_then(quantity);
+        let success = receipt
+            .get("status")
+            .and_then(Value::as_str)
+            .map(|status| status == "success");
+
+        let index = self
+            .transactions
+            .iter()
+            .position(|tx| tx.hash.as_deref() == Some(hash))
+            .or_else(|| {
+                self.transactions.iter().position(|tx| {
+                    tx.hash.is_none() && (chain_id.is_none() || tx.chain_id == chain_id)
+                })
+            });
+        let tx = match index {
+            Some(i) => &mut self.transactions[i],
+            None => {
+                self.transactions.push(ScriptTx::default());
+                self.transactions.last_mut().unwrap()
+            }
+        };
+        tx.hash = Some(hash.to_string());
+        tx.success = success;
+        tx.chain_id = tx.chain_id.or(chain_id);
+
+        if let Some(address) = receipt.get("contract_address").and_then(Value::as_str) {
+            let deployed = DeployedContract {
+                chain_id: tx.chain_id,
+                name: tx.contract.clone(),
+                address: address.to_string(),
+            };
+            self.add_deployed(deployed);
+        }
+    }
+
+    fn add_deployed(&mut self, contract: DeployedContract) {
+        match self
+            .deployed
+            .iter_mut()
+            .find(|c| c.address.eq_ignore_ascii_case(&contract.address))
+        {
+            Some(existing) => {
+                if existing.name.is_none() {
+                    existing.name = contract.name;
+                }
+            }
+            None => self.deployed.push(contract),
+        }
+    }
+}
+
+/// Human-readable form of a line of forge's `--json` output, for the live log.
+/// Lines that aren't forge JSON are returned as they are.
+pub fn readable_lines(line: &str) -> Vec<String> {
+    let Some(value) = json_object(line) else {
+        return vec![line.to_string()];
+    };
+
+    if let Some(transactions) = value.get("transactions") {
+        let mut lines: Vec<String> = value
+            .get("logs")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+        let planned = transactions.as_array().map_or(0, Vec::len);
+        let outcome = match value.get("success").and_then(Value::as_bool) {
+            Some(false) => "Script reverted",
+            _ => "Script ran",
+        };
+        let mut line = format!("{}: {} transaction(s)", outcome, planned);
+        if let Some(gas) = value.get("gas_used").and_then(quantity) {
+            line.push_str(&format!(", {} gas used", gas));
+        }
+        lines.push(line);
+        lines
+    } else if let Some(total_gas) = value.get("estimated_total_gas_used").and_then(quantity) {
+        let mut line = format!(
+            "Chain {}: ~{} {} for {} gas",
+            value.get("chain").and_then(quantity).unwrap_or_default(),
+            value
+                .get("estimated_amount_required")
+                .and_then(Value::as_str)
+                .unwrap_or("?"),
+            value
+                .get("token_symbol")
+                .and_then(Value::as_str)
+                .unwrap_or("ETH"),
+            total_gas
+        );
+        if let Some(price) = value.get("estimated_gas_price").and_then(Value::as_str) {
+            line.push_str(&format!(" at {} gwei", price));
+        }
+        vec![line]
+    } else if let Some(hash) = value.get("tx_hash").and_then(Value::as_str) {
+        let status = value
+            .get("status")
+            .and_then(Value::as_str)
+            .unwrap_or("sent");
+        let mut line = format!("[{}] {}", status, hash);
+        if let Some(address) = value.get("contract_address").and_then(Value::as_str) {
+            line.push_str(&format!(" created {}", address));
+        }
+        if let Some(block) = value.get("block_number").and_then(quantity) {
+            line.push_str(&format!(" in block {}", block));
+        }
+        vec![line]
+    } else {
+        vec![line.to_string()]
+    }
+}
+
+fn json_object(line: &str) -> Option<Value> {
+    let line = line.trim();
+    if !line.starts_with('{') {
+        return None;
+    }
+    serde_json::from_str::<Value>(line)
+        .ok()
+        .filter(Value::is_object)
+}
+
+/// A transaction as planned by the script: `{"rpc", "transaction": {...}}` in the JSON
+/// output, or a run file entry
+fn planned_tx(entry: &Value) -> ScriptTx {
+    let fields = &entry["transaction"];
+    let input = fields
+        .get("input")
+        .or_else(|| fields.get("data"))
+        .and_then(Value::as_str)
+        .unwrap_or("0x");
+    let to = fields.get("to").and_then(Value::as_str).map(str::to_string);
+    // Calls are named by selector until the run file says better
+    let function = to.as_ref().and_then(|_| {
+        let selector = input.get(..10)?;
+        selector.starts_with("0x").then(|| selector.to_string())
+    });
+
+    ScriptTx {
+        chain_id: fields.get("chainId").and_then(quantity),
+        to,
+        function,
+        value: fields
+            .get("value")
+            .and_then(Value::as_str)
+            .and_then(|v| U256::from_str(v).ok())
+            .unwrap_or_default(),
+        gas: fields.get("gas").and_then(quantity),
+        ..Default::default()
+    }
+}
+
+/// A JSON number, or a hex or decimal string as alloy serializes quantities
+fn quantity(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => s.parse().ok(),
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+
+    #[test]
+    fn test_parse_json_output_and_run_file() {
+        let stdout = [
+            "Compiling 1 files with Solc 0.8.26".to_string(),
+            format!(
+                r#"{{"logs":["deploying"],"success":true,"gas_used":90000,"transactions":[{{"rpc":"http://localhost:8545","transaction":{{"from":"0xf39f","to":null,"value":"0x0","input":"0x6080","chainId":"0x7a69"}}}},{{"rpc":"http://localhost:8545","transaction":{{"from":"0xf39f","to":"{TOKEN}","value":"0xde0b6b3a7640000","input":"0x40c10f19000000","chainId":"0x7a69"}}}}]}}"#
+            ),
+            r#"{"chain":31337,"estimated_gas_price":"2.000000001","estimated_total_gas_used":120000,"estimated_amount_required":"0.00024000000012"}"#.to_string(),
+            format!(
+                r#"{{"chain":31337,"status":"success","tx_hash":"0xaaa","contract_address":"{TOKEN}","block_number":1}}"#
+            ),
+            r#"{"chain":31337,"status":"success","tx_hash":"0xbbb","contract_address":null,"block_number":2}"#.to_string(),
+        ]
+        .join("\n");
+
+        let mut summary = ScriptSummary::parse(&stdout).unwrap();
+        assert_eq!(summary.transactions.len(), 2);
+        assert_eq!(summary.transactions[0].to, None);
+        assert_eq!(
+            summary.transactions[1].function.as_deref(),
+            Some("0x40c10f19")
+        );
+        assert_eq!(
+            summary.transactions[1].value,
+            U256::from(1_000_000_000_000_000_000u64)
+        );
+        assert_eq!(summary.transactions[1].hash.as_deref(), Some("0xbbb"));
+        assert_eq!(summary.costs[0].chain_id, 31337);
+        assert_eq!(summary.costs[0].total_gas, 120000);
+        assert_eq!(summary.deployed[0].address, TOKEN);
+        assert!(summary.broadcast());
+
+        let run: Value = serde_json::from_str(&format!(
+            r#"{{"chain":31337,"transactions":[
+                {{"hash":"0xaaa","transactionType":"CREATE","contractName":"Token","contractAddress":"{TOKEN}","function":null,"transaction":{{"gas":"0x1d4c0"}}}},
+                {{"hash":"0xbbb","transactionType":"CALL","contractName":"Token","contractAddress":"{TOKEN}","function":"mint(address,uint256)","transaction":{{"gas":"0x7530"}}}}
+            ]}}"#
+        ))
+        .unwrap();
+        summary.apply_run_file(&run);
+        assert_eq!(summary.transactions[0].contract.as_deref(), Some("Token"));
+        assert_eq!(summary.transactions[0].gas, Some(120000));
+        assert_eq!(
+            summary.transactions[1].function.as_deref(),
+            Some("mint(address,uint256)")
+        );
+        assert_eq!(summary.deployed.len(), 1);
+        assert_eq!(summary.deployed[0].name.as_deref(), Some("Token"));
+
+        assert!(ScriptSummary::parse("plain output\n").is_none());
+    }
+
+    #[test]
+    fn test_readable_lines() {
+        assert_eq!(readable_lines("== Logs =="), ["== Logs =="]);
+        assert_eq!(
+            readable_lines(
+                r#"{"logs":["hello"],"success":true,"gas_used":21000,"transactions":[]}"#
+            ),
+            ["hello", "Script ran: 0 transaction(s), 21000 gas used"]
+        );
+        assert_eq!(
+            readable_lines(
+                r#"{"chain":1,"estimated_gas_price":"12.5","estimated_total_gas_used":100000,"estimated_amount_required":"0.00125"}"#
+            ),
+            ["Chain 1: ~0.00125 ETH for 100000 gas at 12.5 gwei"]
+        );
+    }
+}
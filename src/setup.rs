@@ -87,6 +87,7 @@ fn setup_network(config: &mut AppConfig) -> Result<()> {
             chain_id: Some(chain_id),
             explorer_url,
             explorer_api_key: None,
+            confirmations: None,
         },
     );
 
@@ -141,10 +142,10 @@ fn setup_wallet(config: &mut AppConfig) -> Result<()> {
     );
 
     // Set as default if it's the first wallet
-    if let Some(defaults) = &mut config.defaults {
-        if defaults.wallet.is_none() {
-            defaults.wallet = Some(name.clone());
-        }
+    if let Some(defaults) = &mut config.defaults
+        && defaults.wallet.is_none()
+    {
+        defaults.wallet = Some(name.clone());
     }
 
     println!(
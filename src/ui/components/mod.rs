@@ -1 +1 @@
-// Components removed as they were unused
\ No newline at end of file
+// Components removed as they were unused
@@ -178,6 +178,7 @@ fn draw_keychain_management(frame: &mut Frame, _app: &App, area: Rect) {
         • Press 'a' to add new API key\n\
         • Press 'd' to delete stored credentials\n\
         • Press 'e' to export/view private key\n\
+        • Press 'f' to import networks from foundry.toml\n\
         \n\
         Stored securely in OS keychain (service: runic)";
 
@@ -200,8 +201,9 @@ fn draw_api_keys(frame: &mut Frame, app: &App, area: Rect) {
     let items: Vec<ListItem> = api_keys
         .iter()
         .map(|(name, value)| {
-            let is_keychain = value.starts_with("keychain:");
-            let status = if is_keychain { "🔓" } else { "⚠️" };
+            // Keychain and `${VAR}` references keep the key itself out of the config file
+            let is_reference = value.starts_with("keychain:") || value.starts_with("${");
+            let status = if is_reference { "🔓" } else { "⚠️" };
 
             ListItem::new(format!("{} {}", status, name))
         })
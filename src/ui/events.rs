@@ -0,0 +1,311 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::{App, InteractFocus, InteractState};
+use crate::contracts::{ContractEvent, LogEntry, encode_param_type};
+
+/// Events tab: event picker, query form and decoded logs table
+pub fn draw(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
+    let events: &[ContractEvent] = app
+        .deployments
+        .deployments
+        .get(state.selected_deployment)
+        .map(|d| d.events.as_slice())
+        .unwrap_or_default();
+    let event = state
+        .events
+        .selected_event
+        .checked_sub(1)
+        .and_then(|i| events.get(i));
+
+    let labels = query_labels(event);
+    let list_height = (events.len() as u16 + 1).min(8) + 2;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(list_height),
+            Constraint::Length(labels.len() as u16 + 3),
+            Constraint::Min(5),
+        ])
+        .split(area);
+
+    draw_event_list(frame, state, events, chunks[0]);
+    draw_query_form(frame, state, &labels, chunks[1]);
+    if state.events.tailing || !state.events.tail.is_empty() {
+        let logs_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[2]);
+        draw_logs_table(frame, state, logs_chunks[0]);
+        draw_live_panel(frame, state, logs_chunks[1]);
+    } else {
+        draw_logs_table(frame, state, chunks[2]);
+    }
+}
+
+fn draw_event_list(frame: &mut Frame, state: &InteractState, events: &[ContractEvent], area: Rect) {
+    let is_focused = matches!(state.focus, InteractFocus::Events);
+    let border_color = if is_focused { Color::Cyan } else { Color::Blue };
+
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        "All events",
+        Style::default().add_modifier(Modifier::BOLD),
+    )))];
+    items.extend(events.iter().map(|e| {
+        let params: Vec<String> = e
+            .inputs
+            .iter()
+            .map(|p| {
+                let indexed = if p.indexed { " indexed" } else { "" };
+                format!(
+                    "{}{} {}",
+                    encode_param_type(&p.param),
+                    indexed,
+                    p.param.name
+                )
+            })
+            .collect();
+        let mut spans = vec![
+            Span::styled(&e.name, Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("({})", params.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if e.anonymous {
+            spans.push(Span::styled(
+                " anonymous",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        ListItem::new(Line::from(spans))
+    }));
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.events.selected_event));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Events ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color)),
+        )
+        .highlight_style(if is_focused {
+            Style::default().bg(Color::Blue)
+        } else {
+            Style::default().bg(Color::DarkGray)
+        })
+        .highlight_symbol(if is_focused { "▶ " } else { "  " });
+
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn draw_query_form(frame: &mut Frame, state: &InteractState, labels: &[String], area: Rect) {
+    let is_focused = matches!(state.focus, InteractFocus::EventQuery);
+    let border_color = if is_focused { Color::Cyan } else { Color::Blue };
+
+    let mut lines: Vec<Line> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let is_current = is_focused && i == state.events.current_field;
+            let value = state
+                .events
+                .query_values
+                .get(i)
+                .map(String::as_str)
+                .unwrap_or("");
+            let label_style = if is_current {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let input_style = if is_current {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+
+            Line::from(vec![
+                Span::styled(if is_current { "▶ " } else { "  " }, label_style),
+                Span::styled(format!("{}: ", label), label_style),
+                Span::styled(
+                    format!("{}{}", value, if is_current { "█" } else { "" }),
+                    input_style,
+                ),
+            ])
+        })
+        .collect();
+
+    let hint = if let Some(error) = &state.events.error {
+        Span::styled(error.as_str(), Style::default().fg(Color::Red))
+    } else {
+        Span::styled(
+            "Blocks: number, latest, earliest or latest-N. Empty filters match anything.",
+            Style::default().fg(Color::DarkGray),
+        )
+    };
+    lines.push(Line::from(hint));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Query ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color)),
+    );
+
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_logs_table(frame: &mut Frame, state: &InteractState, area: Rect) {
+    let is_focused = matches!(state.focus, InteractFocus::EventLogs);
+    let border_color = if is_focused { Color::Cyan } else { Color::Blue };
+    let title = match &state.events.status {
+        Some(status) => format!(" Logs: {} ", status),
+        None => " Logs ".to_string(),
+    };
+
+    let header = Row::new(["Block", "Tx", "Event", "Arguments"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = state.events.logs.iter().map(log_row).collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(13),
+            Constraint::Length(18),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color)),
+    )
+    .row_highlight_style(if is_focused {
+        Style::default().bg(Color::Blue)
+    } else {
+        Style::default().bg(Color::DarkGray)
+    });
+
+    let mut table_state = TableState::default();
+    if !state.events.logs.is_empty() {
+        table_state.select(Some(state.events.selected_log));
+    }
+
+    frame.render_stateful_widget(table, area, &mut table_state);
+}
+
+/// Live tail panel, always scrolled to the newest log
+fn draw_live_panel(frame: &mut Frame, state: &InteractState, area: Rect) {
+    let tail = &state.events.tail;
+    let (title, border_color) = if state.events.tailing {
+        (
+            format!(" Live: following ({} log(s)) ", tail.len()),
+            Color::Green,
+        )
+    } else {
+        (
+            format!(" Live: stopped ({} log(s)) ", tail.len()),
+            Color::DarkGray,
+        )
+    };
+
+    // Borders and header take three rows
+    let visible = area.height.saturating_sub(3) as usize;
+    let rows: Vec<Row> = tail
+        .iter()
+        .skip(tail.len().saturating_sub(visible))
+        .map(log_row)
+        .collect();
+    let header = Row::new(["Block", "Tx", "Event", "Arguments"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(13),
+            Constraint::Length(18),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color)),
+    );
+
+    frame.render_widget(table, area);
+}
+
+fn log_row(log: &LogEntry) -> Row<'_> {
+    let block = log
+        .block_number
+        .map(|b| b.to_string())
+        .unwrap_or_else(|| "pending".to_string());
+    let tx = log
+        .tx_hash
+        .map(|h| short_hash(&h.to_string()))
+        .unwrap_or_default();
+
+    let (event, args) = match &log.decoded {
+        Some(decoded) => {
+            let args: Vec<String> = decoded
+                .fields
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect();
+            (
+                Cell::from(decoded.name.as_str()).style(Style::default().fg(Color::Green)),
+                args.join(", "),
+            )
+        }
+        None => (
+            Cell::from("unknown").style(Style::default().fg(Color::DarkGray)),
+            log.topic0
+                .map(|t| format!("topic0 {}", t))
+                .unwrap_or_default(),
+        ),
+    };
+
+    Row::new(vec![
+        Cell::from(block),
+        Cell::from(tx),
+        event,
+        Cell::from(args),
+    ])
+}
+
+/// Labels of the query fields, matching `default_log_query` in the app
+fn query_labels(event: Option<&ContractEvent>) -> Vec<String> {
+    let mut labels = vec!["from block".to_string(), "to block".to_string()];
+    if let Some(event) = event {
+        labels.extend(event.inputs.iter().filter(|p| p.indexed).map(|p| {
+            format!(
+                "{} ({}, indexed)",
+                p.param.name,
+                encode_param_type(&p.param)
+            )
+        }));
+    }
+    labels
+}
+
+/// Shorten a 0x-prefixed hash to `0x1234…abcd`
+fn short_hash(hash: &str) -> String {
+    if hash.len() <= 12 {
+        return hash.to_string();
+    }
+    format!("{}…{}", &hash[..6], &hash[hash.len() - 4..])
+}
@@ -1,6 +1,8 @@
+use std::path::Path;
+
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
 use crate::app::App;
@@ -11,14 +13,99 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
         .split(area);
 
-    draw_project_info(frame, app, chunks[0]);
+    if app.is_workspace() {
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(9), Constraint::Min(3)])
+            .split(chunks[0]);
+        draw_project_info(frame, app, left[0]);
+        draw_project_picker(frame, app, left[1]);
+    } else {
+        draw_project_info(frame, app, chunks[0]);
+    }
     draw_quick_stats(frame, app, chunks[1]);
 }
 
+/// Workspace projects, with "All projects" first to merge their lists
+fn draw_project_picker(frame: &mut Frame, app: &App, area: Rect) {
+    let count_style = Style::default().fg(Color::DarkGray);
+    let total_deployments: usize = app
+        .workspace
+        .iter()
+        .map(|m| m.deployments.deployments.len())
+        .sum();
+    let total_scripts: usize = app.workspace.iter().map(|m| m.scripts.scripts.len()).sum();
+
+    let mut items = vec![ListItem::new(Line::from(vec![
+        Span::styled(
+            "All projects",
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  {} deployments, {} scripts",
+                total_deployments, total_scripts
+            ),
+            count_style,
+        ),
+    ]))];
+    // Deepest directory holding every project
+    let mut root = app.workspace[0].project.root.clone();
+    while !app
+        .workspace
+        .iter()
+        .all(|m| m.project.root.starts_with(&root))
+        && root.pop()
+    {}
+
+    items.extend(app.workspace.iter().map(|member| {
+        let path = member
+            .project
+            .root
+            .strip_prefix(&root)
+            .ok()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        ListItem::new(vec![
+            Line::from(vec![
+                Span::styled(
+                    &member.project.name,
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!(" ({})", member.project.project_type), count_style),
+            ]),
+            Line::from(Span::styled(
+                format!(
+                    "  {}  {} deployments, {} scripts",
+                    path.display(),
+                    member.deployments.deployments.len(),
+                    member.scripts.scripts.len()
+                ),
+                count_style,
+            )),
+        ])
+    }));
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.workspace_scope.map_or(0, |i| i + 1)));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Projects ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
 fn draw_project_info(frame: &mut Frame, app: &App, area: Rect) {
     let project = &app.project;
 
-    let info_lines = vec![
+    let mut info_lines = vec![
         Line::from(vec![
             Span::styled("Type: ", Style::default().fg(Color::DarkGray)),
             Span::styled(
@@ -64,6 +151,16 @@ fn draw_project_info(frame: &mut Frame, app: &App, area: Rect) {
         ]),
     ];
 
+    if let Some(profile) = project.foundry_profile() {
+        info_lines.insert(
+            1,
+            Line::from(vec![
+                Span::styled("Profile: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(profile.to_string(), Style::default().fg(Color::Magenta)),
+            ]),
+        );
+    }
+
     let paragraph = Paragraph::new(info_lines).block(
         Block::default()
             .title(" Project ")
@@ -79,7 +176,7 @@ fn draw_quick_stats(frame: &mut Frame, app: &App, area: Rect) {
     let scripts_count = app.scripts.scripts.len();
     let networks_count = app.config.networks.len();
 
-    let items: Vec<ListItem> = vec![
+    let mut items: Vec<ListItem> = vec![
         ListItem::new(Line::from(vec![
             Span::styled(
                 format!("{:>3}", deployments_count),
@@ -109,6 +206,20 @@ fn draw_quick_stats(frame: &mut Frame, app: &App, area: Rect) {
         ])),
     ];
 
+    // Deployments whose name matches artifacts from several source files have no ABI
+    for collision in &app.deployments.collisions {
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("  ! {} is ambiguous: ", collision.name),
+                Style::default().fg(Color::Red),
+            ),
+            Span::styled(
+                collision.candidates.join(", "),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])));
+    }
+
     let list = List::new(items).block(
         Block::default()
             .title(" Overview ")
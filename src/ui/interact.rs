@@ -3,8 +3,13 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
 };
 
-use crate::app::{App, InteractFocus, InteractState};
-use crate::contracts::ContractFunction;
+use alloy::primitives::{U256, utils::format_units};
+
+use crate::app::{App, InteractFocus, InteractState, visible_functions};
+use crate::contracts::{
+    ContractCaller, ContractFunction, FunctionParam, TxReceipt, encode_param_type,
+    format_eth_value, function_selector, function_signature,
+};
 
 pub fn draw(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
     let chunks = Layout::default()
@@ -56,12 +61,21 @@ fn draw_deployments_list(frame: &mut Frame, app: &App, state: &InteractState, ar
                 Style::default()
             };
 
-            let proxy_indicator = if d.callable_address != d.address {
+            let proxy_indicator = if d.is_proxy {
                 " [P]"
+            } else if d.functions.iter().any(|f| f.facet.is_some()) {
+                " [D]"
             } else {
                 ""
             };
-            ListItem::new(Line::from(vec![
+            let mut spans = Vec::new();
+            if let Some(project) = &d.project {
+                spans.push(Span::styled(
+                    format!("{}/", project),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            spans.extend([
                 Span::styled(&d.name, style.add_modifier(Modifier::BOLD)),
                 Span::styled(
                     format!("{} ({} #{})", proxy_indicator, d.network, d.chain_id),
@@ -71,7 +85,14 @@ fn draw_deployments_list(frame: &mut Frame, app: &App, state: &InteractState, ar
                         Color::Yellow
                     }),
                 ),
-            ]))
+            ]);
+            if d.history.len() > 1 {
+                spans.push(Span::styled(
+                    format!(" {}v", d.history.len()),
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -102,8 +123,17 @@ fn draw_right_panel(frame: &mut Frame, app: &App, state: &InteractState, area: R
         draw_wallet_selection_panel(frame, app, state, area);
     } else if matches!(state.focus, crate::app::InteractFocus::AbiSelection) {
         draw_abi_selection_panel(frame, app, state, area);
+    } else if matches!(state.focus, InteractFocus::History) {
+        draw_history_panel(frame, app, state, area);
     } else if matches!(state.focus, crate::app::InteractFocus::ImplementationPrompt) {
         draw_implementation_prompt_panel(frame, app, state, area);
+    } else if matches!(state.focus, crate::app::InteractFocus::ConfirmTransaction) {
+        draw_confirm_panel(frame, state, area);
+    } else if matches!(
+        state.focus,
+        InteractFocus::Events | InteractFocus::EventQuery | InteractFocus::EventLogs
+    ) {
+        super::events::draw(frame, app, state, area);
     } else {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -155,6 +185,84 @@ fn draw_abi_selection_panel(frame: &mut Frame, app: &App, state: &InteractState,
     frame.render_stateful_widget(list, area, &mut list_state);
 }
 
+fn draw_history_panel(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
+    let Some(deployment) = app.deployments.deployments.get(state.selected_deployment) else {
+        return;
+    };
+    let current = deployment.current_instance();
+
+    let items: Vec<ListItem> = deployment
+        .history
+        .iter()
+        .enumerate()
+        .map(|(i, instance)| {
+            let style = if i == state.history_index {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            let marker = if Some(i) == current { "● " } else { "  " };
+            let tx = instance
+                .tx_hash
+                .as_deref()
+                .map(short_hash)
+                .unwrap_or_else(|| "-".to_string());
+            let commit = instance.commit.as_deref().unwrap_or("-");
+
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(Color::Green)),
+                Span::styled(&instance.address, style.fg(Color::Cyan)),
+                Span::styled(
+                    format!("  {}", format_timestamp(instance.timestamp)),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(format!("  tx {}", tx), Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("  @{}", commit),
+                    Style::default().fg(Color::Magenta),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.history_index));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " {} on {}: {} deployment(s), newest first ",
+                    deployment.name,
+                    deployment.network,
+                    deployment.history.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue))
+        .highlight_symbol("▶ ");
+
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// Format a unix timestamp as a UTC date and time
+fn format_timestamp(timestamp: Option<u64>) -> String {
+    timestamp
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts as i64, 0))
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "unknown date".to_string())
+}
+
+/// Shorten a transaction hash to `0x1234…abcd`
+fn short_hash(hash: &str) -> String {
+    if hash.len() > 14 {
+        format!("{}…{}", &hash[..6], &hash[hash.len() - 4..])
+    } else {
+        hash.to_string()
+    }
+}
+
 fn draw_implementation_prompt_panel(
     frame: &mut Frame,
     app: &App,
@@ -179,9 +287,12 @@ fn draw_implementation_prompt_panel(
         .unwrap_or("unknown");
 
     let header_lines = vec![
-        Line::from(vec![
-            Span::styled("Proxy Contract Detected", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-        ]),
+        Line::from(vec![Span::styled(
+            "Proxy Contract Detected",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
         Line::from(""),
         Line::from(vec![
             Span::styled("Proxy: ", Style::default().fg(Color::DarkGray)),
@@ -250,19 +361,34 @@ fn draw_implementation_prompt_panel(
 fn draw_functions(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
     let deployment = app.deployments.deployments.get(state.selected_deployment);
     let is_focused = matches!(state.focus, InteractFocus::Functions);
+    let is_searching = matches!(state.focus, InteractFocus::FunctionSearch);
 
-    let border_color = if is_focused { Color::Cyan } else { Color::Blue };
-    let title = if is_focused {
-        " Functions [active] "
+    let border_color = if is_focused || is_searching {
+        Color::Cyan
     } else {
-        " Functions (Tab/→ to focus) "
+        Color::Blue
+    };
+    let title = if is_searching || !state.function_filter.is_empty() {
+        format!(
+            " Functions /{}{} ",
+            state.function_filter,
+            if is_searching { "█" } else { "" }
+        )
+    } else if is_focused {
+        " Functions [active] ".to_string()
+    } else {
+        " Functions (Tab/→ to focus) ".to_string()
     };
 
-    if deployment.is_none() || deployment.map(|d| d.functions.is_empty()).unwrap_or(true) {
-        let msg = if deployment.is_none() {
-            "Select a deployment"
-        } else {
-            "No functions found in ABI"
+    let visible = deployment
+        .map(|d| visible_functions(&d.functions, &state.function_filter))
+        .unwrap_or_default();
+
+    if visible.is_empty() {
+        let msg = match deployment {
+            None => "Select a deployment",
+            Some(d) if d.functions.is_empty() => "No functions found in ABI",
+            Some(_) => "No functions match the search",
         };
 
         let paragraph = Paragraph::new(msg)
@@ -277,12 +403,11 @@ fn draw_functions(frame: &mut Frame, app: &App, state: &InteractState, area: Rec
         return;
     }
 
-    let deployment = deployment.unwrap();
-    let items: Vec<ListItem> = deployment
-        .functions
+    let functions = &deployment.unwrap().functions;
+    let items: Vec<ListItem> = visible
         .iter()
-        .enumerate()
-        .map(|(i, f)| {
+        .map(|&i| {
+            let f = &functions[i];
             let is_selected = i == state.selected_function;
             let style = if is_selected && is_focused {
                 Style::default().bg(Color::Blue).fg(Color::White)
@@ -297,19 +422,35 @@ fn draw_functions(frame: &mut Frame, app: &App, state: &InteractState, area: Rec
                 _ => Span::styled("[W] ", Style::default().fg(Color::Yellow)),
             };
 
+            // Overloads share a name, so list them by their full signature
+            let overloaded = functions.iter().filter(|g| g.name == f.name).count() > 1;
+            let (name, details) = if overloaded {
+                (function_signature(f), format_function_returns(f))
+            } else {
+                (f.name.clone(), format_function_signature(f))
+            };
+
             ListItem::new(Line::from(vec![
                 state_badge,
-                Span::styled(&f.name, style.add_modifier(Modifier::BOLD)),
                 Span::styled(
-                    format_function_signature(f),
-                    Style::default().fg(Color::DarkGray),
+                    format!("{} ", function_selector(f)),
+                    Style::default().fg(Color::Magenta),
+                ),
+                Span::styled(name, style.add_modifier(Modifier::BOLD)),
+                Span::styled(details, Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    f.facet
+                        .as_ref()
+                        .map(|facet| format!("  [{}]", facet))
+                        .unwrap_or_default(),
+                    Style::default().fg(Color::Blue),
                 ),
             ]))
         })
         .collect();
 
     let mut list_state = ListState::default();
-    list_state.select(Some(state.selected_function));
+    list_state.select(visible.iter().position(|&i| i == state.selected_function));
 
     let list = List::new(items)
         .block(
@@ -336,54 +477,65 @@ fn draw_input_panel(frame: &mut Frame, app: &App, state: &InteractState, area: R
         return;
     };
 
-    let mut lines: Vec<Line> = vec![
-        Line::from(vec![
-            Span::styled("Function: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&func.name, Style::default().add_modifier(Modifier::BOLD)),
-        ]),
+    let mut lines: Vec<Line> = vec![function_line(func)];
+
+    // NatSpec from the compiled artifact
+    if let Some(notice) = &func.notice {
+        lines.push(Line::from(Span::styled(
+            notice.as_str(),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    if let Some(details) = &func.details {
+        lines.push(Line::from(Span::styled(
+            details.as_str(),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        )));
+    }
+
+    lines.extend([
         Line::from(""),
         Line::from(Span::styled(
             "Enter parameters (Tab/↑↓ to navigate, Enter to submit, Esc to cancel):",
             Style::default().fg(Color::DarkGray),
         )),
         Line::from(""),
-    ];
+    ]);
 
     for (i, input) in func.inputs.iter().enumerate() {
-        let is_current = i == state.current_input;
-        let value = state.input_values.get(i).map(|s: &String| s.as_str()).unwrap_or("");
-
-        let label_style = if is_current {
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        };
-
-        let input_style = if is_current {
-            Style::default().bg(Color::DarkGray).fg(Color::White)
-        } else {
-            Style::default()
-        };
-
-        let cursor = if is_current { "█" } else { "" };
-
-        lines.push(Line::from(vec![
-            Span::styled(if is_current { "▶ " } else { "  " }, label_style),
-            Span::styled(format!("{} ", input.name), label_style),
-            Span::styled(
-                format!("({})", input.param_type),
-                Style::default().fg(Color::DarkGray),
-            ),
-        ]));
+        push_input_field(
+            &mut lines,
+            &input.name,
+            &encode_param_type(input),
+            input.doc.as_deref(),
+            state.input_values.get(i).map(String::as_str).unwrap_or(""),
+            i == state.current_input,
+            input_syntax_hint(input),
+        );
+    }
 
-        lines.push(Line::from(vec![
-            Span::raw("    "),
-            Span::styled(format!("{}{}", value, cursor), input_style),
-        ]));
+    // Payable functions take the ETH value in the field after the arguments
+    if ContractCaller::is_payable(func) {
+        let i = func.inputs.len();
+        let value = state.input_values.get(i).map(String::as_str).unwrap_or("");
+        push_input_field(
+            &mut lines,
+            "value",
+            "ETH to send",
+            None,
+            value,
+            i == state.current_input,
+            Some("e.g. 0.1 ether, 15 gwei, 1000 (wei); empty sends nothing"),
+        );
+    }
 
-        lines.push(Line::from(""));
+    if let Some(error) = &state.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
     }
 
     let paragraph = Paragraph::new(lines)
@@ -398,6 +550,64 @@ fn draw_input_panel(frame: &mut Frame, app: &App, state: &InteractState, area: R
     frame.render_widget(paragraph, area);
 }
 
+pub(super) fn push_input_field<'a>(
+    lines: &mut Vec<Line<'a>>,
+    name: &str,
+    type_label: &str,
+    doc: Option<&str>,
+    value: &str,
+    is_current: bool,
+    hint: Option<&'static str>,
+) {
+    let label_style = if is_current {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let input_style = if is_current {
+        Style::default().bg(Color::DarkGray).fg(Color::White)
+    } else {
+        Style::default()
+    };
+
+    let cursor = if is_current { "█" } else { "" };
+
+    let mut label = vec![
+        Span::styled(if is_current { "▶ " } else { "  " }, label_style),
+        Span::styled(format!("{} ", name), label_style),
+        Span::styled(
+            format!("({})", type_label),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if let Some(doc) = doc {
+        label.push(Span::styled(
+            format!(" - {}", doc),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        ));
+    }
+    lines.push(Line::from(label));
+
+    lines.push(Line::from(vec![
+        Span::raw("    "),
+        Span::styled(format!("{}{}", value, cursor), input_style),
+    ]));
+
+    if is_current && let Some(hint) = hint {
+        lines.push(Line::from(Span::styled(
+            format!("    {}", hint),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    lines.push(Line::from(""));
+}
+
 fn draw_wallet_selection_panel(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
     let wallet_names: Vec<String> = app.config.wallets.keys().cloned().collect();
     let is_focused = matches!(state.focus, crate::app::InteractFocus::WalletSelection);
@@ -442,6 +652,41 @@ fn draw_wallet_selection_panel(frame: &mut Frame, app: &App, state: &InteractSta
         list_state.select(wallet_names.iter().position(|n| n == selected));
     }
 
+    // Summarise the pending transaction so it can be reviewed before signing
+    let func = app
+        .deployments
+        .deployments
+        .get(state.selected_deployment)
+        .and_then(|d| d.functions.get(state.selected_function));
+    let mut summary: Vec<Line> = vec![];
+    if let Some(func) = func {
+        summary.push(function_line(func));
+    }
+    if let Some(value) = state.call_value {
+        summary.push(Line::from(vec![
+            Span::styled("Value: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format_eth_value(value), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(summary.len() as u16 + 2),
+            Constraint::Min(3),
+        ])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new(summary).block(
+            Block::default()
+                .title(" Transaction ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        chunks[0],
+    );
+
     let list = List::new(items)
         .block(
             Block::default()
@@ -456,7 +701,123 @@ fn draw_wallet_selection_panel(frame: &mut Frame, app: &App, state: &InteractSta
         })
         .highlight_symbol(if is_focused { "▶ " } else { "  " });
 
-    frame.render_stateful_widget(list, area, &mut list_state);
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+fn draw_confirm_panel(frame: &mut Frame, state: &InteractState, area: Rect) {
+    let Some(preview) = &state.tx_preview else {
+        return;
+    };
+    let label = Style::default().fg(Color::DarkGray);
+
+    let mut lines: Vec<Line> = vec![
+        Line::from(Span::styled(
+            "Simulation succeeded. Review the transaction before signing:",
+            Style::default().fg(Color::Green),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Function: ", label),
+            Span::styled(
+                &preview.signature,
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    for arg in &preview.decoded_args {
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(arg, Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Calldata: ", label),
+        Span::raw(format!("0x{}", hex::encode(&preview.calldata))),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("To: ", label),
+        Span::styled(
+            preview.to.to_checksum(None),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("From: ", label),
+        Span::raw(preview.from.to_checksum(None)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Value: ", label),
+        Span::styled(
+            format_eth_value(preview.value),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Estimated gas: ", label),
+        Span::raw(preview.gas_limit.to_string()),
+    ]));
+
+    let fee_per_gas = match preview.max_priority_fee_per_gas {
+        Some(priority) => format!(
+            "{} (priority {})",
+            format_gwei(preview.max_fee_per_gas),
+            format_gwei(priority)
+        ),
+        None => format!("{} (legacy)", format_gwei(preview.max_fee_per_gas)),
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Max fee per gas: ", label),
+        Span::raw(fee_per_gas),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Max fee: ", label),
+        Span::styled(
+            format_eth_value(preview.max_fee()),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press Enter to sign and send, Esc to cancel",
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Confirm Transaction ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Format a per-gas price in wei as gwei, e.g. `1.5 gwei`
+fn format_gwei(wei: u128) -> String {
+    format_units(wei, "gwei")
+        .map(|gwei| format!("{} gwei", gwei.trim_end_matches('0').trim_end_matches('.')))
+        .unwrap_or_else(|_| format!("{} wei", wei))
+}
+
+/// Example input syntax for types that are not a single scalar value
+pub(super) fn input_syntax_hint(param: &FunctionParam) -> Option<&'static str> {
+    let ty = encode_param_type(param);
+    if ty.ends_with(']') {
+        Some("e.g. [a, b, c]  (tuples as (x, y), strings with commas in \"quotes\")")
+    } else if ty.starts_with('(') {
+        Some("e.g. (x, y, z)  (components in order, nested arrays as [a, b])")
+    } else if ty.starts_with("int") {
+        Some("e.g. -42, 0x2a")
+    } else if ty.starts_with("uint") {
+        Some("e.g. 1000, 0x3e8, 1.5 ether, 20 gwei")
+    } else {
+        None
+    }
 }
 
 fn format_function_signature(f: &ContractFunction) -> String {
@@ -466,15 +827,39 @@ fn format_function_signature(f: &ContractFunction) -> String {
         .map(|p| format!("{}: {}", p.name, p.param_type))
         .collect();
 
+    format!("({}){}", params.join(", "), format_function_returns(f))
+}
+
+fn format_function_returns(f: &ContractFunction) -> String {
     let outputs: Vec<String> = f.outputs.iter().map(|o| o.param_type.clone()).collect();
 
-    let returns = if outputs.is_empty() {
+    if outputs.is_empty() {
         String::new()
     } else {
         format!(" → {}", outputs.join(", "))
-    };
+    }
+}
 
-    format!("({}){}", params.join(", "), returns)
+/// `Function:` line naming the function by full signature and selector
+pub(super) fn function_line(func: &ContractFunction) -> Line<'static> {
+    let mut spans = vec![
+        Span::styled("Function: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            function_signature(func),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" {}", function_selector(func)),
+            Style::default().fg(Color::Magenta),
+        ),
+    ];
+    if let Some(facet) = &func.facet {
+        spans.push(Span::styled(
+            format!(" via {}", facet),
+            Style::default().fg(Color::Blue),
+        ));
+    }
+    Line::from(spans)
 }
 
 fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect) {
@@ -500,6 +885,45 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
             ),
         ]));
 
+        if let Some(proxy) = &deployment.proxy {
+            let implementation = match &proxy.implementation_name {
+                Some(name) => format!("{} ({})", proxy.implementation, name),
+                None => format!("{} (unknown ABI)", proxy.implementation),
+            };
+            let mut fields = vec![
+                ("Proxy: ", proxy.kind.to_string()),
+                ("Implementation: ", implementation),
+            ];
+            if let Some(admin) = proxy.admin {
+                fields.push(("Proxy admin: ", admin.to_string()));
+            }
+            if let Some(beacon) = proxy.beacon {
+                fields.push(("Beacon: ", beacon.to_string()));
+            }
+            for (label, value) in fields {
+                lines.push(Line::from(vec![
+                    Span::styled(label, Style::default().fg(Color::DarkGray)),
+                    Span::styled(value, Style::default().fg(Color::Yellow)),
+                ]));
+            }
+        }
+
+        if let Some(current) = deployment.current_instance() {
+            let instance = &deployment.history[current];
+            let mut deployed = format_timestamp(instance.timestamp);
+            if deployment.history.len() > 1 {
+                deployed.push_str(&format!(
+                    " (version {} of {}, [h] History)",
+                    deployment.history.len() - current,
+                    deployment.history.len()
+                ));
+            }
+            lines.push(Line::from(vec![
+                Span::styled("Deployed: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(deployed, Style::default().fg(Color::White)),
+            ]));
+        }
+
         // Always show the deployment's network/chain info
         lines.push(Line::from(vec![
             Span::styled("Network: ", Style::default().fg(Color::DarkGray)),
@@ -530,10 +954,7 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
             ]));
         }
 
-        lines.push(Line::from(vec![
-            Span::styled("Function: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&func.name, Style::default().add_modifier(Modifier::BOLD)),
-        ]));
+        lines.push(function_line(func));
 
         lines.push(Line::from(""));
 
@@ -559,6 +980,16 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
                 }
             }
 
+            if ContractCaller::is_payable(func)
+                && let Some(value) = state.call_value
+            {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled("value: ", Style::default().fg(Color::Cyan)),
+                    Span::styled(format_eth_value(value), Style::default()),
+                ]));
+            }
+
             lines.push(Line::from(""));
         }
 
@@ -577,6 +1008,15 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
                     Span::styled("Connecting to RPC...", Style::default().fg(Color::Yellow)),
                 ]));
             }
+            crate::app::CallStatus::Simulating => {
+                lines.push(Line::from(vec![
+                    Span::styled("● ", Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        "Simulating call and estimating gas...",
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ]));
+            }
             crate::app::CallStatus::Executing => {
                 lines.push(Line::from(vec![
                     Span::styled("● ", Style::default().fg(Color::Yellow)),
@@ -587,9 +1027,16 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
                 if let Some(result) = &state.result {
                     lines.push(Line::from(vec![
                         Span::styled("● ", Style::default().fg(Color::Green)),
-                        Span::styled("Result: ", Style::default().fg(Color::Green)),
-                        Span::styled(result, Style::default().fg(Color::Green)),
+                        Span::styled("Result:", Style::default().fg(Color::Green)),
                     ]));
+
+                    // One line per decoded output
+                    for result_line in result.lines() {
+                        lines.push(Line::from(vec![
+                            Span::raw("  "),
+                            Span::styled(result_line, Style::default().fg(Color::Green)),
+                        ]));
+                    }
                 } else {
                     lines.push(Line::from(vec![
                         Span::styled("● ", Style::default().fg(Color::Green)),
@@ -597,6 +1044,42 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
                     ]));
                 }
             }
+            crate::app::CallStatus::Pending {
+                tx_hash,
+                confirmations,
+            } => {
+                let waiting = if *confirmations > 1 {
+                    format!("Pending: waiting for {} confirmations...", confirmations)
+                } else {
+                    "Pending: waiting for transaction to be mined...".to_string()
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("● ", Style::default().fg(Color::Yellow)),
+                    Span::styled(waiting, Style::default().fg(Color::Yellow)),
+                ]));
+                lines.push(Line::from(vec![
+                    Span::styled("  Tx: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(tx_hash, Style::default().fg(Color::Cyan)),
+                ]));
+            }
+            crate::app::CallStatus::Mined => {
+                lines.push(Line::from(vec![
+                    Span::styled("● ", Style::default().fg(Color::Green)),
+                    Span::styled("Transaction succeeded", Style::default().fg(Color::Green)),
+                ]));
+                if let Some(receipt) = &state.receipt {
+                    push_receipt_lines(&mut lines, receipt);
+                }
+            }
+            crate::app::CallStatus::Reverted => {
+                lines.push(Line::from(vec![
+                    Span::styled("✗ ", Style::default().fg(Color::Red)),
+                    Span::styled("Transaction reverted", Style::default().fg(Color::Red)),
+                ]));
+                if let Some(receipt) = &state.receipt {
+                    push_receipt_lines(&mut lines, receipt);
+                }
+            }
             crate::app::CallStatus::Failed(msg) => {
                 lines.push(Line::from(vec![
                     Span::styled("✗ ", Style::default().fg(Color::Red)),
@@ -637,3 +1120,48 @@ fn draw_result(frame: &mut Frame, app: &App, state: &InteractState, area: Rect)
 
     frame.render_widget(paragraph, area);
 }
+
+fn push_receipt_lines<'a>(lines: &mut Vec<Line<'a>>, receipt: &'a TxReceipt) {
+    let label = Style::default().fg(Color::DarkGray);
+    let fee = U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price);
+
+    lines.push(Line::from(vec![
+        Span::styled("  Tx: ", label),
+        Span::styled(&receipt.tx_hash, Style::default().fg(Color::Cyan)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Block: ", label),
+        Span::raw(
+            receipt
+                .block_number
+                .map(|b| b.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Gas used: ", label),
+        Span::raw(receipt.gas_used.to_string()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Gas price: ", label),
+        Span::raw(format_gwei(receipt.effective_gas_price)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Fee: ", label),
+        Span::raw(format_eth_value(fee)),
+    ]));
+
+    if receipt.logs.is_empty() {
+        return;
+    }
+    lines.push(Line::from(Span::styled(
+        format!("  Events ({}):", receipt.logs.len()),
+        label,
+    )));
+    for log in &receipt.logs {
+        lines.push(Line::from(vec![
+            Span::raw("    "),
+            Span::styled(log, Style::default().fg(Color::Magenta)),
+        ]));
+    }
+}
@@ -1,5 +1,6 @@
 mod components;
 mod config;
+mod events;
 mod home;
 mod interact;
 mod scripts;
@@ -35,10 +36,14 @@ pub fn draw(frame: &mut Frame, app: &App) {
 }
 
 fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
-    let title = format!(
-        " runic - {} ({}) ",
-        app.project.name, app.project.project_type
-    );
+    let title = if app.is_workspace() && app.workspace_scope.is_none() {
+        format!(" runic - all {} projects ", app.workspace.len())
+    } else {
+        format!(
+            " runic - {} ({}) ",
+            app.project.name, app.project.project_type
+        )
+    };
 
     let block = Block::default()
         .title(title)
@@ -51,22 +56,69 @@ fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
 
 fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     let help_text = match &app.view {
+        View::Home if app.is_workspace() => {
+            "[↑↓] Project  [i] Interact  [s] Scripts  [c] Config  [p] Profile  [q] Quit"
+        }
+        View::Home if app.project.is_foundry() => {
+            "[i] Interact  [s] Scripts  [c] Config  [p] Profile  [q] Quit"
+        }
         View::Home => "[i] Interact  [s] Scripts  [c] Config  [q] Quit",
         View::Interact(state) => match state.focus {
-            InteractFocus::Deployments => "[↑↓] Navigate  [Tab/→/Enter] Functions  [a] Change ABI  [Esc] Back",
-            InteractFocus::Functions => "[↑↓] Navigate  [Enter] Call  [←/Esc] Deployments",
+            InteractFocus::Deployments => {
+                "[↑↓] Navigate  [Tab/→/Enter] Functions  [e] Events  [h] History  [a] Change ABI  [Esc] Back"
+            }
+            InteractFocus::Functions => {
+                "[↑↓] Navigate  [Enter] Call  [/] Search  [e] Events  [←/Esc] Deployments"
+            }
+            InteractFocus::FunctionSearch => {
+                "Type a name, signature or selector  [Enter] Done  [Esc] Clear"
+            }
             InteractFocus::Inputs => "[↑↓/Tab] Navigate  [Enter] Submit/Next  [Esc] Cancel",
-            InteractFocus::WalletSelection => "[↑↓] Navigate  [Enter] Select  [Esc] Cancel",
+            InteractFocus::WalletSelection => "[↑↓] Navigate  [Enter] Simulate  [Esc] Cancel",
+            InteractFocus::ConfirmTransaction => "[Enter] Sign & Send  [Esc] Cancel",
+            InteractFocus::Events => {
+                "[↑↓] Navigate  [Enter] Query  [Tab] Logs  [t] Live tail  [f] Functions  [Esc] Deployments"
+            }
+            InteractFocus::EventQuery => "[↑↓/Tab] Navigate  [Enter] Fetch logs  [Esc] Back",
+            InteractFocus::EventLogs => {
+                "[↑↓/PgUp/PgDn] Scroll  [g/G] Top/Bottom  [t] Live tail  [Esc] Back"
+            }
             InteractFocus::AbiSelection => "[↑↓] Navigate  [Enter] Select  [Esc] Cancel",
-            InteractFocus::ImplementationPrompt => "[↑↓] Navigate  [Enter] Select ABI  [s] Skip  [Esc] Cancel",
+            InteractFocus::History => "[↑↓] Navigate  [Enter] Use this deployment  [Esc] Cancel",
+            InteractFocus::ImplementationPrompt => {
+                "[↑↓] Navigate  [Enter] Select ABI  [s] Skip  [Esc] Cancel"
+            }
         },
         View::Scripts(state) => match state.phase {
-            ScriptPhase::SelectScript => "[↑/k] Up  [↓/j] Down  [Enter] Run  [Esc] Back",
+            ScriptPhase::SelectScript => {
+                "[↑/k] Up  [↓/j] Down  [Enter] Run  [h] History  [Esc] Back"
+            }
+            ScriptPhase::History if state.history.searching => {
+                "Type to search  [Enter] Done  [Esc] Clear"
+            }
+            ScriptPhase::History => {
+                "[↑↓] Navigate  [Enter] Open  [/] Search  [d] Mark/Diff  [Esc] Back"
+            }
+            ScriptPhase::HistoryRun { .. } | ScriptPhase::HistoryDiff { .. } => {
+                "[↑↓/PgUp/PgDn] Scroll  [Esc] Back"
+            }
+            ScriptPhase::SelectFunction { .. } => {
+                "[↑/k] Up  [↓/j] Down  [Enter] Select  [Esc] Cancel"
+            }
+            ScriptPhase::EnterArguments { .. } => {
+                "[Tab/↑↓] Navigate  [Enter] Next/Submit  [Esc] Back"
+            }
             ScriptPhase::SelectNetwork { .. } => {
                 "[↑/k] Up  [↓/j] Down  [Enter] Confirm  [Esc] Cancel"
             }
-            ScriptPhase::SelectWallet { .. } => "[↑/k] Up  [↓/j] Down  [Enter] Run  [Esc] Back",
-            ScriptPhase::Running => "[Esc] Dismiss output",
+            ScriptPhase::SelectWallet { .. } => {
+                "[↑/k] Up  [↓/j] Down  [Enter] Run options  [Esc] Back"
+            }
+            ScriptPhase::RunOptions { .. } => {
+                "[↑↓] Navigate  [Space] Toggle  [Enter] Run  [Esc] Back"
+            }
+            ScriptPhase::Running { exit: None, .. } => "[c] Cancel script",
+            ScriptPhase::Running { .. } => "[Esc] Dismiss output",
         },
         View::Config => "[Esc] Back",
     };
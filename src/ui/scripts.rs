@@ -1,11 +1,39 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
+    },
 };
 
-use crate::app::{App, ScriptPhase, ScriptsState};
+use alloy::primitives::utils::format_ether;
+
+use super::interact::{function_line, input_syntax_hint, push_input_field};
+use crate::app::{
+    App, ScriptPhase, ScriptsState, run_option_fields, script_networks, selected_script_manager,
+};
+use crate::contracts::{ContractFunction, encode_param_type, function_signature};
+use crate::scripts::{DiffLine, RunOptionField, RunRecord, ScriptSummary, ScriptTx, diff_lines};
 
 pub fn draw(frame: &mut Frame, app: &App, state: &ScriptsState, area: Rect) {
+    // Past runs get the full width
+    match &state.phase {
+        ScriptPhase::History => return draw_history(frame, app, state, area),
+        ScriptPhase::HistoryRun { run, scroll } => {
+            if let Some(run) = app.history.runs.get(*run) {
+                draw_history_run(frame, run, *scroll, area);
+            }
+            return;
+        }
+        ScriptPhase::HistoryDiff { old, new, scroll } => {
+            if let (Some(old), Some(new)) = (app.history.runs.get(*old), app.history.runs.get(*new))
+            {
+                draw_history_diff(frame, old, new, *scroll, area);
+            }
+            return;
+        }
+        _ => {}
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
@@ -45,7 +73,17 @@ fn draw_scripts_list(frame: &mut Frame, app: &App, state: &ScriptsState, area: R
                 Style::default()
             };
 
-            let name_span = Span::styled(&script.name, style.add_modifier(Modifier::BOLD));
+            let mut name_spans = Vec::new();
+            if let Some(project) = &script.project {
+                name_spans.push(Span::styled(
+                    format!("{}/", project),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            name_spans.push(Span::styled(
+                &script.name,
+                style.add_modifier(Modifier::BOLD),
+            ));
 
             let desc_line = if let Some(desc) = &script.description {
                 Line::from(vec![
@@ -56,7 +94,7 @@ fn draw_scripts_list(frame: &mut Frame, app: &App, state: &ScriptsState, area: R
                 Line::from("")
             };
 
-            ListItem::new(vec![Line::from(name_span), desc_line])
+            ListItem::new(vec![Line::from(name_spans), desc_line])
         })
         .collect();
 
@@ -78,6 +116,10 @@ fn draw_scripts_list(frame: &mut Frame, app: &App, state: &ScriptsState, area: R
 
 fn draw_script_output(frame: &mut Frame, app: &App, state: &ScriptsState, area: Rect) {
     match &state.phase {
+        // Drawn full-width by `draw`
+        ScriptPhase::History | ScriptPhase::HistoryRun { .. } | ScriptPhase::HistoryDiff { .. } => {
+        }
+
         ScriptPhase::SelectScript => {
             let content = if let Some(output) = &state.output {
                 output.clone()
@@ -100,12 +142,52 @@ fn draw_script_output(frame: &mut Frame, app: &App, state: &ScriptsState, area:
             frame.render_widget(paragraph, area);
         }
 
+        ScriptPhase::SelectFunction { selected } => {
+            let Some(script) = app.scripts.scripts.get(state.selected_script) else {
+                return;
+            };
+            let labels: Vec<String> = script
+                .functions
+                .iter()
+                .map(|func| match &func.notice {
+                    Some(notice) => format!("{} - {}", function_signature(func), notice),
+                    None => function_signature(func),
+                })
+                .collect();
+
+            draw_selection_list(
+                frame,
+                area,
+                " Select Function ",
+                &labels,
+                *selected,
+                "↑↓ navigate • Enter select • Esc cancel",
+            );
+        }
+
+        ScriptPhase::EnterArguments {
+            function,
+            args,
+            current,
+            error,
+        } => {
+            let Some(func) = app
+                .scripts
+                .scripts
+                .get(state.selected_script)
+                .and_then(|script| script.functions.get(*function))
+            else {
+                return;
+            };
+            draw_arguments(frame, area, func, args, *current, error.as_deref());
+        }
+
         ScriptPhase::SelectNetwork { selected } => {
             draw_selection_list(
                 frame,
                 area,
                 " Select Network ",
-                &app.config.networks.keys().cloned().collect::<Vec<_>>(),
+                &network_labels(app),
                 *selected,
                 "↑↓ navigate • Enter confirm • Esc cancel",
             );
@@ -121,27 +203,466 @@ fn draw_script_output(frame: &mut Frame, app: &App, state: &ScriptsState, area:
                 " Select Wallet ",
                 &wallet_options,
                 *selected,
-                "↑↓ navigate • Enter run • Esc back",
+                "↑↓ navigate • Enter continue • Esc back",
             );
         }
 
-        ScriptPhase::Running => {
+        ScriptPhase::RunOptions {
+            selected, options, ..
+        } => {
+            let Some(script) = app.scripts.scripts.get(state.selected_script) else {
+                return;
+            };
+            let labels: Vec<String> = run_option_fields(script)
+                .iter()
+                .enumerate()
+                .map(|(i, &field)| match (field, options.flag(field)) {
+                    (RunOptionField::Broadcast, Some(broadcast)) => format!(
+                        "{}: {}",
+                        field.label(),
+                        if broadcast { "Broadcast" } else { "Simulate" }
+                    ),
+                    (_, Some(enabled)) => {
+                        format!("[{}] {}", if enabled { "x" } else { " " }, field.label())
+                    }
+                    (_, None) => {
+                        let cursor = if i == *selected { "_" } else { "" };
+                        let value = options.text(field).map_or("", |t| t.as_str());
+                        format!("{}: {}{}", field.label(), value, cursor)
+                    }
+                })
+                .collect();
+
+            draw_selection_list(
+                frame,
+                area,
+                " Run Options ",
+                &labels,
+                *selected,
+                "↑↓ navigate • Space toggle • type to edit • Enter run • Esc back",
+            );
+        }
+
+        ScriptPhase::Running { started, exit } => {
             let content = state.output.as_deref().unwrap_or("Running script...");
 
+            let (title, color) = match exit {
+                None => (
+                    format!(
+                        " Output · running {}s (c to cancel) ",
+                        started.elapsed().as_secs()
+                    ),
+                    Color::Yellow,
+                ),
+                Some(exit) => (
+                    format!(" Output · {} (Esc to dismiss) ", exit),
+                    if exit.success() {
+                        Color::Green
+                    } else {
+                        Color::Red
+                    },
+                ),
+            };
+
+            let output_area = match &state.summary {
+                Some(summary) => {
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([
+                            Constraint::Min(5),
+                            Constraint::Length(summary_height(summary, area.height)),
+                        ])
+                        .split(area);
+                    draw_summary(frame, summary, chunks[1]);
+                    chunks[0]
+                }
+                None => area,
+            };
+
             let paragraph = Paragraph::new(content)
                 .style(Style::default().fg(Color::Yellow))
                 .wrap(Wrap { trim: true })
                 .block(
                     Block::default()
-                        .title(" Output (Esc to dismiss) ")
+                        .title(title)
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow)),
+                        .border_style(Style::default().fg(color)),
                 );
-            frame.render_widget(paragraph, area);
+            frame.render_widget(paragraph, output_area);
         }
     }
 }
 
+/// Typed argument form for a script function, laid out like the Interact view's
+fn draw_arguments(
+    frame: &mut Frame,
+    area: Rect,
+    func: &ContractFunction,
+    args: &[String],
+    current: usize,
+    error: Option<&str>,
+) {
+    let mut lines: Vec<Line> = vec![function_line(func)];
+    if let Some(notice) = &func.notice {
+        lines.push(Line::from(Span::styled(
+            notice.as_str(),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter arguments (Tab/↑↓ to navigate, Enter to submit, Esc to go back):",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ]);
+
+    for (i, input) in func.inputs.iter().enumerate() {
+        push_input_field(
+            &mut lines,
+            &input.name,
+            &encode_param_type(input),
+            input.doc.as_deref(),
+            args.get(i).map(String::as_str).unwrap_or(""),
+            i == current,
+            input_syntax_hint(input),
+        );
+    }
+
+    if let Some(error) = error {
+        lines.push(Line::from(Span::styled(
+            error,
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Script Arguments ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Rows for the transaction table plus cost and deployment lines, at most half the panel
+fn summary_height(summary: &ScriptSummary, available: u16) -> u16 {
+    let rows = summary.transactions.len() + summary.costs.len() + summary.deployed.len();
+    // Borders, table header and the section headings
+    let headings =
+        usize::from(!summary.costs.is_empty()) + usize::from(!summary.deployed.is_empty());
+    let height = rows + 3 + headings;
+    (height as u16).min(available / 2)
+}
+
+/// Table of planned or sent transactions, estimated cost per chain and created contracts
+fn draw_summary(frame: &mut Frame, summary: &ScriptSummary, area: Rect) {
+    let sent = summary.broadcast();
+    let title = if sent {
+        " Transactions (sent) "
+    } else {
+        " Transactions (simulated) "
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut info: Vec<Line> = Vec::new();
+    if !summary.costs.is_empty() {
+        info.push(Line::from(Span::styled(
+            "Estimated cost",
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for cost in &summary.costs {
+            let mut text = format!(
+                "  chain {}: {} {} for {} gas",
+                cost.chain_id, cost.amount, cost.symbol, cost.total_gas
+            );
+            if let Some(price) = &cost.gas_price {
+                text.push_str(&format!(" at {} gwei", price));
+            }
+            info.push(Line::from(text));
+        }
+    }
+    if !summary.deployed.is_empty() {
+        info.push(Line::from(Span::styled(
+            if sent { "Deployed" } else { "Will deploy" },
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for contract in &summary.deployed {
+            let mut spans = vec![
+                Span::raw("  "),
+                Span::styled(
+                    contract.name.as_deref().unwrap_or("(unnamed)"),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw(format!(" {}", contract.address)),
+            ];
+            if let Some(chain_id) = contract.chain_id {
+                spans.push(Span::styled(
+                    format!(" (chain {})", chain_id),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            info.push(Line::from(spans));
+        }
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(2), Constraint::Length(info.len() as u16)])
+        .split(inner);
+
+    let header = Row::new(["Chain", "Target", "Function", "Value", "Gas", "Hash"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = summary.transactions.iter().map(tx_row).collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(16),
+            Constraint::Min(16),
+            Constraint::Length(12),
+            Constraint::Length(10),
+            Constraint::Length(13),
+        ],
+    )
+    .header(header);
+
+    frame.render_widget(table, chunks[0]);
+    frame.render_widget(Paragraph::new(info), chunks[1]);
+}
+
+fn tx_row(tx: &ScriptTx) -> Row<'_> {
+    let target = match (&tx.to, &tx.contract) {
+        (None, Some(contract)) => format!("new {}", contract),
+        (None, None) => "(create)".to_string(),
+        (Some(to), Some(contract)) => format!("{} {}", contract, short_hash(to)),
+        (Some(to), None) => short_hash(to),
+    };
+    let value = if tx.value.is_zero() {
+        "-".to_string()
+    } else {
+        let ether = format_ether(tx.value);
+        format!("{} ETH", ether.trim_end_matches('0').trim_end_matches('.'))
+    };
+    let hash = match (&tx.hash, tx.success) {
+        (Some(hash), Some(false)) => {
+            Cell::from(short_hash(hash)).style(Style::default().fg(Color::Red))
+        }
+        (Some(hash), _) => Cell::from(short_hash(hash)).style(Style::default().fg(Color::Green)),
+        (None, _) => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+    };
+
+    Row::new(vec![
+        Cell::from(tx.chain_id.map(|id| id.to_string()).unwrap_or_default()),
+        Cell::from(target),
+        Cell::from(tx.function.as_deref().unwrap_or("-")),
+        Cell::from(value),
+        Cell::from(tx.gas.map(|gas| gas.to_string()).unwrap_or_default()),
+        hash,
+    ])
+}
+
+/// Shorten a 0x-prefixed hash or address to `0x1234…abcd`
+fn short_hash(hash: &str) -> String {
+    if hash.len() <= 12 {
+        return hash.to_string();
+    }
+    format!("{}…{}", &hash[..6], &hash[hash.len() - 4..])
+}
+
+/// Saved runs, newest first, with the search box above them
+fn draw_history(frame: &mut Frame, app: &App, state: &ScriptsState, area: Rect) {
+    let history = &state.history;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(3)])
+        .split(area);
+
+    let search_style = if history.searching {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let search_text = if history.query.is_empty() && !history.searching {
+        "Press / to search scripts, networks, wallets, flags and logs".to_string()
+    } else if history.searching {
+        format!("{}_", history.query)
+    } else {
+        history.query.clone()
+    };
+    let search = Paragraph::new(search_text).style(search_style).block(
+        Block::default()
+            .title(" Search ")
+            .borders(Borders::ALL)
+            .border_style(search_style),
+    );
+    frame.render_widget(search, chunks[0]);
+
+    let visible = app.history.filtered(&history.query);
+    let title = match &app.history.dir {
+        Some(dir) => format!(
+            " Run History ({}/{}) - {} ",
+            visible.len(),
+            app.history.runs.len(),
+            dir.display()
+        ),
+        None => " Run History (not saved: no data directory) ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+
+    if visible.is_empty() {
+        let text = if app.history.runs.is_empty() {
+            "No runs yet. Every script run is saved here."
+        } else {
+            "No runs match the search."
+        };
+        let paragraph = Paragraph::new(text)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(paragraph, chunks[1]);
+        return;
+    }
+
+    let header = Row::new([
+        "", "Started", "Script", "Network", "Wallet", "User", "Status", "Flags",
+    ])
+    .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = visible
+        .iter()
+        .map(|&i| {
+            let run = &app.history.runs[i];
+            let mark = if history.marked == Some(i) { "◆" } else { "" };
+            let status_style = if run.success() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            let script = match &run.project {
+                Some(project) => format!("{}/{}", project, run.script),
+                None => run.script.clone(),
+            };
+            Row::new(vec![
+                Cell::from(mark).style(Style::default().fg(Color::Yellow)),
+                Cell::from(run.started_label()),
+                Cell::from(script),
+                Cell::from(run.network.as_str()),
+                Cell::from(run.wallet.as_str()),
+                Cell::from(run.user.as_deref().unwrap_or("-")),
+                Cell::from(run.status()).style(status_style),
+                Cell::from(run.flags.join(" ")).style(Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(1),
+            Constraint::Length(19),
+            Constraint::Min(16),
+            Constraint::Length(12),
+            Constraint::Length(16),
+            Constraint::Length(10),
+            Constraint::Length(28),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(history.selected));
+    frame.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
+/// Details and full log of one saved run
+fn draw_history_run(frame: &mut Frame, run: &RunRecord, scroll: u16, area: Rect) {
+    let label = Style::default().fg(Color::DarkGray);
+    let field = |name: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<9}", name), label),
+            Span::raw(value),
+        ])
+    };
+    let status_style = if run.success() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::Red)
+    };
+
+    let mut lines = vec![
+        field("Script", run.script_path.display().to_string()),
+        field("Network", run.network.clone()),
+        field("Wallet", run.wallet.clone()),
+        field("User", run.user.clone().unwrap_or_else(|| "-".to_string())),
+        field("Flags", run.flags.join(" ")),
+        field("Started", run.started_at.clone()),
+        field("Ended", run.ended_at.clone()),
+        Line::from(vec![
+            Span::styled(format!("{:<9}", "Status"), label),
+            Span::styled(run.status(), status_style),
+        ]),
+        Line::from(""),
+    ];
+    lines.extend(run.log().lines().map(|line| Line::from(line.to_string())));
+
+    let paragraph = Paragraph::new(lines).scroll((scroll, 0)).block(
+        Block::default()
+            .title(format!(" {} - {} ", run.script, run.started_label()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Line diff of two saved runs' logs, older first
+fn draw_history_diff(frame: &mut Frame, old: &RunRecord, new: &RunRecord, scroll: u16, area: Rect) {
+    let old_log = old.log();
+    let new_log = new.log();
+    let lines: Vec<Line> = diff_lines(&old_log, &new_log)
+        .into_iter()
+        .map(|line| match line {
+            DiffLine::Same(text) => Line::from(format!("  {}", text)),
+            DiffLine::Removed(text) => {
+                Line::styled(format!("- {}", text), Style::default().fg(Color::Red))
+            }
+            DiffLine::Added(text) => {
+                Line::styled(format!("+ {}", text), Style::default().fg(Color::Green))
+            }
+        })
+        .collect();
+
+    let title = format!(
+        " Diff: {} {} ({}) → {} {} ({}) ",
+        old.script,
+        old.started_label(),
+        old.status(),
+        new.script,
+        new.started_label(),
+        new.status()
+    );
+    let paragraph = Paragraph::new(lines).scroll((scroll, 0)).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
 fn draw_selection_list(
     frame: &mut Frame,
     area: Rect,
@@ -191,3 +712,31 @@ fn draw_selection_list(
         .alignment(Alignment::Center);
     frame.render_widget(help, chunks[1]);
 }
+
+/// Network names, with where Hardhat-only networks come from and which keys they use
+fn network_labels(app: &App) -> Vec<String> {
+    let hardhat_networks = &selected_script_manager(app).hardhat_networks;
+    script_networks(app)
+        .into_iter()
+        .map(|name| match hardhat_networks.get(&name) {
+            Some(network) if !app.config.networks.contains_key(&name) => {
+                let mut label = format!("{} (hardhat.config", name);
+                if let Some(chain_id) = network.chain_id {
+                    label.push_str(&format!(", chain {}", chain_id));
+                }
+                let keys: Vec<String> = network
+                    .accounts
+                    .iter()
+                    .flatten()
+                    .map(|var| format!("${}", var))
+                    .collect();
+                if !keys.is_empty() {
+                    label.push_str(&format!(", accounts {}", keys.join(" ")));
+                }
+                label.push(')');
+                label
+            }
+            _ => name,
+        })
+        .collect()
+}